@@ -0,0 +1,173 @@
+// the MPU6050 driver on embedded-hal-async, so reading the sensor does not block
+// the caller while the I2C transfer runs. same registers, state and decoding as
+// the blocking mpu6050::Mpu6050, the methods come from the same driver_methods! body
+// with every bus access and delay awaited.
+
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::I2c;
+use crate::driver::driver_methods;
+use crate::fifo::{FIFO_SIZE, FifoConfig, FifoRead};
+use crate::interrupt::{IntPinConfig, Interrupts};
+use crate::mpu6050::{
+    ACCEL_HPF_5HZ, ClockSource, Error, Measurement, RESET_ALL_PATHS, RESET_DELAY_MS, SELF_TEST_SAMPLES,
+    SELF_TEST_SETTLE_MS, State, WHO_AM_I_VALUE, accumulate, fifo_enable, int_pin_cfg, measurement, self_test_config,
+    self_test_report, words,
+};
+use crate::power::{self, Standby, WakeRate};
+use crate::range::{self, AccelRange, GyroRange};
+use crate::self_test::SelfTestReport;
+use crate::reg::{
+    self, AccelConfig, Config, GyroConfig, IntEnable, IntPinCfg, IntStatus, PwrMgmt1, PwrMgmt2, Register, UserCtrl,
+};
+
+pub struct Mpu6050<T> {
+    device_address: u8,
+    i2c_interface: T,
+    state: State,
+}
+
+impl<T: I2c> Mpu6050<T> {
+    driver_methods!(async await);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::range::GRAVITY;
+    use embedded_hal_mock::eh1::delay::{CheckedDelay, Transaction as DelayTransaction};
+    use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    use pollster::block_on;
+
+    const ADDR: u8 = 0x68;
+
+    fn read(reg: u8, response: &[u8]) -> Transaction {
+        Transaction::write_read(ADDR, vec![reg], response.to_vec())
+    }
+
+    fn write(reg: u8, value: u8) -> Transaction {
+        Transaction::write(ADDR, vec![reg, value])
+    }
+
+    #[test]
+    fn init_wakes_and_picks_up_the_ranges() {
+        let expectations = [
+            read(reg::MPU6050_REG_WHO_AM_I, &[WHO_AM_I_VALUE]),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x40]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x01),
+            read(reg::MPU6050_REG_ACCEL_CONFIG, &[0x18]),
+            read(reg::MPU6050_REG_GYRO_CONFIG, &[0x08]),
+        ];
+        let mut mpu = Mpu6050::new(Mock::new(&expectations), ADDR);
+
+        block_on(mpu.init()).unwrap();
+        assert_eq!(mpu.accel_range(), AccelRange::G16);
+        assert_eq!(mpu.gyro_range(), GyroRange::Dps500);
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn wrong_device_is_refused() {
+        let mut mpu = Mpu6050::new(Mock::new(&[read(reg::MPU6050_REG_WHO_AM_I, &[0x70])]), ADDR);
+        assert_eq!(block_on(mpu.init()), Err(Error::WrongDevice(0x70)));
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn read_all_in_one_burst() {
+        let mut burst = Vec::new();
+        for word in [100i16, -200, 16384, -340, 131, -262, 7] {
+            burst.extend_from_slice(&word.to_be_bytes());
+        }
+        let expectations = [
+            read(reg::MPU6050_REG_ACCEL_XOUT_H, &burst),
+            read(reg::MPU6050_REG_ACCEL_XOUT_H, &burst),
+        ];
+        let mut mpu = Mpu6050::new(Mock::new(&expectations), ADDR);
+
+        let raw = block_on(mpu.read_all()).unwrap();
+        assert_eq!(raw.accel, [100, -200, 16384]);
+        assert_eq!(raw.gyro, [131, -262, 7]);
+
+        let scaled = block_on(mpu.read_all_scaled()).unwrap();
+        assert!((scaled.accel[2] - GRAVITY).abs() < 1e-3);
+        assert!((scaled.gyro[1] + 2.0).abs() < 1e-3);
+        assert!((scaled.temperature - 35.53).abs() < 1e-3);
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn interrupts_read_along_are_kept() {
+        let expectations = [
+            // FIFO overflow and motion at once
+            read(reg::MPU6050_REG_INT_STATUS, &[0x50]),
+            read(reg::MPU6050_REG_FIFO_COUNTH, &[0x00, 0x0C]),
+            read(reg::MPU6050_REG_USER_CTRL, &[0x40]),
+            write(reg::MPU6050_REG_USER_CTRL, 0x04),
+            read(reg::MPU6050_REG_USER_CTRL, &[0x00]),
+            write(reg::MPU6050_REG_USER_CTRL, 0x40),
+            read(reg::MPU6050_REG_INT_STATUS, &[0x00]),
+        ];
+        let mut mpu = Mpu6050::new(Mock::new(&expectations), ADDR);
+
+        let mut buf = [0; 24];
+        assert_eq!(block_on(mpu.read_fifo(&mut buf)).unwrap(), FifoRead::Overflow);
+        assert!(block_on(mpu.motion_detected()).unwrap());
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn wake_on_motion_sequence() {
+        let expectations = [
+            read(reg::MPU6050_REG_ACCEL_CONFIG, &[0x00]),
+            write(reg::MPU6050_REG_ACCEL_CONFIG, ACCEL_HPF_5HZ),
+            write(reg::MPU6050_REG_MOT_THR, 20),
+            write(reg::MPU6050_REG_MOT_DUR, 1),
+            read(reg::MPU6050_REG_INT_ENABLE, &[0x00]),
+            write(reg::MPU6050_REG_INT_ENABLE, 0x40),
+            // stale motion is dropped
+            read(reg::MPU6050_REG_INT_STATUS, &[0x40]),
+            read(reg::MPU6050_REG_PWR_MGMT_2, &[0x00]),
+            write(reg::MPU6050_REG_PWR_MGMT_2, 0xC7),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x01]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x28),
+            read(reg::MPU6050_REG_INT_STATUS, &[0x00]),
+        ];
+        let mut mpu = Mpu6050::new(Mock::new(&expectations), ADDR);
+
+        block_on(mpu.enable_wake_on_motion(20, 1, WakeRate::Hz40)).unwrap();
+        assert!(!block_on(mpu.motion_detected()).unwrap());
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn reset_waits_on_the_async_delay() {
+        let expectations = [
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x80),
+            write(reg::MPU6050_REG_SIGNAL_PATH_RESET, RESET_ALL_PATHS),
+        ];
+        let delays = [
+            DelayTransaction::async_delay_ms(RESET_DELAY_MS),
+            DelayTransaction::async_delay_ms(RESET_DELAY_MS),
+        ];
+        let mut mpu = Mpu6050::new(Mock::new(&expectations), ADDR);
+        let mut delay = CheckedDelay::new(&delays);
+
+        block_on(mpu.reset(&mut delay)).unwrap();
+        mpu.i2c_mut().done();
+        delay.done();
+    }
+
+    #[test]
+    fn disable_fifo_stops_filling_it() {
+        let expectations = [
+            read(reg::MPU6050_REG_USER_CTRL, &[0x40]),
+            write(reg::MPU6050_REG_USER_CTRL, 0x00),
+            write(reg::MPU6050_REG_FIFO_EN, 0x00),
+        ];
+        let mut mpu = Mpu6050::new(Mock::new(&expectations), ADDR);
+
+        block_on(mpu.disable_fifo()).unwrap();
+        assert_eq!(mpu.fifo_config(), FifoConfig::default());
+        mpu.i2c_mut().done();
+    }
+}
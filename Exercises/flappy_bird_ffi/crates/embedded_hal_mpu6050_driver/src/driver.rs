@@ -0,0 +1,420 @@
+// the register sequences of the MPU6050 driver, written once for the blocking
+// mpu6050::Mpu6050 and the async asynch::Mpu6050.
+//
+//   impl<T: I2c> Mpu6050<T> { driver_methods!(); }             blocking
+//   impl<T: I2c> Mpu6050<T> { driver_methods!(async await); }  every bus access and delay awaited
+//
+// names are resolved where the macro is used: that module declares the struct
+// (device_address, i2c_interface, state) and imports its own I2c and DelayNs, the
+// registers and the helpers of mpu6050.rs.
+
+macro_rules! driver_methods {
+    ($($async:tt $await:tt)?) => {
+        pub fn new(i2c_interface: T, device_address: u8) -> Self {
+            Self {
+                device_address,
+                i2c_interface,
+                state: State::default(),
+            }
+        }
+
+        //the bus the sensor sits on
+        pub fn i2c_mut(&mut self) -> &mut T {
+            &mut self.i2c_interface
+        }
+
+        //minimal init code
+        // checks WHO_AM_I, then clears the SLEEP bit of the register PWR_MGMT_1 of mpu6050 sensor
+        //after powerup the sensor sets this bit to 1(SLEEP bit = 1), putting the device into low-power mode
+        //runs on the x gyro PLL and picks up the ranges, which survive a reset of the microcontroller
+        pub $($async)? fn init(&mut self) -> Result<(), Error<T::Error>> {
+            self.probe()$(.$await)??;
+            self.modify_register(|pwr: PwrMgmt1| pwr.with_sleep(false).with_clksel(ClockSource::PllGyroX as u8))
+                $(.$await)??;
+            self.read_register::<AccelConfig>()$(.$await)??;
+            self.read_register::<GyroConfig>()$(.$await)??;
+            Ok(())
+        }
+
+        //checks that an MPU6050 answers at the address
+        pub $($async)? fn probe(&mut self) -> Result<(), Error<T::Error>> {
+            match self.read_byte(reg::MPU6050_REG_WHO_AM_I)$(.$await)?? {
+                WHO_AM_I_VALUE => Ok(()),
+                id => Err(Error::WrongDevice(id)),
+            }
+        }
+
+        //resets all registers and the signal paths, the sensor is asleep again afterwards,
+        //init() wakes it up
+        pub $($async)? fn reset(&mut self, delay: &mut impl DelayNs) -> Result<(), Error<T::Error>> {
+            self.write_register(PwrMgmt1::default().with_device_reset(true))$(.$await)??;
+            delay.delay_ms(RESET_DELAY_MS)$(.$await)?;
+            self.write_byte(reg::MPU6050_REG_SIGNAL_PATH_RESET, RESET_ALL_PATHS)$(.$await)??;
+            delay.delay_ms(RESET_DELAY_MS)$(.$await)?;
+
+            self.state = State::default();
+            Ok(())
+        }
+
+        pub $($async)? fn set_clock_source(&mut self, source: ClockSource) -> Result<(), Error<T::Error>> {
+            self.modify_register(|pwr: PwrMgmt1| pwr.with_clksel(source as u8))$(.$await)?
+        }
+
+        //low power sleep, the registers keep their values
+        pub $($async)? fn sleep(&mut self) -> Result<(), Error<T::Error>> {
+            self.modify_register(|pwr: PwrMgmt1| pwr.with_sleep(true))$(.$await)?
+        }
+
+        //out of sleep, cycle mode is left with disable_cycle_mode()
+        pub $($async)? fn wake(&mut self) -> Result<(), Error<T::Error>> {
+            self.modify_register(|pwr: PwrMgmt1| pwr.with_sleep(false))$(.$await)?
+        }
+
+        //accelerometer only: the sensor sleeps and wakes up for one accel sample at `rate`,
+        //gyros and temperature sensor are off. the motion interrupt keeps working
+        pub $($async)? fn enable_cycle_mode(&mut self, rate: WakeRate) -> Result<(), Error<T::Error>> {
+            self.modify_register(|pwr: PwrMgmt2| power::cycle_standby(pwr, rate))$(.$await)??;
+            let pwr = self.read_register::<PwrMgmt1>()$(.$await)??;
+            if !pwr.cycle() {
+                self.state.before_cycle = Some(pwr);
+            }
+            self.write_register(power::cycle_mode(pwr))$(.$await)?
+        }
+
+        //back to continuous sampling, on the clock and with the temperature sensor as before
+        //cycle mode
+        pub $($async)? fn disable_cycle_mode(&mut self) -> Result<(), Error<T::Error>> {
+            // the gyros run before a gyro PLL becomes the clock
+            self.modify_register(power::gyro_standby_off)$(.$await)??;
+            let before = self.state.before_cycle.take();
+            self.modify_register(|pwr: PwrMgmt1| power::continuous_mode(pwr, before))$(.$await)?
+        }
+
+        //axes in standby read 0 and draw no current
+        pub $($async)? fn set_standby(&mut self, standby: Standby) -> Result<(), Error<T::Error>> {
+            self.modify_register(|pwr: PwrMgmt2| standby.apply(pwr))$(.$await)?
+        }
+
+        pub $($async)? fn standby(&mut self) -> Result<Standby, Error<T::Error>> {
+            Ok(Standby::from_register(self.read_register()$(.$await)??))
+        }
+
+        pub $($async)? fn set_temperature_enabled(&mut self, enabled: bool) -> Result<(), Error<T::Error>> {
+            self.modify_register(|pwr: PwrMgmt1| pwr.with_temp_dis(!enabled))$(.$await)?
+        }
+
+        //cycle mode with the motion interrupt, for a board put aside: the sensor samples at
+        //`rate` and raises MOTION (and the INT pin) once it is picked up, see motion_detected().
+        //threshold and duration as for enable_motion_interrupt
+        pub $($async)? fn enable_wake_on_motion(
+            &mut self,
+            threshold: u8,
+            duration_ms: u8,
+            rate: WakeRate,
+        ) -> Result<(), Error<T::Error>> {
+            self.enable_motion_interrupt(threshold, duration_ms)$(.$await)??;
+            // motion from before does not count
+            self.take_interrupts(Interrupts::MOTION)$(.$await)??;
+            self.enable_cycle_mode(rate)$(.$await)?
+        }
+
+        pub $($async)? fn disable_wake_on_motion(&mut self) -> Result<(), Error<T::Error>> {
+            self.disable_cycle_mode()$(.$await)??;
+            self.disable_interrupts(Interrupts::MOTION)$(.$await)?
+        }
+
+        //factory self-test of all six axes, for a diagnostics screen. the board has to keep
+        //still for about half a second. the ranges are put back afterwards
+        pub $($async)? fn self_test(&mut self, delay: &mut impl DelayNs) -> Result<SelfTestReport, Error<T::Error>> {
+            let accel = self.read_register::<AccelConfig>()$(.$await)??;
+            let gyro = self.read_register::<GyroConfig>()$(.$await)??;
+
+            let off = self.self_test_sum(accel, gyro, false, delay)$(.$await)??;
+            let on = self.self_test_sum(accel, gyro, true, delay)$(.$await)??;
+            let mut trim = [0; 4];
+            self.read_n_byte(reg::MPU6050_REG_SELF_TEST_X, &mut trim)$(.$await)??;
+
+            self.write_register(accel)$(.$await)??;
+            self.write_register(gyro)$(.$await)??;
+            delay.delay_ms(SELF_TEST_SETTLE_MS)$(.$await)?;
+            Ok(self_test_report(trim, off, on))
+        }
+
+        // sum of SELF_TEST_SAMPLES readings at the self-test ranges
+        $($async)? fn self_test_sum(
+            &mut self,
+            accel: AccelConfig,
+            gyro: GyroConfig,
+            on: bool,
+            delay: &mut impl DelayNs,
+        ) -> Result<Measurement<i32>, Error<T::Error>> {
+            let (accel, gyro) = self_test_config(accel, gyro, on);
+            self.write_register(accel)$(.$await)??;
+            self.write_register(gyro)$(.$await)??;
+            delay.delay_ms(SELF_TEST_SETTLE_MS)$(.$await)?;
+
+            let mut sum = Measurement::default();
+            for _ in 0..SELF_TEST_SAMPLES {
+                accumulate(&mut sum, self.read_all()$(.$await)??);
+                delay.delay_ms(1)$(.$await)?;
+            }
+            Ok(sum)
+        }
+
+        pub $($async)? fn read_register<R: Register>(&mut self) -> Result<R, Error<T::Error>> {
+            let value = self.read_byte(R::ADDR)$(.$await)??;
+            self.state.track(R::ADDR, value);
+            Ok(R::from_bits(value))
+        }
+
+        pub $($async)? fn write_register<R: Register>(&mut self, value: R) -> Result<(), Error<T::Error>> {
+            self.write_byte(R::ADDR, value.bits())$(.$await)??;
+            self.state.track(R::ADDR, value.bits());
+            Ok(())
+        }
+
+        //read-modify-write, the bits `f` leaves alone keep their value
+        pub $($async)? fn modify_register<R: Register>(
+            &mut self,
+            f: impl FnOnce(R) -> R,
+        ) -> Result<(), Error<T::Error>> {
+            let value = self.read_register::<R>()$(.$await)??;
+            self.write_register(f(value))$(.$await)?
+        }
+
+        pub $($async)? fn set_accel_range(&mut self, range: AccelRange) -> Result<(), Error<T::Error>> {
+            self.modify_register(|accel: AccelConfig| accel.with_afs_sel(range.bits()))$(.$await)?
+        }
+
+        pub $($async)? fn set_gyro_range(&mut self, range: GyroRange) -> Result<(), Error<T::Error>> {
+            self.modify_register(|gyro: GyroConfig| gyro.with_fs_sel(range.bits()))$(.$await)?
+        }
+
+        pub fn accel_range(&self) -> AccelRange {
+            self.state.accel_range
+        }
+
+        pub fn gyro_range(&self) -> GyroRange {
+            self.state.gyro_range
+        }
+
+        $($async)? fn write_byte(&mut self, reg_addr: u8, reg_value: u8) -> Result<(), Error<T::Error>> {
+            let buf: [u8; 2] = [reg_addr, reg_value];
+            self.i2c_interface.write(self.device_address, &buf)$(.$await)?.map_err(Error::Bus)
+        }
+
+        $($async)? fn read_byte(&mut self, reg_addr: u8) -> Result<u8, Error<T::Error>> {
+            let mut read_buf = [0u8];
+            self.read_n_byte(reg_addr, &mut read_buf)$(.$await)??;
+            Ok(read_buf[0])
+        }
+
+        $($async)? fn read_n_byte(&mut self, reg_addr: u8, read_buf: &mut [u8]) -> Result<(), Error<T::Error>> {
+            self.i2c_interface
+                .write_read(self.device_address, &[reg_addr], read_buf)
+                $(.$await)?
+                .map_err(Error::Bus)
+        }
+
+        pub $($async)? fn read_accel_data_raw(&mut self) -> Result<[i16; 3], Error<T::Error>> {
+            let mut buf = [0; 6];
+            self.read_n_byte(reg::MPU6050_REG_ACCEL_XOUT_H, &mut buf)$(.$await)??;
+            Ok(words(&buf))
+        }
+
+        pub $($async)? fn read_gyro_raw(&mut self) -> Result<[i16; 3], Error<T::Error>> {
+            let mut buf = [0; 6];
+            self.read_n_byte(reg::MPU6050_REG_GYRO_XOUT_H, &mut buf)$(.$await)??;
+            Ok(words(&buf))
+        }
+
+        pub $($async)? fn read_temperature_raw(&mut self) -> Result<i16, Error<T::Error>> {
+            let mut buf = [0; 2];
+            self.read_n_byte(reg::MPU6050_REG_TEMP_OUT_H, &mut buf)$(.$await)??;
+            Ok(i16::from_be_bytes(buf))
+        }
+
+        //accel, temperature and gyro in one 14 byte burst, so all of them are from the same sample
+        pub $($async)? fn read_all(&mut self) -> Result<Measurement<i16>, Error<T::Error>> {
+            let mut buf = [0; 14];
+            self.read_n_byte(reg::MPU6050_REG_ACCEL_XOUT_H, &mut buf)$(.$await)??;
+            Ok(measurement(&buf))
+        }
+
+        //acceleration in m/s^2 at the configured full scale
+        pub $($async)? fn read_accel(&mut self) -> Result<[f32; 3], Error<T::Error>> {
+            let range = self.state.accel_range;
+            Ok(self.read_accel_data_raw()$(.$await)??.map(|a| range.to_ms2(a)))
+        }
+
+        //rotation in degrees/s at the configured full scale
+        pub $($async)? fn read_gyro(&mut self) -> Result<[f32; 3], Error<T::Error>> {
+            let range = self.state.gyro_range;
+            Ok(self.read_gyro_raw()$(.$await)??.map(|g| range.to_dps(g)))
+        }
+
+        //die temperature in degrees C
+        pub $($async)? fn read_temperature(&mut self) -> Result<f32, Error<T::Error>> {
+            Ok(range::temperature_celsius(self.read_temperature_raw()$(.$await)??))
+        }
+
+        //read_all in m/s^2, degrees C and degrees/s
+        pub $($async)? fn read_all_scaled(&mut self) -> Result<Measurement<f32>, Error<T::Error>> {
+            let raw = self.read_all()$(.$await)??;
+            Ok(self.state.scaled(raw))
+        }
+
+        //acceleration in milli g, without floats
+        pub $($async)? fn read_accel_mg(&mut self) -> Result<[i32; 3], Error<T::Error>> {
+            let range = self.state.accel_range;
+            Ok(self.read_accel_data_raw()$(.$await)??.map(|a| range.to_mg(a)))
+        }
+
+        //rotation in milli degrees/s, without floats
+        pub $($async)? fn read_gyro_mdps(&mut self) -> Result<[i32; 3], Error<T::Error>> {
+            let range = self.state.gyro_range;
+            Ok(self.read_gyro_raw()$(.$await)??.map(|g| range.to_mdps(g)))
+        }
+
+        //read_all in milli g, hundredths of a degree C and milli degrees/s, without floats
+        pub $($async)? fn read_all_fixed(&mut self) -> Result<Measurement<i32>, Error<T::Error>> {
+            let raw = self.read_all()$(.$await)??;
+            Ok(self.state.fixed(raw))
+        }
+
+        //motion detection interrupt: `threshold` in 2 mg steps, `duration_ms` above it in 1 ms steps.
+        //also turns on the 5 Hz high pass filter of the motion detector, so gravity does not count
+        pub $($async)? fn enable_motion_interrupt(
+            &mut self,
+            threshold: u8,
+            duration_ms: u8,
+        ) -> Result<(), Error<T::Error>> {
+            self.modify_register(|accel: AccelConfig| accel.with_accel_hpf(ACCEL_HPF_5HZ))$(.$await)??;
+            self.write_byte(reg::MPU6050_REG_MOT_THR, threshold)$(.$await)??;
+            self.write_byte(reg::MPU6050_REG_MOT_DUR, duration_ms)$(.$await)??;
+            self.enable_interrupts(Interrupts::MOTION)$(.$await)?
+        }
+
+        //true if motion was detected since the last call, reading INT_STATUS clears it
+        pub $($async)? fn motion_detected(&mut self) -> Result<bool, Error<T::Error>> {
+            self.take_interrupts(Interrupts::MOTION)$(.$await)?
+        }
+
+        //sample rate = gyro output rate (8 kHz, 1 kHz with the low pass filter on) / (1 + divider)
+        pub $($async)? fn set_sample_rate_divider(&mut self, divider: u8) -> Result<(), Error<T::Error>> {
+            self.write_byte(reg::MPU6050_REG_SMPLRT_DIV, divider)$(.$await)?
+        }
+
+        //starts filling the FIFO with `config`'s measurements, from empty
+        pub $($async)? fn enable_fifo(&mut self, config: FifoConfig) -> Result<(), Error<T::Error>> {
+            self.write_register(fifo_enable(config))$(.$await)??;
+            // the overflow only shows in INT_STATUS with its interrupt enabled
+            self.enable_interrupts(Interrupts::FIFO_OVERFLOW)$(.$await)??;
+            self.state.fifo = config;
+            self.reset_fifo()$(.$await)?
+        }
+
+        pub $($async)? fn disable_fifo(&mut self) -> Result<(), Error<T::Error>> {
+            self.modify_register(|user: UserCtrl| user.with_fifo_en(false))$(.$await)??;
+            self.write_register(fifo_enable(FifoConfig::default()))$(.$await)??;
+            self.state.fifo = FifoConfig::default();
+            Ok(())
+        }
+
+        pub fn fifo_config(&self) -> FifoConfig {
+            self.state.fifo
+        }
+
+        //bytes waiting in the FIFO
+        pub $($async)? fn fifo_count(&mut self) -> Result<u16, Error<T::Error>> {
+            let mut buf = [0; 2];
+            self.read_n_byte(reg::MPU6050_REG_FIFO_COUNTH, &mut buf)$(.$await)??;
+            Ok(u16::from_be_bytes(buf))
+        }
+
+        //empties the FIFO and keeps it running
+        pub $($async)? fn reset_fifo(&mut self) -> Result<(), Error<T::Error>> {
+            // the reset bit clears itself
+            self.modify_register(|user: UserCtrl| user.with_fifo_en(false).with_fifo_reset(true))$(.$await)??;
+            self.modify_register(|user: UserCtrl| user.with_fifo_en(true).with_fifo_reset(false))$(.$await)??;
+            self.state.int_status = self.state.int_status & !Interrupts::FIFO_OVERFLOW;
+            Ok(())
+        }
+
+        //moves as many whole frames as are waiting and fit into `buf`, in one burst.
+        //FifoConfig::measurements takes them apart. after an overflow the frames are
+        //no longer aligned, so the FIFO is emptied and FifoRead::Overflow returned
+        pub $($async)? fn read_fifo(&mut self, buf: &mut [u8]) -> Result<FifoRead, Error<T::Error>> {
+            let overflow = self.take_interrupts(Interrupts::FIFO_OVERFLOW)$(.$await)??;
+            let count = self.fifo_count()$(.$await)?? as usize;
+
+            if overflow || count >= FIFO_SIZE {
+                self.reset_fifo()$(.$await)??;
+                return Ok(FifoRead::Overflow);
+            }
+
+            let frames = self.state.fifo_frames(count, buf.len());
+            if frames > 0 {
+                let len = frames * self.state.fifo.frame_len();
+                self.read_n_byte(reg::MPU6050_REG_FIFO_R_W, &mut buf[..len])$(.$await)??;
+            }
+            Ok(FifoRead::Frames(frames))
+        }
+
+        //adds `interrupts` to the enabled ones, they show in INT_STATUS and on the INT pin
+        pub $($async)? fn enable_interrupts(&mut self, interrupts: Interrupts) -> Result<(), Error<T::Error>> {
+            self.modify_register(|int: IntEnable| IntEnable(int.bits() | interrupts.bits()))$(.$await)?
+        }
+
+        pub $($async)? fn disable_interrupts(&mut self, interrupts: Interrupts) -> Result<(), Error<T::Error>> {
+            self.modify_register(|int: IntEnable| IntEnable(int.bits() & !interrupts.bits()))$(.$await)?
+        }
+
+        pub $($async)? fn enabled_interrupts(&mut self) -> Result<Interrupts, Error<T::Error>> {
+            Ok(Interrupts::from_bits(self.read_register::<IntEnable>()$(.$await)??.bits()))
+        }
+
+        //INT pin level, drive and latching, FSYNC and I2C bypass stay as they are
+        pub $($async)? fn configure_interrupt_pin(&mut self, config: IntPinConfig) -> Result<(), Error<T::Error>> {
+            self.modify_register(|pin: IntPinCfg| int_pin_cfg(pin, config))$(.$await)?
+        }
+
+        //interrupts raised since the last call, reading INT_STATUS clears them on the sensor
+        pub $($async)? fn interrupt_status(&mut self) -> Result<Interrupts, Error<T::Error>> {
+            let status = self.read_int_status()$(.$await)??;
+            self.state.int_status = Interrupts::NONE;
+            Ok(status)
+        }
+
+        //for the INT pin's EXTI handler or a task waiting on the pin: the raised interrupts
+        //which are also enabled, what the C driver handed to its callbacks
+        pub $($async)? fn handle_interrupt(&mut self) -> Result<Interrupts, Error<T::Error>> {
+            let status = self.interrupt_status()$(.$await)??;
+            Ok(status & self.enabled_interrupts()$(.$await)??)
+        }
+
+        // INT_STATUS together with the bits an earlier read left
+        $($async)? fn read_int_status(&mut self) -> Result<Interrupts, Error<T::Error>> {
+            let status = self.read_register::<IntStatus>()$(.$await)??;
+            Ok(self.state.merge_status(status))
+        }
+
+        // true if one of `interrupts` was raised, the others stay for later
+        $($async)? fn take_interrupts(&mut self, interrupts: Interrupts) -> Result<bool, Error<T::Error>> {
+            let status = self.read_int_status()$(.$await)??;
+            Ok(self.state.take(status, interrupts))
+        }
+
+        //DLPF_CFG 0..=6, 7 is reserved
+        pub $($async)? fn set_low_pass_filter(&mut self, dlpf_cfg: u8) -> Result<(), Error<T::Error>> {
+            if dlpf_cfg > 6 {
+                return Err(Error::InvalidConfig);
+            }
+
+            // Write dlpf_cfg to CONFIG register, EXT_SYNC_SET stays
+            self.modify_register(|config: Config| config.with_dlpf_cfg(dlpf_cfg))$(.$await)?
+        }
+    };
+}
+
+pub(crate) use driver_methods;
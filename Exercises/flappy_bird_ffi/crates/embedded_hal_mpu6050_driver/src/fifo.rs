@@ -0,0 +1,98 @@
+// what goes into the 1024 byte FIFO and how to take its frames apart.
+// the sensor writes one frame per sample, the enabled measurements in register
+// order (accel x, y, z, temperature, gyro x, y, z), two bytes each, high byte first.
+
+use crate::mpu6050::Measurement;
+
+pub const FIFO_SIZE: usize = 1024;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct FifoConfig {
+    pub accel: bool,
+    pub temperature: bool,
+    pub gyro: bool,
+}
+
+impl FifoConfig {
+    pub const ACCEL_GYRO: Self = Self {
+        accel: true,
+        temperature: false,
+        gyro: true,
+    };
+
+    //bytes per sample
+    pub fn frame_len(&self) -> usize {
+        6 * self.accel as usize + 2 * self.temperature as usize + 6 * self.gyro as usize
+    }
+
+    //one frame of frame_len() bytes, measurements which are not in the FIFO read as 0
+    pub fn parse(&self, frame: &[u8]) -> Measurement<i16> {
+        let mut words = frame
+            .chunks_exact(2)
+            .map(|w| i16::from_be_bytes([w[0], w[1]]));
+        let mut next = |on: bool| if on { words.next().unwrap_or(0) } else { 0 };
+
+        let accel = [next(self.accel), next(self.accel), next(self.accel)];
+        let temperature = next(self.temperature);
+        let gyro = [next(self.gyro), next(self.gyro), next(self.gyro)];
+        Measurement {
+            accel,
+            temperature,
+            gyro,
+        }
+    }
+
+    //the whole frames in `data`, as read_fifo left them
+    pub fn measurements<'a>(&'a self, data: &'a [u8]) -> impl Iterator<Item = Measurement<i16>> + 'a {
+        data.chunks_exact(self.frame_len().max(1))
+            .map(|frame| self.parse(frame))
+    }
+}
+
+// outcome of Mpu6050::read_fifo
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FifoRead {
+    // whole frames now at the start of the buffer
+    Frames(usize),
+    // the FIFO ran full and lost data, it was emptied and fills again from now
+    Overflow,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_layout() {
+        assert_eq!(FifoConfig::ACCEL_GYRO.frame_len(), 12);
+        assert_eq!(FifoConfig::default().frame_len(), 0);
+
+        let config = FifoConfig {
+            accel: false,
+            temperature: true,
+            gyro: true,
+        };
+        assert_eq!(config.frame_len(), 8);
+        let frame = [0xFE, 0xAC, 0x00, 0x83, 0xFF, 0x7D, 0x00, 0x07];
+        assert_eq!(
+            config.parse(&frame),
+            Measurement {
+                accel: [0, 0, 0],
+                temperature: -340,
+                gyro: [131, -131, 7],
+            }
+        );
+    }
+
+    #[test]
+    fn measurements_skip_partial_frames() {
+        let config = FifoConfig {
+            accel: true,
+            temperature: false,
+            gyro: false,
+        };
+        let data = [0, 1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0, 7];
+        let frames: Vec<_> = config.measurements(&data).map(|m| m.accel).collect();
+        assert_eq!(frames, [[1, 2, 3], [4, 5, 6]]);
+    }
+}
@@ -0,0 +1,103 @@
+// interrupt sources (the bits of INT_ENABLE and INT_STATUS) and the INT pin setup
+
+use core::ops::{BitAnd, BitOr, BitOrAssign, Not};
+
+// set of interrupt sources, combined with `|`
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Interrupts(u8);
+
+impl Interrupts {
+    pub const NONE: Self = Self(0);
+    pub const DATA_READY: Self = Self(0x01);
+    pub const I2C_MASTER: Self = Self(0x08);
+    pub const FIFO_OVERFLOW: Self = Self(0x10);
+    pub const MOTION: Self = Self(0x40);
+    pub const ALL: Self = Self(0x59);
+
+    //register value, bits which are no interrupt source are dropped
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits & Self::ALL.0)
+    }
+
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for Interrupts {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Interrupts {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for Interrupts {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl Not for Interrupts {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self(!self.0 & Self::ALL.0)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum IntLevel {
+    #[default]
+    ActiveHigh,
+    ActiveLow,
+}
+
+// INT_PIN_CFG, the default is the power-on state: active high push-pull 50 us pulses
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct IntPinConfig {
+    pub level: IntLevel,
+    pub open_drain: bool,
+    // hold the pin until the interrupt is cleared instead of pulsing it
+    pub latch: bool,
+    // clear on any register read instead of only on reading INT_STATUS
+    pub clear_on_any_read: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flag_set_operations() {
+        let both = Interrupts::MOTION | Interrupts::DATA_READY;
+        assert_eq!(both.bits(), 0x41);
+        assert!(both.contains(Interrupts::MOTION));
+        assert!(!both.contains(Interrupts::MOTION | Interrupts::FIFO_OVERFLOW));
+        assert_eq!(both & Interrupts::MOTION, Interrupts::MOTION);
+        assert_eq!(!both, Interrupts::I2C_MASTER | Interrupts::FIFO_OVERFLOW);
+        assert!((both & Interrupts::I2C_MASTER).is_empty());
+    }
+
+    #[test]
+    fn unknown_bits_are_dropped() {
+        assert_eq!(Interrupts::from_bits(0xFF), Interrupts::ALL);
+        assert_eq!(Interrupts::from_bits(0x06), Interrupts::NONE);
+    }
+}
@@ -6,11 +6,588 @@
 // this makes your driver usable outside the crate
 pub mod mpu6050;
 
-// this keeps this module private to teh crate. no need to expose constants unless needed)
-mod reg;
+// the method bodies shared by the blocking and the async driver
+mod driver;
 
-//this is the placeholder for unit tests. 
+// the same driver on embedded-hal-async
+#[cfg(feature = "async")]
+pub mod asynch;
+
+// FIFO frame layout
+pub mod fifo;
+
+// interrupt sources and INT pin setup
+pub mod interrupt;
+
+// sleep, cycle mode and standby
+pub mod power;
+
+// full scale ranges and unit conversion
+pub mod range;
+
+// factory self-test trims and the per axis result
+pub mod self_test;
+
+// register addresses and the typed registers for read_register / modify_register
+pub mod reg;
+
+// the driver against embedded-hal-mock: every test lists the I2C transactions
+// it expects, in order, and done() checks that nothing else went over the bus.
 #[cfg(test)]
 mod tests {
     use super::*;
+    use embedded_hal_mock::eh1::delay::{CheckedDelay, Transaction as DelayTransaction};
+    use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    use embedded_hal::i2c::ErrorKind;
+    use fifo::{FifoConfig, FifoRead};
+    use interrupt::{IntLevel, IntPinConfig, Interrupts};
+    use mpu6050::{ClockSource, Error, Measurement, Mpu6050, WHO_AM_I_VALUE};
+    use power::{Standby, WakeRate};
+    use range::{AccelRange, GyroRange, GRAVITY};
+    use reg::{AccelConfig, GyroConfig};
+
+    const ADDR: u8 = 0x68;
+
+    fn read(reg: u8, response: &[u8]) -> Transaction {
+        Transaction::write_read(ADDR, vec![reg], response.to_vec())
+    }
+
+    fn write(reg: u8, value: u8) -> Transaction {
+        Transaction::write(ADDR, vec![reg, value])
+    }
+
+    fn sensor(expectations: &[Transaction]) -> Mpu6050<Mock> {
+        Mpu6050::new(Mock::new(expectations), ADDR)
+    }
+
+    fn words(words: &[i16]) -> Vec<u8> {
+        words.iter().flat_map(|word| word.to_be_bytes()).collect()
+    }
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn init_probes_wakes_and_reads_the_ranges() {
+        let mut mpu = sensor(&[
+            read(reg::MPU6050_REG_WHO_AM_I, &[WHO_AM_I_VALUE]),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x40]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x01),
+            read(reg::MPU6050_REG_ACCEL_CONFIG, &[0x10]),
+            read(reg::MPU6050_REG_GYRO_CONFIG, &[0x18]),
+        ]);
+        mpu.init().unwrap();
+        assert_eq!(mpu.accel_range(), AccelRange::G8);
+        assert_eq!(mpu.gyro_range(), GyroRange::Dps2000);
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn init_stops_at_a_wrong_device() {
+        let mut mpu = sensor(&[read(reg::MPU6050_REG_WHO_AM_I, &[0x70])]);
+        assert_eq!(mpu.init(), Err(Error::WrongDevice(0x70)));
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn bus_errors_are_passed_on() {
+        let mut mpu = sensor(&[
+            read(reg::MPU6050_REG_WHO_AM_I, &[WHO_AM_I_VALUE]),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x40]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x01).with_error(ErrorKind::Other),
+        ]);
+        assert_eq!(mpu.init(), Err(Error::Bus(ErrorKind::Other)));
+        mpu.i2c_mut().done();
+
+        // a failed read is not followed by the write of read-modify-write
+        let mut mpu = sensor(&[read(reg::MPU6050_REG_ACCEL_CONFIG, &[0]).with_error(ErrorKind::Other)]);
+        assert_eq!(mpu.set_accel_range(AccelRange::G4), Err(Error::Bus(ErrorKind::Other)));
+        assert_eq!(mpu.accel_range(), AccelRange::G2);
+        mpu.i2c_mut().done();
+
+        let mut mpu = sensor(&[read(reg::MPU6050_REG_ACCEL_XOUT_H, &[0; 14]).with_error(ErrorKind::Other)]);
+        assert_eq!(mpu.read_all(), Err(Error::Bus(ErrorKind::Other)));
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn filter_and_sample_rate() {
+        let mut mpu = sensor(&[
+            // EXT_SYNC_SET stays
+            read(reg::MPU6050_REG_CONFIG, &[0x28]),
+            write(reg::MPU6050_REG_CONFIG, 0x2B),
+            write(reg::MPU6050_REG_SMPLRT_DIV, 9),
+        ]);
+        mpu.set_low_pass_filter(3).unwrap();
+        // the reserved setting never reaches the bus
+        assert_eq!(mpu.set_low_pass_filter(7), Err(Error::InvalidConfig));
+        mpu.set_sample_rate_divider(9).unwrap();
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn negative_values_are_big_endian() {
+        let burst = words(&[-2, i16::MIN, i16::MAX, -340, -131, 131, -1000]);
+        let mut mpu = sensor(&[
+            read(reg::MPU6050_REG_ACCEL_XOUT_H, &[0xFF, 0xFE, 0x80, 0x00, 0x7F, 0xFF]),
+            read(reg::MPU6050_REG_GYRO_XOUT_H, &[0xFF, 0x7D, 0x00, 0x83, 0xFC, 0x18]),
+            read(reg::MPU6050_REG_TEMP_OUT_H, &[0xFE, 0xAC]),
+            read(reg::MPU6050_REG_ACCEL_XOUT_H, &burst),
+            read(reg::MPU6050_REG_FIFO_COUNTH, &[0x01, 0x02]),
+        ]);
+        assert_eq!(mpu.read_accel_data_raw().unwrap(), [-2, i16::MIN, i16::MAX]);
+        assert_eq!(mpu.read_gyro_raw().unwrap(), [-131, 131, -1000]);
+        assert_eq!(mpu.read_temperature_raw().unwrap(), -340);
+        assert_eq!(
+            mpu.read_all().unwrap(),
+            Measurement {
+                accel: [-2, i16::MIN, i16::MAX],
+                temperature: -340,
+                gyro: [-131, 131, -1000],
+            }
+        );
+        assert_eq!(mpu.fifo_count().unwrap(), 258);
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn scaled_reads_follow_the_written_range() {
+        let mut mpu = sensor(&[
+            // the motion high pass filter bit stays
+            read(reg::MPU6050_REG_ACCEL_CONFIG, &[0x01]),
+            write(reg::MPU6050_REG_ACCEL_CONFIG, 0x19),
+            read(reg::MPU6050_REG_ACCEL_XOUT_H, &words(&[-2048, 2048, 0])),
+            read(reg::MPU6050_REG_GYRO_CONFIG, &[0x00]),
+            write(reg::MPU6050_REG_GYRO_CONFIG, 0x08),
+            read(reg::MPU6050_REG_GYRO_XOUT_H, &words(&[-262, 131, 0])),
+        ]);
+        mpu.set_accel_range(AccelRange::G16).unwrap();
+        assert_eq!(mpu.read_accel_mg().unwrap(), [-1000, 1000, 0]);
+        mpu.set_gyro_range(GyroRange::Dps500).unwrap();
+        assert_eq!(mpu.read_gyro_mdps().unwrap(), [-4000, 2000, 0]);
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn float_and_fixed_point_reads() {
+        let burst = words(&[100, -200, 16384, -340, 131, -262, 7]);
+        let mut mpu = sensor(&[
+            read(reg::MPU6050_REG_TEMP_OUT_H, &words(&[-340])),
+            read(reg::MPU6050_REG_ACCEL_XOUT_H, &words(&[100, -200, 16384])),
+            read(reg::MPU6050_REG_GYRO_XOUT_H, &words(&[131, -262, 7])),
+            // +-8 g and +-1000 dps
+            read(reg::MPU6050_REG_ACCEL_CONFIG, &[0x00]),
+            write(reg::MPU6050_REG_ACCEL_CONFIG, 0x10),
+            read(reg::MPU6050_REG_GYRO_CONFIG, &[0x00]),
+            write(reg::MPU6050_REG_GYRO_CONFIG, 0x10),
+            read(reg::MPU6050_REG_ACCEL_XOUT_H, &burst),
+            read(reg::MPU6050_REG_ACCEL_XOUT_H, &burst),
+        ]);
+        assert!(close(mpu.read_temperature().unwrap(), 35.53));
+        assert!(close(mpu.read_accel().unwrap()[2], GRAVITY));
+        assert!(close(mpu.read_gyro().unwrap()[1], -2.0));
+
+        mpu.set_accel_range(AccelRange::G8).unwrap();
+        mpu.set_gyro_range(GyroRange::Dps1000).unwrap();
+        let all = mpu.read_all_scaled().unwrap();
+        assert!(close(all.accel[2], 4.0 * GRAVITY));
+        assert!(close(all.gyro[0], 4.0));
+        assert!(close(all.temperature, 35.53));
+
+        let fixed = mpu.read_all_fixed().unwrap();
+        assert_eq!(fixed.accel, [24, -48, 4000]);
+        assert_eq!(fixed.temperature, 3553);
+        assert_eq!(fixed.gyro, [4000, -8000, 213]);
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn typed_registers_keep_the_ranges() {
+        let mut mpu = sensor(&[
+            write(reg::MPU6050_REG_ACCEL_CONFIG, 0x08),
+            read(reg::MPU6050_REG_GYRO_CONFIG, &[0x08]),
+            read(reg::MPU6050_REG_ACCEL_XOUT_H, &words(&[0, 0, 16384])),
+            read(reg::MPU6050_REG_GYRO_XOUT_H, &words(&[131, 0, 0])),
+        ]);
+        mpu.write_register(AccelConfig(0x08)).unwrap();
+        assert_eq!(mpu.accel_range(), AccelRange::G4);
+        mpu.read_register::<GyroConfig>().unwrap();
+        assert_eq!(mpu.gyro_range(), GyroRange::Dps500);
+
+        assert_eq!(mpu.read_accel_mg().unwrap()[2], 2000);
+        assert_eq!(mpu.read_gyro_mdps().unwrap()[0], 2000);
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn reset_waits_after_each_step() {
+        let mut mpu = sensor(&[
+            read(reg::MPU6050_REG_ACCEL_CONFIG, &[0x00]),
+            write(reg::MPU6050_REG_ACCEL_CONFIG, 0x18),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x80),
+            write(reg::MPU6050_REG_SIGNAL_PATH_RESET, 0x07),
+        ]);
+        mpu.set_accel_range(AccelRange::G16).unwrap();
+
+        let mut delay = CheckedDelay::new(&[DelayTransaction::delay_ms(100), DelayTransaction::delay_ms(100)]);
+        mpu.reset(&mut delay).unwrap();
+        // the remembered settings are the power-up ones again
+        assert_eq!(mpu.accel_range(), AccelRange::G2);
+        assert_eq!(mpu.fifo_config(), FifoConfig::default());
+        mpu.i2c_mut().done();
+        delay.done();
+    }
+
+    #[test]
+    fn clock_sleep_and_temperature() {
+        let mut mpu = sensor(&[
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x01]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x00),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x00]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x40),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x40]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x00),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x00]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x08),
+        ]);
+        mpu.set_clock_source(ClockSource::Internal8MHz).unwrap();
+        mpu.sleep().unwrap();
+        mpu.wake().unwrap();
+        mpu.set_temperature_enabled(false).unwrap();
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn standby_and_cycle_mode() {
+        let standby = Standby {
+            accel: [true, false, false],
+            gyro: [false, false, true],
+        };
+        let mut mpu = sensor(&[
+            // LP_WAKE_CTRL stays
+            read(reg::MPU6050_REG_PWR_MGMT_2, &[0xC0]),
+            write(reg::MPU6050_REG_PWR_MGMT_2, 0xE1),
+            read(reg::MPU6050_REG_PWR_MGMT_2, &[0xE1]),
+            // 20 Hz, gyros in standby, then cycling on the internal oscillator
+            read(reg::MPU6050_REG_PWR_MGMT_2, &[0x00]),
+            write(reg::MPU6050_REG_PWR_MGMT_2, 0x87),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x01]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x28),
+            // gyros first, then back on their PLL
+            read(reg::MPU6050_REG_PWR_MGMT_2, &[0x87]),
+            write(reg::MPU6050_REG_PWR_MGMT_2, 0x80),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x28]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x01),
+        ]);
+        mpu.set_standby(standby).unwrap();
+        assert_eq!(mpu.standby().unwrap(), standby);
+        mpu.enable_cycle_mode(WakeRate::Hz20).unwrap();
+        mpu.disable_cycle_mode().unwrap();
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn cycle_mode_gives_back_the_clock_and_temperature_setting() {
+        let mut mpu = sensor(&[
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x01]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x03),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x03]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x0B),
+            read(reg::MPU6050_REG_PWR_MGMT_2, &[0x00]),
+            write(reg::MPU6050_REG_PWR_MGMT_2, 0x47),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x0B]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x28),
+            // z gyro PLL and the temperature sensor off again
+            read(reg::MPU6050_REG_PWR_MGMT_2, &[0x47]),
+            write(reg::MPU6050_REG_PWR_MGMT_2, 0x40),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x28]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x0B),
+        ]);
+        mpu.set_clock_source(ClockSource::PllGyroZ).unwrap();
+        mpu.set_temperature_enabled(false).unwrap();
+        mpu.enable_cycle_mode(WakeRate::Hz5).unwrap();
+        mpu.disable_cycle_mode().unwrap();
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn interrupt_setup_and_handling() {
+        let mut mpu = sensor(&[
+            read(reg::MPU6050_REG_INT_ENABLE, &[0x10]),
+            write(reg::MPU6050_REG_INT_ENABLE, 0x51),
+            // I2C bypass stays
+            read(reg::MPU6050_REG_INT_PIN_CFG, &[0x02]),
+            write(reg::MPU6050_REG_INT_PIN_CFG, 0xB2),
+            read(reg::MPU6050_REG_INT_STATUS, &[0x41]),
+            read(reg::MPU6050_REG_INT_ENABLE, &[0x40]),
+        ]);
+        mpu.enable_interrupts(Interrupts::DATA_READY | Interrupts::MOTION).unwrap();
+        mpu.configure_interrupt_pin(IntPinConfig {
+            level: IntLevel::ActiveLow,
+            open_drain: false,
+            latch: true,
+            clear_on_any_read: true,
+        })
+        .unwrap();
+        assert_eq!(mpu.handle_interrupt().unwrap(), Interrupts::MOTION);
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn interrupts_enable_disable_and_status() {
+        let mut mpu = sensor(&[
+            read(reg::MPU6050_REG_INT_ENABLE, &[0x08]),
+            write(reg::MPU6050_REG_INT_ENABLE, 0x49),
+            read(reg::MPU6050_REG_INT_ENABLE, &[0x49]),
+            write(reg::MPU6050_REG_INT_ENABLE, 0x09),
+            read(reg::MPU6050_REG_INT_ENABLE, &[0x09]),
+            read(reg::MPU6050_REG_INT_ENABLE, &[0x09]),
+            write(reg::MPU6050_REG_INT_ENABLE, 0x00),
+            read(reg::MPU6050_REG_INT_STATUS, &[0x11]),
+            // raised, but not enabled
+            read(reg::MPU6050_REG_INT_STATUS, &[0x01]),
+            read(reg::MPU6050_REG_INT_ENABLE, &[0x40]),
+            // FSYNC interrupt and I2C bypass stay
+            read(reg::MPU6050_REG_INT_PIN_CFG, &[0xE6]),
+            write(reg::MPU6050_REG_INT_PIN_CFG, 0x06),
+        ]);
+        mpu.enable_interrupts(Interrupts::DATA_READY | Interrupts::MOTION).unwrap();
+        mpu.disable_interrupts(Interrupts::MOTION).unwrap();
+        assert_eq!(mpu.enabled_interrupts().unwrap(), Interrupts::DATA_READY | Interrupts::I2C_MASTER);
+        mpu.disable_interrupts(Interrupts::ALL).unwrap();
+
+        assert_eq!(mpu.interrupt_status().unwrap(), Interrupts::FIFO_OVERFLOW | Interrupts::DATA_READY);
+        assert!(mpu.handle_interrupt().unwrap().is_empty());
+        mpu.configure_interrupt_pin(IntPinConfig::default()).unwrap();
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn motion_interrupt_and_wake_on_motion() {
+        let mut mpu = sensor(&[
+            read(reg::MPU6050_REG_ACCEL_CONFIG, &[0x08]),
+            write(reg::MPU6050_REG_ACCEL_CONFIG, 0x09),
+            write(reg::MPU6050_REG_MOT_THR, 10),
+            write(reg::MPU6050_REG_MOT_DUR, 5),
+            read(reg::MPU6050_REG_INT_ENABLE, &[0x00]),
+            write(reg::MPU6050_REG_INT_ENABLE, 0x40),
+            read(reg::MPU6050_REG_INT_STATUS, &[0x40]),
+            // out of wake on motion
+            read(reg::MPU6050_REG_PWR_MGMT_2, &[0x47]),
+            write(reg::MPU6050_REG_PWR_MGMT_2, 0x40),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x28]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x01),
+            read(reg::MPU6050_REG_INT_ENABLE, &[0x40]),
+            write(reg::MPU6050_REG_INT_ENABLE, 0x00),
+        ]);
+        mpu.enable_motion_interrupt(10, 5).unwrap();
+        assert!(mpu.motion_detected().unwrap());
+        mpu.disable_wake_on_motion().unwrap();
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn wake_on_motion_forgets_earlier_motion() {
+        let mut mpu = sensor(&[
+            read(reg::MPU6050_REG_ACCEL_CONFIG, &[0x00]),
+            write(reg::MPU6050_REG_ACCEL_CONFIG, 0x01),
+            write(reg::MPU6050_REG_MOT_THR, 20),
+            write(reg::MPU6050_REG_MOT_DUR, 1),
+            read(reg::MPU6050_REG_INT_ENABLE, &[0x00]),
+            write(reg::MPU6050_REG_INT_ENABLE, 0x40),
+            // motion seen before going to sleep
+            read(reg::MPU6050_REG_INT_STATUS, &[0x40]),
+            // 5 Hz, gyros in standby, cycling on the internal oscillator
+            read(reg::MPU6050_REG_PWR_MGMT_2, &[0x00]),
+            write(reg::MPU6050_REG_PWR_MGMT_2, 0x47),
+            read(reg::MPU6050_REG_PWR_MGMT_1, &[0x01]),
+            write(reg::MPU6050_REG_PWR_MGMT_1, 0x28),
+            read(reg::MPU6050_REG_INT_STATUS, &[0x00]),
+        ]);
+        mpu.enable_wake_on_motion(20, 1, WakeRate::Hz5).unwrap();
+        assert!(!mpu.motion_detected().unwrap());
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn fifo_frames_and_overflow() {
+        let frames: Vec<u8> = words(&[-1, 2, -3, 4, -5, 6, 7, -8, 9, -10, 11, -12]);
+        let mut mpu = sensor(&[
+            write(reg::MPU6050_REG_FIFO_EN, 0x78),
+            read(reg::MPU6050_REG_INT_ENABLE, &[0x00]),
+            write(reg::MPU6050_REG_INT_ENABLE, 0x10),
+            read(reg::MPU6050_REG_USER_CTRL, &[0x00]),
+            write(reg::MPU6050_REG_USER_CTRL, 0x04),
+            read(reg::MPU6050_REG_USER_CTRL, &[0x04]),
+            write(reg::MPU6050_REG_USER_CTRL, 0x40),
+            // 30 bytes waiting, two whole frames in one burst
+            read(reg::MPU6050_REG_INT_STATUS, &[0x00]),
+            read(reg::MPU6050_REG_FIFO_COUNTH, &[0x00, 0x1E]),
+            read(reg::MPU6050_REG_FIFO_R_W, &frames),
+            // overflow: emptied and running again
+            read(reg::MPU6050_REG_INT_STATUS, &[0x10]),
+            read(reg::MPU6050_REG_FIFO_COUNTH, &[0x04, 0x00]),
+            read(reg::MPU6050_REG_USER_CTRL, &[0x40]),
+            write(reg::MPU6050_REG_USER_CTRL, 0x04),
+            read(reg::MPU6050_REG_USER_CTRL, &[0x04]),
+            write(reg::MPU6050_REG_USER_CTRL, 0x40),
+        ]);
+        mpu.enable_fifo(FifoConfig::ACCEL_GYRO).unwrap();
+
+        let mut buf = [0; 32];
+        assert_eq!(mpu.read_fifo(&mut buf).unwrap(), FifoRead::Frames(2));
+        let measurements: Vec<_> = mpu.fifo_config().measurements(&buf[..24]).collect();
+        assert_eq!(measurements[0].accel, [-1, 2, -3]);
+        assert_eq!(measurements[1].gyro, [-10, 11, -12]);
+
+        assert_eq!(mpu.read_fifo(&mut buf).unwrap(), FifoRead::Overflow);
+        mpu.i2c_mut().done();
+    }
+
+    // enable_fifo(FifoConfig::ACCEL_GYRO) on a sensor with nothing else enabled
+    fn fifo_enabled() -> Vec<Transaction> {
+        vec![
+            write(reg::MPU6050_REG_FIFO_EN, 0x78),
+            read(reg::MPU6050_REG_INT_ENABLE, &[0x00]),
+            write(reg::MPU6050_REG_INT_ENABLE, 0x10),
+            read(reg::MPU6050_REG_USER_CTRL, &[0x00]),
+            write(reg::MPU6050_REG_USER_CTRL, 0x04),
+            read(reg::MPU6050_REG_USER_CTRL, &[0x04]),
+            write(reg::MPU6050_REG_USER_CTRL, 0x40),
+        ]
+    }
+
+    fn fifo_reset() -> [Transaction; 4] {
+        [
+            read(reg::MPU6050_REG_USER_CTRL, &[0x40]),
+            write(reg::MPU6050_REG_USER_CTRL, 0x04),
+            read(reg::MPU6050_REG_USER_CTRL, &[0x04]),
+            write(reg::MPU6050_REG_USER_CTRL, 0x40),
+        ]
+    }
+
+    #[test]
+    fn fifo_reads_what_fits() {
+        let mut expectations = fifo_enabled();
+        expectations.extend([
+            // two and a half frames waiting, a buffer for one and a half
+            read(reg::MPU6050_REG_INT_STATUS, &[0x00]),
+            read(reg::MPU6050_REG_FIFO_COUNTH, &[0x00, 0x1E]),
+            read(reg::MPU6050_REG_FIFO_R_W, &[7; 12]),
+            // a full FIFO counts as an overflow
+            read(reg::MPU6050_REG_INT_STATUS, &[0x00]),
+            read(reg::MPU6050_REG_FIFO_COUNTH, &[0x04, 0x00]),
+        ]);
+        expectations.extend(fifo_reset());
+
+        let mut mpu = sensor(&expectations);
+        mpu.enable_fifo(FifoConfig::ACCEL_GYRO).unwrap();
+        let mut buf = [0; 20];
+        assert_eq!(mpu.read_fifo(&mut buf).unwrap(), FifoRead::Frames(1));
+        assert_eq!(buf[..12], [7; 12]);
+        assert_eq!(mpu.read_fifo(&mut buf).unwrap(), FifoRead::Overflow);
+        mpu.i2c_mut().done();
+    }
+
+    #[test]
+    fn fifo_overflow_keeps_the_motion_bit() {
+        let mut expectations = fifo_enabled();
+        expectations.extend([
+            read(reg::MPU6050_REG_INT_STATUS, &[0x50]),
+            read(reg::MPU6050_REG_FIFO_COUNTH, &[0x00, 0x1E]),
+        ]);
+        expectations.extend(fifo_reset());
+        expectations.extend([
+            read(reg::MPU6050_REG_INT_STATUS, &[0x00]),
+            read(reg::MPU6050_REG_INT_STATUS, &[0x00]),
+        ]);
+
+        let mut mpu = sensor(&expectations);
+        mpu.enable_fifo(FifoConfig::ACCEL_GYRO).unwrap();
+        let mut buf = [0; 32];
+        assert_eq!(mpu.read_fifo(&mut buf).unwrap(), FifoRead::Overflow);
+        // read along with the overflow, and only once
+        assert!(mpu.motion_detected().unwrap());
+        assert!(!mpu.motion_detected().unwrap());
+        mpu.i2c_mut().done();
+    }
+
+    // a self-test from ACCEL_CONFIG `accel` and GYRO_CONFIG `gyro`: readings are 0 with
+    // self-test off and `excited` (read_all order) with it on, `trim` is SELF_TEST_X .. A
+    fn self_test_expectations(accel: u8, gyro: u8, excited: [i16; 7], trim: [u8; 4]) -> Vec<Transaction> {
+        let idle = words(&[0; 7]);
+        let excited = words(&excited);
+        let mut expectations = vec![
+            read(reg::MPU6050_REG_ACCEL_CONFIG, &[accel]),
+            read(reg::MPU6050_REG_GYRO_CONFIG, &[gyro]),
+            // +-8 g and +-250 dps, self-test off, the filter bits stay
+            write(reg::MPU6050_REG_ACCEL_CONFIG, accel & 0x07 | 0x10),
+            write(reg::MPU6050_REG_GYRO_CONFIG, gyro & 0x07),
+        ];
+        expectations.extend((0..8).map(|_| read(reg::MPU6050_REG_ACCEL_XOUT_H, &idle)));
+        expectations.push(write(reg::MPU6050_REG_ACCEL_CONFIG, accel & 0x07 | 0xF0));
+        expectations.push(write(reg::MPU6050_REG_GYRO_CONFIG, gyro & 0x07 | 0xE0));
+        expectations.extend((0..8).map(|_| read(reg::MPU6050_REG_ACCEL_XOUT_H, &excited)));
+        expectations.push(read(reg::MPU6050_REG_SELF_TEST_X, &trim));
+        expectations.push(write(reg::MPU6050_REG_ACCEL_CONFIG, accel));
+        expectations.push(write(reg::MPU6050_REG_GYRO_CONFIG, gyro));
+        expectations
+    }
+
+    fn self_test_delays() -> CheckedDelay {
+        let mut delays = Vec::new();
+        for _ in 0..2 {
+            delays.push(DelayTransaction::delay_ms(200));
+            delays.extend((0..8).map(|_| DelayTransaction::delay_ms(1)));
+        }
+        delays.push(DelayTransaction::delay_ms(200));
+        CheckedDelay::new(&delays)
+    }
+
+    #[test]
+    fn self_test_sequence() {
+        // all trims 1
+        let expectations = self_test_expectations(
+            0x00,
+            0x00,
+            [1392, 1392, 1392, 0, 3275, -3275, 3275],
+            [0x01, 0x01, 0x01, 0x15],
+        );
+
+        let mut mpu = sensor(&expectations);
+        let mut delay = self_test_delays();
+        let report = mpu.self_test(&mut delay).unwrap();
+        assert!(report.passed());
+        assert!(report.gyro[1].change_percent.unwrap().abs() < 0.1);
+        mpu.i2c_mut().done();
+        delay.done();
+    }
+
+    #[test]
+    fn self_test_finds_an_axis_off_the_trim() {
+        // motion high pass filter on. XA_TEST .. ZA_TEST = 1, XG_TEST .. ZG_TEST = 1, 13, 0.
+        // accel x 10% over, y 20% over, z as trimmed; gyro x, y as trimmed, z untrimmed
+        let expectations = self_test_expectations(
+            0x01,
+            0x00,
+            [1532, 1671, 1392, 0, 3275, -5618, 500],
+            [0x01, 0x0D, 0x00, 0x15],
+        );
+
+        let mut mpu = sensor(&expectations);
+        let mut delay = self_test_delays();
+        let report = mpu.self_test(&mut delay).unwrap();
+        assert!(report.accel[0].passed);
+        assert!((report.accel[0].change_percent.unwrap() - 10.0).abs() < 0.1);
+        assert!(!report.accel[1].passed);
+        assert!(report.accel[2].passed);
+        assert!(report.gyro[0].passed);
+        assert!(report.gyro[1].passed);
+        assert_eq!(report.gyro[2].change_percent, None);
+        assert!(!report.passed());
+        assert_eq!(mpu.accel_range(), AccelRange::G2);
+        mpu.i2c_mut().done();
+        delay.done();
+    }
 }
@@ -1,71 +1,190 @@
 
 //import the trait or interface
+use embedded_hal::delay::DelayNs;
 use embedded_hal::i2c::I2c;
-use crate::reg;
+use crate::driver::driver_methods;
+use crate::fifo::{FIFO_SIZE, FifoConfig, FifoRead};
+use crate::interrupt::{IntLevel, IntPinConfig, Interrupts};
+use crate::power::{self, Standby, WakeRate};
+use crate::range::{self, AccelRange, GyroRange};
+use crate::self_test::{FactoryTrim, SelfTestReport};
+use crate::reg::{
+    self, AccelConfig, Config, FifoEn, GyroConfig, IntEnable, IntPinCfg, IntStatus, PwrMgmt1, PwrMgmt2, Register, UserCtrl,
+};
+
+// WHO_AM_I holds the upper six bits of the I2C address, whatever AD0 is
+pub const WHO_AM_I_VALUE: u8 = 0x68;
+
+// after DEVICE_RESET and after the signal path reset
+pub(crate) const RESET_DELAY_MS: u32 = 100;
+
+// SIGNAL_PATH_RESET: gyro, accel and temperature
+pub(crate) const RESET_ALL_PATHS: u8 = 0x07;
+
+// ACCEL_HPF of the motion detector
+pub(crate) const ACCEL_HPF_5HZ: u8 = 0x01;
+
+// for the outputs to settle after self-test is switched on or off
+pub(crate) const SELF_TEST_SETTLE_MS: u32 = 200;
+// readings averaged with self-test off and on, 1 ms apart
+pub(crate) const SELF_TEST_SAMPLES: i32 = 8;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Error<E> {
+    // the I2C bus failed
+    Bus(E),
+    // WHO_AM_I answered with something else, not an MPU6050 at that address
+    WrongDevice(u8),
+    // a setting outside of what the register takes
+    InvalidConfig,
+}
 
-pub struct Mpu6050<T> {
-    device_address: u8,
-    i2c_interface: T,
-   
+// PWR_MGMT_1 CLKSEL, the datasheet recommends a gyro PLL over the internal oscillator
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum ClockSource {
+    Internal8MHz = 0,
+    #[default]
+    PllGyroX = 1,
+    PllGyroY = 2,
+    PllGyroZ = 3,
+    PllExternal32kHz = 4,
+    PllExternal19MHz = 5,
+    // keeps the timing generator in reset
+    Stopped = 7,
 }
 
-impl<T: I2c> Mpu6050<T>
-{
+// accel, temperature and gyro of one sampling instant. raw counts as Measurement<i16>,
+// m/s^2, degrees C and degrees/s as Measurement<f32>, fixed point as Measurement<i32>
+// in milli g, hundredths of a degree C and milli degrees/s
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Measurement<T> {
+    pub accel: [T; 3],
+    pub temperature: T,
+    pub gyro: [T; 3],
+}
+
+// what the driver remembers about the sensor, shared with the async driver
+#[derive(Default)]
+pub(crate) struct State {
+    // full scale ranges as last read from or written to the sensor
+    pub(crate) accel_range: AccelRange,
+    pub(crate) gyro_range: GyroRange,
+    pub(crate) fifo: FifoConfig,
+    // INT_STATUS clears on read, bits seen but not yet asked for wait here
+    pub(crate) int_status: Interrupts,
+    // PWR_MGMT_1 before cycle mode, its clock and TEMP_DIS come back when cycle mode is left
+    pub(crate) before_cycle: Option<PwrMgmt1>,
+}
 
-   pub fn new(i2c_interface: T, device_address: u8) -> Self {
-        Self {
-            device_address,
-            i2c_interface, 
-          
+impl State {
+    // keeps the remembered ranges in step with the range registers
+    pub(crate) fn track(&mut self, reg_addr: u8, value: u8) {
+        match reg_addr {
+            AccelConfig::ADDR => self.accel_range = AccelRange::from_bits(AccelConfig(value).afs_sel()),
+            GyroConfig::ADDR => self.gyro_range = GyroRange::from_bits(GyroConfig(value).fs_sel()),
+            _ => {}
         }
     }
 
-    //minimal init code 
-    // here you have to clear SLEEP bit of the register PWR_MGMT_1 of mpu6050 sensor
-    //after powerup the sensor sets this bit to 1(SLEEP bit = 1), putting the device into low-power mode
-    pub fn init(&mut self) -> Result<(), T::Error> {
-        self.write_byte(reg::MPU6050_REG_PWR_MGMT_1, 0x00)
+    // INT_STATUS together with the bits an earlier read left
+    pub(crate) fn merge_status(&self, status: IntStatus) -> Interrupts {
+        Interrupts::from_bits(status.bits()) | self.int_status
     }
 
-    fn write_byte(&mut self, reg_addr: u8, reg_value: u8) -> Result<(), T::Error> {
-        let buf: [u8; 2] = [reg_addr, reg_value];
-        self.i2c_interface.write(self.device_address, &buf)
+    // true if one of `interrupts` is in `status`, the others stay for later
+    pub(crate) fn take(&mut self, status: Interrupts, interrupts: Interrupts) -> bool {
+        self.int_status = status & !interrupts;
+        !(status & interrupts).is_empty()
     }
 
-    fn read_byte(&mut self, reg_addr: u8) -> Result<u8, T::Error> {
-        let mut read_buf = [0u8];
-        self.i2c_interface.
-            write_read(self.device_address, &[reg_addr], &mut read_buf)?;
-        Ok(read_buf[0])
+    pub(crate) fn scaled(&self, raw: Measurement<i16>) -> Measurement<f32> {
+        Measurement {
+            accel: raw.accel.map(|a| self.accel_range.to_ms2(a)),
+            temperature: range::temperature_celsius(raw.temperature),
+            gyro: raw.gyro.map(|g| self.gyro_range.to_dps(g)),
+        }
     }
 
-    fn read_n_byte(&mut self, reg_addr: u8, read_buf: &mut [u8]) -> Result<(), T::Error> {
-        self.i2c_interface
-            .write_read(self.device_address, &[reg_addr], read_buf)
+    pub(crate) fn fixed(&self, raw: Measurement<i16>) -> Measurement<i32> {
+        Measurement {
+            accel: raw.accel.map(|a| self.accel_range.to_mg(a)),
+            temperature: range::temperature_centi_celsius(raw.temperature),
+            gyro: raw.gyro.map(|g| self.gyro_range.to_mdps(g)),
+        }
     }
 
-    pub fn read_accel_data_raw(&mut self) -> Result<[i16; 3], T::Error> {
-        let mut buf = [0; 6];
-
+    // whole frames to move out of `count` waiting bytes into a buffer of `buf_len`
+    pub(crate) fn fifo_frames(&self, count: usize, buf_len: usize) -> usize {
+        let frame_len = self.fifo.frame_len();
+        if frame_len == 0 {
+            return 0;
+        }
+        (count / frame_len).min(buf_len / frame_len)
+    }
+}
 
-        self.read_n_byte(reg::MPU6060_REG_ACCEL_READ, &mut buf)?;
+// big endian words as the measurement registers hold them
+pub(crate) fn words<const N: usize>(buf: &[u8]) -> [i16; N] {
+    core::array::from_fn(|i| i16::from_be_bytes([buf[2 * i], buf[2 * i + 1]]))
+}
 
-        let x = i16::from_be_bytes([buf[0], buf[1]]);
-        let y = i16::from_be_bytes([buf[2], buf[3]]);
-        let z = i16::from_be_bytes([buf[4], buf[5]]);
+// the 14 bytes from ACCEL_XOUT_H on
+pub(crate) fn measurement(buf: &[u8; 14]) -> Measurement<i16> {
+    let [ax, ay, az, temperature, gx, gy, gz] = words(buf);
+    Measurement {
+        accel: [ax, ay, az],
+        temperature,
+        gyro: [gx, gy, gz],
+    }
+}
 
-        Ok([x, y, z])
-   }
+pub(crate) fn fifo_enable(config: FifoConfig) -> FifoEn {
+    FifoEn::default()
+        .with_accel_fifo_en(config.accel)
+        .with_temp_fifo_en(config.temperature)
+        .with_xg_fifo_en(config.gyro)
+        .with_yg_fifo_en(config.gyro)
+        .with_zg_fifo_en(config.gyro)
+}
 
-pub fn set_low_pass_filter(&mut self, dlpf_cfg: u8) -> Result<(), T::Error> {
-    assert!(dlpf_cfg <= 6, "Invalid DLPF_CFG value");
+// self-test ranges with the XA/YA/ZA and XG/YG/ZG self-test bits `on` or off,
+// the filter bits stay as they are
+pub(crate) fn self_test_config(accel: AccelConfig, gyro: GyroConfig, on: bool) -> (AccelConfig, GyroConfig) {
+    let accel = accel.with_afs_sel(AccelRange::G8.bits()).with_xa_st(on).with_ya_st(on).with_za_st(on);
+    let gyro = gyro.with_fs_sel(GyroRange::Dps250.bits()).with_xg_st(on).with_yg_st(on).with_zg_st(on);
+    (accel, gyro)
+}
 
-    const CONFIG_REGISTER: u8 = 0x1A;
+pub(crate) fn accumulate(sum: &mut Measurement<i32>, sample: Measurement<i16>) {
+    for i in 0..3 {
+        sum.accel[i] += sample.accel[i] as i32;
+        sum.gyro[i] += sample.gyro[i] as i32;
+    }
+}
 
-    // Write dlpf_cfg to CONFIG register
-    self.write_byte(CONFIG_REGISTER, dlpf_cfg)
- }
+// the sums of SELF_TEST_SAMPLES readings with self-test off and on against the trim registers
+pub(crate) fn self_test_report(trim: [u8; 4], off: Measurement<i32>, on: Measurement<i32>) -> SelfTestReport {
+    let response = |on: i32, off: i32| (on - off) as f32 / SELF_TEST_SAMPLES as f32;
+    SelfTestReport::new(
+        &FactoryTrim::from_registers(trim),
+        core::array::from_fn(|i| response(on.accel[i], off.accel[i])),
+        core::array::from_fn(|i| response(on.gyro[i], off.gyro[i])),
+    )
+}
 
+pub(crate) fn int_pin_cfg(pin: IntPinCfg, config: IntPinConfig) -> IntPinCfg {
+    pin.with_int_level(config.level == IntLevel::ActiveLow)
+        .with_int_open(config.open_drain)
+        .with_latch_int_en(config.latch)
+        .with_int_rd_clear(config.clear_on_any_read)
+}
 
+pub struct Mpu6050<T> {
+    device_address: u8,
+    i2c_interface: T,
+    state: State,
+}
 
-}
\ No newline at end of file
+impl<T: I2c> Mpu6050<T> {
+    driver_methods!();
+}
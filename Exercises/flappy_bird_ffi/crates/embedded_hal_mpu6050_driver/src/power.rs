@@ -0,0 +1,118 @@
+// low power settings in PWR_MGMT_1 and PWR_MGMT_2: accelerometer only cycle mode
+// and standby of single axes.
+
+use crate::mpu6050::ClockSource;
+use crate::reg::{PwrMgmt1, PwrMgmt2};
+
+// LP_WAKE_CTRL, how often the accelerometer wakes up for a sample in cycle mode
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum WakeRate {
+    #[default]
+    Hz1_25 = 0,
+    Hz5 = 1,
+    Hz20 = 2,
+    Hz40 = 3,
+}
+
+// axes in standby, x, y, z
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Standby {
+    pub accel: [bool; 3],
+    pub gyro: [bool; 3],
+}
+
+impl Standby {
+    pub const NONE: Self = Self {
+        accel: [false; 3],
+        gyro: [false; 3],
+    };
+    // what cycle mode runs with
+    pub const GYRO: Self = Self {
+        accel: [false; 3],
+        gyro: [true; 3],
+    };
+
+    pub fn from_register(pwr: PwrMgmt2) -> Self {
+        Self {
+            accel: [pwr.stby_xa(), pwr.stby_ya(), pwr.stby_za()],
+            gyro: [pwr.stby_xg(), pwr.stby_yg(), pwr.stby_zg()],
+        }
+    }
+
+    //`pwr` with these standby bits, LP_WAKE_CTRL stays
+    pub fn apply(self, pwr: PwrMgmt2) -> PwrMgmt2 {
+        pwr.with_stby_xa(self.accel[0])
+            .with_stby_ya(self.accel[1])
+            .with_stby_za(self.accel[2])
+            .with_stby_xg(self.gyro[0])
+            .with_stby_yg(self.gyro[1])
+            .with_stby_zg(self.gyro[2])
+    }
+}
+
+// PWR_MGMT_1 in cycle mode. the gyros are in standby during cycle mode,
+// so the clock comes from the internal oscillator and the temperature sensor is off
+pub(crate) fn cycle_mode(pwr: PwrMgmt1) -> PwrMgmt1 {
+    pwr.with_sleep(false)
+        .with_cycle(true)
+        .with_temp_dis(true)
+        .with_clksel(ClockSource::Internal8MHz as u8)
+}
+
+// PWR_MGMT_1 out of cycle mode, with the clock and TEMP_DIS of `before`, the register
+// as it was when cycle mode was entered. without it the x gyro PLL and the temperature sensor on
+pub(crate) fn continuous_mode(pwr: PwrMgmt1, before: Option<PwrMgmt1>) -> PwrMgmt1 {
+    let before = before.unwrap_or(PwrMgmt1(ClockSource::PllGyroX as u8));
+    pwr.with_sleep(false)
+        .with_cycle(false)
+        .with_temp_dis(before.temp_dis())
+        .with_clksel(before.clksel())
+}
+
+// PWR_MGMT_2 for cycle mode at `rate`, accel standby bits stay
+pub(crate) fn cycle_standby(pwr: PwrMgmt2, rate: WakeRate) -> PwrMgmt2 {
+    let standby = Standby {
+        gyro: [true; 3],
+        ..Standby::from_register(pwr)
+    };
+    standby.apply(pwr).with_lp_wake_ctrl(rate as u8)
+}
+
+// PWR_MGMT_2 after cycle mode, gyros running again
+pub(crate) fn gyro_standby_off(pwr: PwrMgmt2) -> PwrMgmt2 {
+    let standby = Standby {
+        gyro: [false; 3],
+        ..Standby::from_register(pwr)
+    };
+    standby.apply(pwr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reg::Register;
+
+    #[test]
+    fn standby_bits() {
+        let pwr = Standby::GYRO.apply(PwrMgmt2(0xC0));
+        assert_eq!(pwr.bits(), 0xC7);
+        assert_eq!(Standby::from_register(pwr), Standby::GYRO);
+
+        let z_only = Standby {
+            accel: [false, false, true],
+            gyro: [false, false, true],
+        };
+        assert_eq!(z_only.apply(PwrMgmt2(0x3F)).bits(), 0x09);
+    }
+
+    #[test]
+    fn cycle_mode_registers() {
+        assert_eq!(cycle_mode(PwrMgmt1(0x41)).bits(), 0x28);
+        assert_eq!(continuous_mode(PwrMgmt1(0x28), None).bits(), 0x01);
+        // z gyro PLL with the temperature sensor off, as before cycle mode
+        assert_eq!(continuous_mode(PwrMgmt1(0x28), Some(PwrMgmt1(0x0B))).bits(), 0x0B);
+        // accel x stays in standby
+        assert_eq!(cycle_standby(PwrMgmt2(0x20), WakeRate::Hz20).bits(), 0xA7);
+        assert_eq!(gyro_standby_off(PwrMgmt2(0xA7)).bits(), 0xA0);
+    }
+}
@@ -0,0 +1,133 @@
+// full scale ranges and the conversion of raw counts to physical units,
+// in floats or in integers (fixed point, for targets without an FPU).
+
+// standard gravity, for readings in m/s^2
+pub const GRAVITY: f32 = 9.80665;
+
+// counts per g at +-2 g and per degree/s at +-250 dps, both halve per range step
+const ACCEL_LSB_PER_G: i32 = 16384;
+const GYRO_LSB_PER_DPS: i32 = 131;
+
+// AFS_SEL in ACCEL_CONFIG
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum AccelRange {
+    #[default]
+    G2 = 0,
+    G4 = 1,
+    G8 = 2,
+    G16 = 3,
+}
+
+impl AccelRange {
+    //range for an AFS_SEL value, only the low two bits count
+    pub fn from_bits(afs_sel: u8) -> Self {
+        match afs_sel & 0x03 {
+            0 => AccelRange::G2,
+            1 => AccelRange::G4,
+            2 => AccelRange::G8,
+            _ => AccelRange::G16,
+        }
+    }
+
+    pub fn bits(self) -> u8 {
+        self as u8
+    }
+
+    pub fn lsb_per_g(self) -> i32 {
+        ACCEL_LSB_PER_G >> self as u8
+    }
+
+    pub fn to_g(self, raw: i16) -> f32 {
+        raw as f32 / self.lsb_per_g() as f32
+    }
+
+    pub fn to_ms2(self, raw: i16) -> f32 {
+        self.to_g(raw) * GRAVITY
+    }
+
+    //milli g, rounded towards zero
+    pub fn to_mg(self, raw: i16) -> i32 {
+        raw as i32 * 1000 / self.lsb_per_g()
+    }
+}
+
+// FS_SEL in GYRO_CONFIG
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum GyroRange {
+    #[default]
+    Dps250 = 0,
+    Dps500 = 1,
+    Dps1000 = 2,
+    Dps2000 = 3,
+}
+
+impl GyroRange {
+    //range for an FS_SEL value, only the low two bits count
+    pub fn from_bits(fs_sel: u8) -> Self {
+        match fs_sel & 0x03 {
+            0 => GyroRange::Dps250,
+            1 => GyroRange::Dps500,
+            2 => GyroRange::Dps1000,
+            _ => GyroRange::Dps2000,
+        }
+    }
+
+    pub fn bits(self) -> u8 {
+        self as u8
+    }
+
+    pub fn to_dps(self, raw: i16) -> f32 {
+        raw as f32 * (1 << self as u8) as f32 / GYRO_LSB_PER_DPS as f32
+    }
+
+    //milli degrees/s, rounded towards zero
+    pub fn to_mdps(self, raw: i16) -> i32 {
+        ((raw as i32 * 1000) << self as u8) / GYRO_LSB_PER_DPS
+    }
+}
+
+// register map: degrees C = count / 340 + 36.53
+pub fn temperature_celsius(raw: i16) -> f32 {
+    raw as f32 / 340.0 + 36.53
+}
+
+//hundredths of a degree C
+pub fn temperature_centi_celsius(raw: i16) -> i32 {
+    raw as i32 * 100 / 340 + 3653
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accel_conversions() {
+        assert_eq!(AccelRange::G2.to_mg(16384), 1000);
+        assert_eq!(AccelRange::G16.to_mg(2048), 1000);
+        assert_eq!(AccelRange::G4.to_mg(-8192), -1000);
+        assert_eq!(AccelRange::G8.to_g(i16::MAX).round(), 8.0);
+        assert!((AccelRange::G2.to_ms2(16384) - GRAVITY).abs() < 1e-5);
+    }
+
+    #[test]
+    fn gyro_conversions() {
+        assert_eq!(GyroRange::Dps250.to_mdps(131), 1000);
+        assert_eq!(GyroRange::Dps2000.to_mdps(-131), -8000);
+        assert_eq!(GyroRange::Dps2000.to_mdps(i16::MAX), 2_001_038);
+        assert!((GyroRange::Dps500.to_dps(131) - 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn range_bits_round_trip() {
+        for bits in 0..4 {
+            assert_eq!(AccelRange::from_bits(bits).bits(), bits);
+            assert_eq!(GyroRange::from_bits(bits).bits(), bits);
+        }
+    }
+
+    #[test]
+    fn temperature() {
+        assert_eq!(temperature_centi_celsius(-340), 3553);
+        assert!((temperature_celsius(0) - 36.53).abs() < 1e-5);
+    }
+}
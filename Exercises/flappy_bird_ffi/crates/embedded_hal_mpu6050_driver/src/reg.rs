@@ -1,2 +1,272 @@
+// register map of the MPU-6000/MPU-6050 (MPU-6000 Register Map rev 4.2 in Docs/,
+// MOT_THR/MOT_DUR/MOT_EN from the earlier revisions).
+// the registers the driver configures also have a typed view with bitfield
+// accessors, changed with read-modify-write through Mpu6050::modify_register.
+
+pub const MPU6050_REG_SELF_TEST_X: u8 = 0x0D;
+pub const MPU6050_REG_SELF_TEST_Y: u8 = 0x0E;
+pub const MPU6050_REG_SELF_TEST_Z: u8 = 0x0F;
+pub const MPU6050_REG_SELF_TEST_A: u8 = 0x10;
+pub const MPU6050_REG_SMPLRT_DIV: u8 = 0x19;
+pub const MPU6050_REG_CONFIG: u8 = 0x1A;
+pub const MPU6050_REG_GYRO_CONFIG: u8 = 0x1B;
+pub const MPU6050_REG_ACCEL_CONFIG: u8 = 0x1C;
+pub const MPU6050_REG_MOT_THR: u8 = 0x1F;
+pub const MPU6050_REG_MOT_DUR: u8 = 0x20;
+pub const MPU6050_REG_FIFO_EN: u8 = 0x23;
+pub const MPU6050_REG_I2C_MST_CTRL: u8 = 0x24;
+pub const MPU6050_REG_I2C_SLV0_ADDR: u8 = 0x25;
+pub const MPU6050_REG_I2C_SLV0_REG: u8 = 0x26;
+pub const MPU6050_REG_I2C_SLV0_CTRL: u8 = 0x27;
+pub const MPU6050_REG_I2C_SLV1_ADDR: u8 = 0x28;
+pub const MPU6050_REG_I2C_SLV1_REG: u8 = 0x29;
+pub const MPU6050_REG_I2C_SLV1_CTRL: u8 = 0x2A;
+pub const MPU6050_REG_I2C_SLV2_ADDR: u8 = 0x2B;
+pub const MPU6050_REG_I2C_SLV2_REG: u8 = 0x2C;
+pub const MPU6050_REG_I2C_SLV2_CTRL: u8 = 0x2D;
+pub const MPU6050_REG_I2C_SLV3_ADDR: u8 = 0x2E;
+pub const MPU6050_REG_I2C_SLV3_REG: u8 = 0x2F;
+pub const MPU6050_REG_I2C_SLV3_CTRL: u8 = 0x30;
+pub const MPU6050_REG_I2C_SLV4_ADDR: u8 = 0x31;
+pub const MPU6050_REG_I2C_SLV4_REG: u8 = 0x32;
+pub const MPU6050_REG_I2C_SLV4_DO: u8 = 0x33;
+pub const MPU6050_REG_I2C_SLV4_CTRL: u8 = 0x34;
+pub const MPU6050_REG_I2C_SLV4_DI: u8 = 0x35;
+pub const MPU6050_REG_I2C_MST_STATUS: u8 = 0x36;
+pub const MPU6050_REG_INT_PIN_CFG: u8 = 0x37;
+pub const MPU6050_REG_INT_ENABLE: u8 = 0x38;
+pub const MPU6050_REG_INT_STATUS: u8 = 0x3A;
+// measurements, big endian, high byte first
+pub const MPU6050_REG_ACCEL_XOUT_H: u8 = 0x3B;
+pub const MPU6050_REG_ACCEL_YOUT_H: u8 = 0x3D;
+pub const MPU6050_REG_ACCEL_ZOUT_H: u8 = 0x3F;
+pub const MPU6050_REG_TEMP_OUT_H: u8 = 0x41;
+pub const MPU6050_REG_GYRO_XOUT_H: u8 = 0x43;
+pub const MPU6050_REG_GYRO_YOUT_H: u8 = 0x45;
+pub const MPU6050_REG_GYRO_ZOUT_H: u8 = 0x47;
+// 24 bytes read from the auxiliary I2C sensors
+pub const MPU6050_REG_EXT_SENS_DATA_00: u8 = 0x49;
+pub const MPU6050_REG_I2C_SLV0_DO: u8 = 0x63;
+pub const MPU6050_REG_I2C_SLV1_DO: u8 = 0x64;
+pub const MPU6050_REG_I2C_SLV2_DO: u8 = 0x65;
+pub const MPU6050_REG_I2C_SLV3_DO: u8 = 0x66;
+pub const MPU6050_REG_I2C_MST_DELAY_CTRL: u8 = 0x67;
+pub const MPU6050_REG_SIGNAL_PATH_RESET: u8 = 0x68;
+pub const MPU6050_REG_MOT_DETECT_CTRL: u8 = 0x69;
+pub const MPU6050_REG_USER_CTRL: u8 = 0x6A;
 pub const MPU6050_REG_PWR_MGMT_1: u8 = 0x6B;
-pub const MPU6060_REG_ACCEL_READ: u8 = 0x3B;
\ No newline at end of file
+pub const MPU6050_REG_PWR_MGMT_2: u8 = 0x6C;
+pub const MPU6050_REG_FIFO_COUNTH: u8 = 0x72;
+pub const MPU6050_REG_FIFO_COUNTL: u8 = 0x73;
+pub const MPU6050_REG_FIFO_R_W: u8 = 0x74;
+pub const MPU6050_REG_WHO_AM_I: u8 = 0x75;
+
+// a register with a typed view, `bits` is the raw value
+pub trait Register: Copy {
+    const ADDR: u8;
+
+    fn from_bits(bits: u8) -> Self;
+    fn bits(self) -> u8;
+}
+
+// newtype over the register value with a getter and a `with_` setter per bitfield.
+// flags are single bits (bit number), fields are (lowest bit, width)
+macro_rules! register {
+    ($name:ident = $addr:expr,
+        flags { $($flag:ident, $with_flag:ident: $bit:expr;)* }
+        fields { $($field:ident, $with_field:ident: $shift:expr, $width:expr;)* }
+    ) => {
+        #[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+        pub struct $name(pub u8);
+
+        impl Register for $name {
+            const ADDR: u8 = $addr;
+
+            fn from_bits(bits: u8) -> Self {
+                Self(bits)
+            }
+
+            fn bits(self) -> u8 {
+                self.0
+            }
+        }
+
+        impl $name {
+            $(
+                pub fn $flag(self) -> bool {
+                    self.0 & (1 << $bit) != 0
+                }
+
+                pub fn $with_flag(self, on: bool) -> Self {
+                    if on { Self(self.0 | (1 << $bit)) } else { Self(self.0 & !(1 << $bit)) }
+                }
+            )*
+            $(
+                pub fn $field(self) -> u8 {
+                    (self.0 >> $shift) & ((1 << $width) - 1)
+                }
+
+                //bits of `value` above the field's width are dropped
+                pub fn $with_field(self, value: u8) -> Self {
+                    let mask = ((1u8 << $width) - 1) << $shift;
+                    Self((self.0 & !mask) | ((value << $shift) & mask))
+                }
+            )*
+        }
+    };
+}
+
+// frame synchronisation and digital low pass filter
+register!(Config = MPU6050_REG_CONFIG,
+    flags {}
+    fields {
+        ext_sync_set, with_ext_sync_set: 3, 3;
+        dlpf_cfg, with_dlpf_cfg: 0, 3;
+    }
+);
+
+register!(GyroConfig = MPU6050_REG_GYRO_CONFIG,
+    flags {
+        xg_st, with_xg_st: 7;
+        yg_st, with_yg_st: 6;
+        zg_st, with_zg_st: 5;
+    }
+    fields {
+        fs_sel, with_fs_sel: 3, 2;
+    }
+);
+
+register!(AccelConfig = MPU6050_REG_ACCEL_CONFIG,
+    flags {
+        xa_st, with_xa_st: 7;
+        ya_st, with_ya_st: 6;
+        za_st, with_za_st: 5;
+    }
+    fields {
+        afs_sel, with_afs_sel: 3, 2;
+        // high pass filter of the motion detector
+        accel_hpf, with_accel_hpf: 0, 3;
+    }
+);
+
+register!(IntPinCfg = MPU6050_REG_INT_PIN_CFG,
+    flags {
+        int_level, with_int_level: 7;
+        int_open, with_int_open: 6;
+        latch_int_en, with_latch_int_en: 5;
+        int_rd_clear, with_int_rd_clear: 4;
+        fsync_int_level, with_fsync_int_level: 3;
+        fsync_int_en, with_fsync_int_en: 2;
+        i2c_bypass_en, with_i2c_bypass_en: 1;
+    }
+    fields {}
+);
+
+register!(IntEnable = MPU6050_REG_INT_ENABLE,
+    flags {
+        mot_en, with_mot_en: 6;
+        fifo_oflow_en, with_fifo_oflow_en: 4;
+        i2c_mst_int_en, with_i2c_mst_int_en: 3;
+        data_rdy_en, with_data_rdy_en: 0;
+    }
+    fields {}
+);
+
+// same bits as IntEnable, cleared by reading it
+register!(IntStatus = MPU6050_REG_INT_STATUS,
+    flags {
+        mot_int, with_mot_int: 6;
+        fifo_oflow_int, with_fifo_oflow_int: 4;
+        i2c_mst_int, with_i2c_mst_int: 3;
+        data_rdy_int, with_data_rdy_int: 0;
+    }
+    fields {}
+);
+
+register!(FifoEn = MPU6050_REG_FIFO_EN,
+    flags {
+        temp_fifo_en, with_temp_fifo_en: 7;
+        xg_fifo_en, with_xg_fifo_en: 6;
+        yg_fifo_en, with_yg_fifo_en: 5;
+        zg_fifo_en, with_zg_fifo_en: 4;
+        accel_fifo_en, with_accel_fifo_en: 3;
+        slv2_fifo_en, with_slv2_fifo_en: 2;
+        slv1_fifo_en, with_slv1_fifo_en: 1;
+        slv0_fifo_en, with_slv0_fifo_en: 0;
+    }
+    fields {}
+);
+
+register!(UserCtrl = MPU6050_REG_USER_CTRL,
+    flags {
+        fifo_en, with_fifo_en: 6;
+        i2c_mst_en, with_i2c_mst_en: 5;
+        i2c_if_dis, with_i2c_if_dis: 4;
+        fifo_reset, with_fifo_reset: 2;
+        i2c_mst_reset, with_i2c_mst_reset: 1;
+        sig_cond_reset, with_sig_cond_reset: 0;
+    }
+    fields {}
+);
+
+register!(PwrMgmt1 = MPU6050_REG_PWR_MGMT_1,
+    flags {
+        device_reset, with_device_reset: 7;
+        sleep, with_sleep: 6;
+        cycle, with_cycle: 5;
+        temp_dis, with_temp_dis: 3;
+    }
+    fields {
+        clksel, with_clksel: 0, 3;
+    }
+);
+
+register!(PwrMgmt2 = MPU6050_REG_PWR_MGMT_2,
+    flags {
+        stby_xa, with_stby_xa: 5;
+        stby_ya, with_stby_ya: 4;
+        stby_za, with_stby_za: 3;
+        stby_xg, with_stby_xg: 2;
+        stby_yg, with_stby_yg: 1;
+        stby_zg, with_stby_zg: 0;
+    }
+    fields {
+        lp_wake_ctrl, with_lp_wake_ctrl: 6, 2;
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_set_and_clear_one_bit() {
+        let pwr = PwrMgmt1(0x40);
+        assert!(pwr.sleep());
+        assert_eq!(pwr.with_sleep(false), PwrMgmt1(0x00));
+        assert_eq!(pwr.with_device_reset(true).bits(), 0xC0);
+        assert!(!IntEnable::default().with_mot_en(true).data_rdy_en());
+    }
+
+    #[test]
+    fn fields_keep_the_other_bits() {
+        let accel = AccelConfig(0xE7).with_afs_sel(2);
+        assert_eq!(accel.bits(), 0xF7);
+        assert_eq!(accel.afs_sel(), 2);
+        assert_eq!(accel.accel_hpf(), 7);
+
+        // too wide values are cut to the field
+        assert_eq!(Config(0).with_dlpf_cfg(0xFF), Config(0x07));
+        assert_eq!(PwrMgmt2(0x3F).with_lp_wake_ctrl(3).bits(), 0xFF);
+    }
+
+    #[test]
+    fn typed_registers_know_their_address() {
+        assert_eq!(Config::ADDR, 0x1A);
+        assert_eq!(GyroConfig::ADDR, 0x1B);
+        assert_eq!(FifoEn::ADDR, 0x23);
+        assert_eq!(IntPinCfg::ADDR, 0x37);
+        assert_eq!(UserCtrl::ADDR, 0x6A);
+        assert_eq!(PwrMgmt2::ADDR, 0x6C);
+    }
+}
@@ -0,0 +1,154 @@
+// factory self-test (MPU-6000 Register Map rev 4.2, SELF_TEST registers).
+// with self-test on, each axis is pushed by an electrostatic force and its output
+// moves by the self-test response. the response measured at the factory is stored
+// in SELF_TEST_X..A as a 5 bit code per axis, an axis whose response is more than
+// 14% off that trim is damaged.
+
+pub const SELF_TEST_LIMIT_PERCENT: f32 = 14.0;
+
+// the trims are for accel at +-8 g and gyro at +-250 dps.
+// FT[Xa] = 4096 * 0.34 * (0.92 / 0.34) ^ ((XA_TEST - 1) / 30)
+const ACCEL_TRIM_BASE: f32 = 4096.0 * 0.34;
+// (0.92 / 0.34) ^ (1 / 30), so the power is a product of integer steps
+const ACCEL_TRIM_STEP: f32 = 1.033_737_6;
+// FT[Xg] = 25 * 131 * 1.046 ^ (XG_TEST - 1), negative for the y axis
+const GYRO_TRIM_BASE: f32 = 25.0 * 131.0;
+const GYRO_TRIM_STEP: f32 = 1.046;
+
+// the 5 bit XA_TEST .. ZA_TEST and XG_TEST .. ZG_TEST codes, 0 means no trim
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct FactoryTrim {
+    pub accel: [u8; 3],
+    pub gyro: [u8; 3],
+}
+
+impl FactoryTrim {
+    //SELF_TEST_X, SELF_TEST_Y, SELF_TEST_Z and SELF_TEST_A as read in one burst
+    pub fn from_registers(regs: [u8; 4]) -> Self {
+        // high three accel bits in the axis register, low two in SELF_TEST_A
+        let accel = |axis: usize| ((regs[axis] >> 3) & 0x1C) | ((regs[3] >> (4 - 2 * axis)) & 0x03);
+        Self {
+            accel: [accel(0), accel(1), accel(2)],
+            gyro: [regs[0] & 0x1F, regs[1] & 0x1F, regs[2] & 0x1F],
+        }
+    }
+
+    //factory self-test response in counts at +-8 g
+    pub fn accel_response(&self) -> [f32; 3] {
+        self.accel.map(|code| match code {
+            0 => 0.0,
+            code => ACCEL_TRIM_BASE * powi(ACCEL_TRIM_STEP, code - 1),
+        })
+    }
+
+    //factory self-test response in counts at +-250 dps
+    pub fn gyro_response(&self) -> [f32; 3] {
+        let mut response = self.gyro.map(|code| match code {
+            0 => 0.0,
+            code => GYRO_TRIM_BASE * powi(GYRO_TRIM_STEP, code - 1),
+        });
+        response[1] = -response[1];
+        response
+    }
+}
+
+// no powf without std
+fn powi(base: f32, exp: u8) -> f32 {
+    (0..exp).fold(1.0, |acc, _| acc * base)
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct AxisResult {
+    // deviation of the measured response from the factory trim, None without a trim
+    pub change_percent: Option<f32>,
+    pub passed: bool,
+}
+
+impl AxisResult {
+    //`response` and `factory` in counts
+    pub fn new(response: f32, factory: f32) -> Self {
+        if factory == 0.0 {
+            return Self {
+                change_percent: None,
+                passed: false,
+            };
+        }
+        let change = (response - factory) / factory * 100.0;
+        Self {
+            change_percent: Some(change),
+            passed: change.abs() <= SELF_TEST_LIMIT_PERCENT,
+        }
+    }
+}
+
+// outcome of Mpu6050::self_test, per axis
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SelfTestReport {
+    pub accel: [AxisResult; 3],
+    pub gyro: [AxisResult; 3],
+}
+
+impl SelfTestReport {
+    //measured responses (output with self-test on minus output with it off) against the trim
+    pub fn new(trim: &FactoryTrim, accel_response: [f32; 3], gyro_response: [f32; 3]) -> Self {
+        let accel_trim = trim.accel_response();
+        let gyro_trim = trim.gyro_response();
+        Self {
+            accel: core::array::from_fn(|i| AxisResult::new(accel_response[i], accel_trim[i])),
+            gyro: core::array::from_fn(|i| AxisResult::new(gyro_response[i], gyro_trim[i])),
+        }
+    }
+
+    //all six axes passed
+    pub fn passed(&self) -> bool {
+        self.accel.iter().chain(&self.gyro).all(|axis| axis.passed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trim_codes_are_taken_apart() {
+        // XA_TEST = 0b10110, YA_TEST = 0b01001, ZA_TEST = 0b11111
+        let trim = FactoryTrim::from_registers([0xA3, 0x4C, 0xFF, 0x27]);
+        assert_eq!(trim.accel, [0x16, 0x09, 0x1F]);
+        assert_eq!(trim.gyro, [0x03, 0x0C, 0x1F]);
+    }
+
+    #[test]
+    fn factory_responses() {
+        let trim = FactoryTrim {
+            accel: [1, 16, 31],
+            gyro: [1, 13, 0],
+        };
+        let accel = trim.accel_response();
+        assert!((accel[0] - 1392.64).abs() < 0.01);
+        assert!((accel[1] - 2290.83).abs() < 0.1);
+        assert!((accel[2] - 3768.32).abs() < 0.1);
+
+        let gyro = trim.gyro_response();
+        assert_eq!(gyro[0], 3275.0);
+        assert!((gyro[1] + 5618.13).abs() < 0.1);
+        assert_eq!(gyro[2], 0.0);
+    }
+
+    #[test]
+    fn axes_within_the_limit_pass() {
+        let trim = FactoryTrim {
+            accel: [1, 1, 1],
+            gyro: [1, 1, 0],
+        };
+        let report = SelfTestReport::new(&trim, [1392.64, 1392.64 * 1.2, 0.0], [3275.0 * 0.9, -3275.0, 100.0]);
+        assert!(report.accel[0].passed);
+        assert!(!report.accel[1].passed);
+        assert!((report.accel[1].change_percent.unwrap() - 20.0).abs() < 1e-3);
+        assert_eq!(report.accel[2].change_percent, Some(-100.0));
+        assert!(report.gyro[0].passed);
+        assert!(report.gyro[1].passed);
+        // no trim, nothing to compare with
+        assert_eq!(report.gyro[2].change_percent, None);
+        assert!(!report.passed());
+    }
+}
@@ -0,0 +1,257 @@
+// guided calibration of a tilt input (the MPU6050 accelerometer).
+// three poses are sampled, each once the board has been held still:
+//   flat   -> offsets, a flat board should read (0, 0, +1 g)
+//   top    -> tilt angle which puts the bird at the top of the screen
+//   bottom -> tilt angle which puts the bird at the bottom
+// the routine blocks; prompts and the clock come from a CalibrationUi.
+
+// raw reading of 1 g at the default +-2 g full scale
+pub const ONE_G: i16 = 16384;
+
+// the board counts as still while no axis moves further than this from the reference sample
+const STILL_THRESHOLD: i16 = 600;
+const STILL_MS: u32 = 1000;
+// giving up on a pose after this long
+const POSE_TIMEOUT_MS: u32 = 15_000;
+const SAMPLE_COUNT: i32 = 32;
+// smaller tilt ranges are rejected, the bird would jump around
+const MIN_TILT_RANGE: f32 = 10.0;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Calibration {
+    // subtracted from every raw reading (x, y, z)
+    pub accel_offset: [i16; 3],
+    // tilt in degrees mapped to the top and to the bottom of the screen,
+    // top > bottom is fine for a flipped mapping
+    pub tilt_top: f32,
+    pub tilt_bottom: f32,
+}
+
+impl Default for Calibration {
+    // what SensorInput used before there was a calibration
+    fn default() -> Self {
+        Self {
+            accel_offset: [0; 3],
+            tilt_top: -10.0,
+            tilt_bottom: 90.0,
+        }
+    }
+}
+
+impl Calibration {
+    pub fn apply(&self, raw: [i16; 3]) -> [f32; 3] {
+        [
+            raw[0] as f32 - self.accel_offset[0] as f32,
+            raw[1] as f32 - self.accel_offset[1] as f32,
+            raw[2] as f32 - self.accel_offset[2] as f32,
+        ]
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Pose {
+    Flat,
+    Top,
+    Bottom,
+}
+
+impl Pose {
+    pub fn prompt(self) -> &'static str {
+        match self {
+            Pose::Flat => "Put the board\nflat and keep\nit still",
+            Pose::Top => "Tilt to the\nhighest bird\nposition, hold",
+            Pose::Bottom => "Tilt to the\nlowest bird\nposition, hold",
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum CalibrationOutcome {
+    NotSupported,
+    // a pose was not held still in time, or the tilt range is too small
+    Aborted,
+    Done(Calibration),
+}
+
+pub trait CalibrationUi {
+    //asks the player to take `pose`
+    fn show_pose(&mut self, pose: Pose);
+    //milliseconds since some fixed point, may wrap
+    fn now_ms(&mut self) -> u32;
+}
+
+//runs the three poses, `angle` turns an offset corrected reading into the tilt angle
+pub fn run<E, U: CalibrationUi>(
+    read: &mut impl FnMut() -> Result<[i16; 3], E>,
+    angle: impl Fn([f32; 3]) -> f32,
+    ui: &mut U,
+) -> Result<CalibrationOutcome, E> {
+    ui.show_pose(Pose::Flat);
+    let Some(flat) = capture(read, ui)? else {
+        return Ok(CalibrationOutcome::Aborted);
+    };
+
+    let mut calibration = Calibration {
+        accel_offset: [flat[0], flat[1], flat[2].saturating_sub(ONE_G)],
+        ..Calibration::default()
+    };
+
+    ui.show_pose(Pose::Top);
+    let Some(top) = capture(read, ui)? else {
+        return Ok(CalibrationOutcome::Aborted);
+    };
+
+    ui.show_pose(Pose::Bottom);
+    let Some(bottom) = capture(read, ui)? else {
+        return Ok(CalibrationOutcome::Aborted);
+    };
+
+    calibration.tilt_top = angle(calibration.apply(top));
+    calibration.tilt_bottom = angle(calibration.apply(bottom));
+    if (calibration.tilt_bottom - calibration.tilt_top).abs() < MIN_TILT_RANGE {
+        return Ok(CalibrationOutcome::Aborted);
+    }
+
+    Ok(CalibrationOutcome::Done(calibration))
+}
+
+// waits until the board is still, then returns the average of SAMPLE_COUNT readings.
+// None if it was not held still within POSE_TIMEOUT_MS
+fn capture<E, U: CalibrationUi>(
+    read: &mut impl FnMut() -> Result<[i16; 3], E>,
+    ui: &mut U,
+) -> Result<Option<[i16; 3]>, E> {
+    let start = ui.now_ms();
+    let mut reference = read()?;
+    let mut still_since = start;
+
+    loop {
+        let now = ui.now_ms();
+        if now.wrapping_sub(start) > POSE_TIMEOUT_MS {
+            return Ok(None);
+        }
+
+        let sample = read()?;
+        let moved = (0..3).any(|i| (sample[i] as i32 - reference[i] as i32).abs() > STILL_THRESHOLD as i32);
+        if moved {
+            reference = sample;
+            still_since = now;
+        } else if now.wrapping_sub(still_since) >= STILL_MS {
+            break;
+        }
+    }
+
+    let mut sum = [0i32; 3];
+    for _ in 0..SAMPLE_COUNT {
+        let sample = read()?;
+        for i in 0..3 {
+            sum[i] += sample[i] as i32;
+        }
+    }
+
+    Ok(Some(sum.map(|s| (s / SAMPLE_COUNT) as i16)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mpu6050::get_roll_angle;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    // clock advancing 10 ms per call, the fake sensor reads `pose`
+    struct FakeUi {
+        now: u32,
+        pose: Rc<Cell<Pose>>,
+        shown: Vec<Pose>,
+    }
+
+    impl CalibrationUi for FakeUi {
+        fn show_pose(&mut self, pose: Pose) {
+            self.pose.set(pose);
+            self.shown.push(pose);
+        }
+
+        fn now_ms(&mut self) -> u32 {
+            self.now = self.now.wrapping_add(10);
+            self.now
+        }
+    }
+
+    fn ui() -> FakeUi {
+        FakeUi {
+            now: 0,
+            pose: Rc::new(Cell::new(Pose::Flat)),
+            shown: Vec::new(),
+        }
+    }
+
+    // sensor with an offset of (200, -300, 500), rolled by `deg` degrees
+    fn reading(deg: f32, noise: i16) -> [i16; 3] {
+        let rad = deg.to_radians();
+        [
+            200 + noise,
+            -300 + (rad.sin() * ONE_G as f32) as i16 - noise,
+            500 + (rad.cos() * ONE_G as f32) as i16,
+        ]
+    }
+
+    fn roll(v: [f32; 3]) -> f32 {
+        get_roll_angle(v[1], v[2])
+    }
+
+    #[test]
+    fn offsets_and_tilt_range() {
+        let mut ui = ui();
+        let pose = ui.pose.clone();
+        let mut n = 0i16;
+        let mut read = || -> Result<[i16; 3], ()> {
+            // a little noise, well below the stillness threshold
+            n = (n + 7) % 40;
+            Ok(match pose.get() {
+                Pose::Flat => reading(0.0, n - 20),
+                Pose::Top => reading(-20.0, n - 20),
+                Pose::Bottom => reading(60.0, n - 20),
+            })
+        };
+
+        let CalibrationOutcome::Done(calibration) = run(&mut read, roll, &mut ui).unwrap() else {
+            panic!("calibration did not finish");
+        };
+        assert_eq!(ui.shown, [Pose::Flat, Pose::Top, Pose::Bottom]);
+        for (got, want) in calibration.accel_offset.iter().zip([200, -300, 500]) {
+            assert!((got - want).abs() <= 20, "offset {} != {}", got, want);
+        }
+        assert!((calibration.tilt_top + 20.0).abs() < 0.5);
+        assert!((calibration.tilt_bottom - 60.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn shaking_board_times_out() {
+        let mut ui = ui();
+        let mut flip = false;
+        let mut read = || -> Result<[i16; 3], ()> {
+            flip = !flip;
+            Ok(reading(if flip { 0.0 } else { 30.0 }, 0))
+        };
+
+        assert_eq!(run(&mut read, roll, &mut ui), Ok(CalibrationOutcome::Aborted));
+        assert_eq!(ui.shown, [Pose::Flat]);
+        assert!(ui.now > POSE_TIMEOUT_MS);
+    }
+
+    #[test]
+    fn too_small_range_is_rejected() {
+        let mut ui = ui();
+        let mut read = || -> Result<[i16; 3], ()> { Ok(reading(5.0, 0)) };
+        assert_eq!(run(&mut read, roll, &mut ui), Ok(CalibrationOutcome::Aborted));
+        assert_eq!(ui.shown.len(), 3);
+    }
+
+    #[test]
+    fn sensor_errors_are_passed_on() {
+        let mut ui = ui();
+        let mut read = || -> Result<[i16; 3], u8> { Err(7) };
+        assert_eq!(run(&mut read, roll, &mut ui), Err(7));
+    }
+}
@@ -1,6 +1,177 @@
+// RGB565 colours and the colour themes of the game.
+// colours are u16 values as display_fill_rectangle takes them. pixel buffers
+// for display_draw_image use the byte order of the image assets, see image_order().
+
 pub const BACKGROUND: u16 = 0x9F5E;
 pub const WHITE: u16 = 0xFFFF;
 pub const BLACK: u16 = 0x0000;
 pub const RED: u16 = 0xF800;
 
 pub const SCORE: u16 = 0xE71C;
+
+// sky colour the image assets are painted on
+pub const ASSET_BACKGROUND: u16 = BACKGROUND;
+
+// the `swapped-assets` feature is for drivers which stream image data in memory order
+pub const SWAPPED_IMAGES: bool = cfg!(feature = "swapped-assets");
+
+//RGB888 -> RGB565
+pub const fn rgb(r: u8, g: u8, b: u8) -> u16 {
+    ((r as u16 >> 3) << 11) | ((g as u16 >> 2) << 5) | (b as u16 >> 3)
+}
+
+//hue in degrees (wraps at 360), saturation and value 0..=255
+pub const fn hsv(hue: u16, sat: u8, val: u8) -> u16 {
+    let hue = hue % 360;
+    // position inside the 60 degree sector, scaled to 0..=255
+    let f = (hue % 60) as u32 * 255 / 60;
+    let s = sat as u32;
+    let v = val as u32;
+
+    let p = v * (255 - s) / 255;
+    let q = v * (255 - s * f / 255) / 255;
+    let t = v * (255 - s * (255 - f) / 255) / 255;
+
+    let (r, g, b) = match hue / 60 {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    };
+    rgb(r as u8, g as u8, b as u8)
+}
+
+//colour as it has to be stored in a pixel buffer for draw_image
+pub const fn image_order(color: u16) -> u16 {
+    if SWAPPED_IMAGES {
+        color.swap_bytes()
+    } else {
+        color
+    }
+}
+
+// replaces one colour of an image while it is drawn
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Recolor {
+    pub from: u16,
+    pub to: u16,
+}
+
+impl Recolor {
+    pub const NONE: Recolor = Recolor { from: 0, to: 0 };
+
+    pub fn is_identity(self) -> bool {
+        self.from == self.to
+    }
+
+    pub fn apply(self, pixel: u16) -> u16 {
+        if pixel == self.from {
+            self.to
+        } else {
+            pixel
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Theme {
+    pub name: &'static str,
+    // sky, also what moving objects are cleared with
+    pub background: u16,
+    pub pipes: u16,
+    // score card at the top of the screen
+    pub hud: u16,
+    pub hud_border: u16,
+    pub score: u16,
+    // text on the sky and on the score card
+    pub text: u16,
+    pub highlight: u16,
+}
+
+pub static DAY: Theme = Theme {
+    name: "Day",
+    background: BACKGROUND,
+    pipes: BLACK,
+    hud: WHITE,
+    hud_border: BLACK,
+    score: SCORE,
+    text: BLACK,
+    highlight: RED,
+};
+
+pub static NIGHT: Theme = Theme {
+    name: "Night",
+    background: rgb(0x10, 0x18, 0x38),
+    pipes: hsv(120, 160, 150),
+    hud: rgb(0x20, 0x24, 0x3C),
+    hud_border: hsv(220, 60, 200),
+    score: rgb(0x38, 0x3C, 0x58),
+    text: WHITE,
+    highlight: hsv(45, 255, 255),
+};
+
+pub static THEMES: [&Theme; 2] = [&DAY, &NIGHT];
+
+impl Theme {
+    //puts the image assets onto the sky of this theme
+    pub const fn asset_recolor(&self) -> Recolor {
+        Recolor {
+            from: image_order(ASSET_BACKGROUND),
+            to: image_order(self.background),
+        }
+    }
+
+    //the theme after this one in THEMES, wraps around
+    pub fn next(&self) -> &'static Theme {
+        let index = THEMES.iter().position(|t| *t == self).unwrap_or(0);
+        THEMES[(index + 1) % THEMES.len()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rgb_matches_the_constants() {
+        assert_eq!(rgb(0xFF, 0xFF, 0xFF), WHITE);
+        assert_eq!(rgb(0, 0, 0), BLACK);
+        assert_eq!(rgb(0xFF, 0, 0), RED);
+        assert_eq!(rgb(0x98, 0xE8, 0xF0), BACKGROUND);
+    }
+
+    #[test]
+    fn hsv_primaries() {
+        assert_eq!(hsv(0, 255, 255), RED);
+        assert_eq!(hsv(120, 255, 255), rgb(0, 0xFF, 0));
+        assert_eq!(hsv(240, 255, 255), rgb(0, 0, 0xFF));
+        assert_eq!(hsv(360, 255, 255), RED);
+        assert_eq!(hsv(60, 255, 255), rgb(0xFF, 0xFF, 0));
+        // no saturation is grey, no value is black
+        assert_eq!(hsv(200, 0, 255), WHITE);
+        assert_eq!(hsv(200, 255, 0), BLACK);
+    }
+
+    #[test]
+    fn image_order_follows_the_feature() {
+        let expected = if SWAPPED_IMAGES { 0x5E9F } else { 0x9F5E };
+        assert_eq!(image_order(0x9F5E), expected);
+    }
+
+    #[test]
+    fn day_assets_are_drawn_unchanged() {
+        assert!(DAY.asset_recolor().is_identity());
+        let night = NIGHT.asset_recolor();
+        assert!(!night.is_identity());
+        assert_eq!(night.apply(image_order(BACKGROUND)), image_order(NIGHT.background));
+        assert_eq!(night.apply(0x1234), 0x1234);
+    }
+
+    #[test]
+    fn themes_cycle() {
+        assert_eq!(DAY.next(), &NIGHT);
+        assert_eq!(NIGHT.next(), &DAY);
+    }
+}
@@ -0,0 +1,403 @@
+// InputDevice made of two devices, e.g. tilt plus a button or touch.
+//   Merge    -> taps from either, otherwise the primary's position (the secondary's if it has none)
+//   Fallback -> only the primary, the secondary while the primary is down
+// a device whose read fails sits out for a backoff time (doubling up to
+// MAX_BACKOFF_MS), then is initialised again and retried. sample() only fails
+// while neither device answers.
+// sleep and wake go to the primary, the secondary is not read while asleep.
+
+use crate::calibration::{Calibration, CalibrationOutcome, CalibrationUi};
+use crate::diagnostics::SelfTestReport;
+use crate::game::{self, Input, InputDevice, InputSample};
+use crate::response::ResponseCurve;
+use crate::settings::InputSource;
+
+const FIRST_BACKOFF_MS: u32 = 20;
+const MAX_BACKOFF_MS: u32 = 1000;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Mode {
+    Merge,
+    Fallback,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CompositeError<P, S> {
+    Primary(P),
+    Secondary(S),
+    // both devices are waiting for their retry
+    Unavailable,
+}
+
+// one device and its retry state
+struct Channel<D: InputDevice> {
+    device: D,
+    // failed reads in a row
+    failures: u32,
+    retry_at: u32,
+}
+
+impl<D: InputDevice> Channel<D> {
+    fn new(device: D) -> Self {
+        Self {
+            device,
+            failures: 0,
+            retry_at: 0,
+        }
+    }
+
+    fn is_up(&self) -> bool {
+        self.failures == 0
+    }
+
+    // None while waiting for the retry
+    fn read(&mut self, now: u32) -> Option<Result<InputSample, D::Error>> {
+        if !self.is_up() {
+            // wrapping compare: retry_at is at most MAX_BACKOFF_MS ahead
+            if (now.wrapping_sub(self.retry_at) as i32) < 0 {
+                return None;
+            }
+            if let Err(e) = self.device.init() {
+                self.failed(now);
+                return Some(Err(e));
+            }
+        }
+
+        let sample = self.device.sample();
+        match sample {
+            Ok(_) => self.failures = 0,
+            Err(_) => self.failed(now),
+        }
+        Some(sample)
+    }
+
+    fn init(&mut self, now: u32) -> Result<(), D::Error> {
+        let result = self.device.init();
+        match result {
+            Ok(()) => self.failures = 0,
+            Err(_) => self.failed(now),
+        }
+        result
+    }
+
+    fn failed(&mut self, now: u32) {
+        let backoff = FIRST_BACKOFF_MS
+            .checked_shl(self.failures)
+            .map_or(MAX_BACKOFF_MS, |b| b.min(MAX_BACKOFF_MS));
+        self.failures = self.failures.saturating_add(1);
+        self.retry_at = now.wrapping_add(backoff);
+    }
+}
+
+pub struct Composite<P: InputDevice, S: InputDevice> {
+    mode: Mode,
+    primary: Channel<P>,
+    secondary: Channel<S>,
+    clock: fn() -> u32,
+}
+
+impl<P: InputDevice, S: InputDevice> Composite<P, S> {
+    pub fn new(mode: Mode, primary: P, secondary: S) -> Self {
+        Self::with_clock(mode, primary, secondary, game::tick)
+    }
+
+    //`clock` gives milliseconds for the backoff, may wrap
+    pub fn with_clock(mode: Mode, primary: P, secondary: S, clock: fn() -> u32) -> Self {
+        Self {
+            mode,
+            primary: Channel::new(primary),
+            secondary: Channel::new(secondary),
+            clock,
+        }
+    }
+
+    pub fn primary(&mut self) -> &mut P {
+        &mut self.primary.device
+    }
+
+    pub fn secondary(&mut self) -> &mut S {
+        &mut self.secondary.device
+    }
+
+    //(primary, secondary), false while a device is failing
+    pub fn health(&self) -> (bool, bool) {
+        (self.primary.is_up(), self.secondary.is_up())
+    }
+}
+
+impl<P: InputDevice, S: InputDevice> InputDevice for Composite<P, S> {
+    type Error = CompositeError<P::Error, S::Error>;
+
+    //fine as long as one of the devices starts, the other one is retried later
+    fn init(&mut self) -> Result<(), Self::Error> {
+        let now = (self.clock)();
+        let primary = self.primary.init(now);
+        let secondary = self.secondary.init(now);
+        match (primary, secondary) {
+            (Err(e), Err(_)) => Err(CompositeError::Primary(e)),
+            _ => Ok(()),
+        }
+    }
+
+    fn log_data(&mut self) {
+        self.primary.device.log_data();
+        self.secondary.device.log_data();
+    }
+
+    fn sample(&mut self) -> Result<InputSample, Self::Error> {
+        let now = (self.clock)();
+        let primary = self.primary.read(now);
+
+        let secondary = match (self.mode, &primary) {
+            (Mode::Fallback, Some(Ok(_))) => None,
+            _ => self.secondary.read(now),
+        };
+
+        match (primary, secondary) {
+            (Some(Ok(first)), Some(Ok(second))) => Ok(merge(first, second)),
+            (Some(Ok(sample)), _) => Ok(sample),
+            (_, Some(Ok(sample))) => Ok(sample),
+            (_, Some(Err(e))) => Err(CompositeError::Secondary(e)),
+            (Some(Err(e)), None) => Err(CompositeError::Primary(e)),
+            (None, None) => Err(CompositeError::Unavailable),
+        }
+    }
+
+    fn set_input_source(&mut self, source: InputSource) {
+        self.primary.device.set_input_source(source);
+        self.secondary.device.set_input_source(source);
+    }
+
+    //the primary if it can be calibrated, otherwise the secondary
+    fn calibrate<U: CalibrationUi>(&mut self, ui: &mut U) -> Result<CalibrationOutcome, Self::Error> {
+        match self.primary.device.calibrate(ui).map_err(CompositeError::Primary)? {
+            CalibrationOutcome::NotSupported => {
+                self.secondary.device.calibrate(ui).map_err(CompositeError::Secondary)
+            }
+            outcome => Ok(outcome),
+        }
+    }
+
+    fn set_calibration(&mut self, calibration: &Calibration) {
+        self.primary.device.set_calibration(calibration);
+        self.secondary.device.set_calibration(calibration);
+    }
+
+    fn set_response(&mut self, response: &ResponseCurve) {
+        self.primary.device.set_response(response);
+        self.secondary.device.set_response(response);
+    }
+
+    //the primary's self-test, the secondary's if the primary has none
+    fn self_test(&mut self) -> Result<Option<SelfTestReport>, Self::Error> {
+        match self.primary.device.self_test().map_err(CompositeError::Primary)? {
+            None => self.secondary.device.self_test().map_err(CompositeError::Secondary),
+            report => Ok(report),
+        }
+    }
+
+    fn sleep(&mut self) -> Result<bool, Self::Error> {
+        self.primary.device.sleep().map_err(CompositeError::Primary)
+    }
+
+    fn poll_wake(&mut self) -> Result<bool, Self::Error> {
+        self.primary.device.poll_wake().map_err(CompositeError::Primary)
+    }
+}
+
+// taps win over positions, the first sample over the second
+fn merge(first: InputSample, second: InputSample) -> InputSample {
+    if first.is_tap() || (!second.is_tap() && first.input != Input::None) {
+        first
+    } else {
+        second
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Gesture;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    thread_local! {
+        static NOW: Cell<u32> = const { Cell::new(0) };
+    }
+
+    fn now() -> u32 {
+        NOW.with(|n| n.get())
+    }
+
+    fn advance(ms: u32) {
+        NOW.with(|n| n.set(n.get().wrapping_add(ms)));
+    }
+
+    // device answering with `input`, or failing while `down` is set.
+    // the log records "init" and "read" calls
+    #[derive(Clone)]
+    struct Fake {
+        input: Input,
+        down: Rc<Cell<bool>>,
+        log: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl Fake {
+        fn new(input: Input) -> Self {
+            Self {
+                input,
+                down: Rc::new(Cell::new(false)),
+                log: Rc::new(RefCell::new(Vec::new())),
+            }
+        }
+
+        fn reads(&self) -> usize {
+            self.log.borrow().iter().filter(|&&e| e == "read").count()
+        }
+    }
+
+    impl InputDevice for Fake {
+        type Error = u8;
+
+        fn init(&mut self) -> Result<(), u8> {
+            self.log.borrow_mut().push("init");
+            if self.down.get() { Err(1) } else { Ok(()) }
+        }
+
+        fn sample(&mut self) -> Result<InputSample, u8> {
+            self.log.borrow_mut().push("read");
+            if self.down.get() { Err(2) } else { Ok(InputSample::new(self.input, now())) }
+        }
+
+        fn sleep(&mut self) -> Result<bool, u8> {
+            self.log.borrow_mut().push("sleep");
+            Ok(true)
+        }
+
+        fn poll_wake(&mut self) -> Result<bool, u8> {
+            self.log.borrow_mut().push("wake");
+            Ok(true)
+        }
+    }
+
+    fn composite(mode: Mode, primary: &Fake, secondary: &Fake) -> Composite<Fake, Fake> {
+        NOW.with(|n| n.set(1000));
+        Composite::with_clock(mode, primary.clone(), secondary.clone(), now)
+    }
+
+    fn at(input: Input) -> Result<InputSample, CompositeError<u8, u8>> {
+        Ok(InputSample::new(input, now()))
+    }
+
+    #[test]
+    fn merge_prefers_taps_then_the_primary() {
+        let tilt = Fake::new(Input::Absolute(0.5));
+        let button = Fake::new(Input::Flap);
+        let mut input = composite(Mode::Merge, &tilt, &button);
+        assert_eq!(input.sample(), at(Input::Flap));
+
+        let flick = Fake::new(Input::Gesture(Gesture::Flick));
+        let mut input = composite(Mode::Merge, &flick, &button);
+        assert_eq!(input.sample(), at(Input::Gesture(Gesture::Flick)));
+
+        let idle = Fake::new(Input::None);
+        let mut input = composite(Mode::Merge, &idle, &tilt);
+        assert_eq!(input.sample(), at(Input::Absolute(0.5)));
+
+        let other = Fake::new(Input::Relative(0.1));
+        let mut input = composite(Mode::Merge, &tilt, &other);
+        assert_eq!(input.sample(), at(Input::Absolute(0.5)));
+    }
+
+    #[test]
+    fn fallback_only_reads_the_secondary_when_needed() {
+        let primary = Fake::new(Input::Absolute(0.25));
+        let secondary = Fake::new(Input::Absolute(0.75));
+        let mut input = composite(Mode::Fallback, &primary, &secondary);
+
+        assert_eq!(input.sample(), at(Input::Absolute(0.25)));
+        assert_eq!(secondary.reads(), 0);
+
+        primary.down.set(true);
+        assert_eq!(input.sample(), at(Input::Absolute(0.75)));
+        assert_eq!(input.health(), (false, true));
+    }
+
+    #[test]
+    fn failed_device_is_retried_with_backoff() {
+        let primary = Fake::new(Input::Absolute(0.25));
+        let secondary = Fake::new(Input::Absolute(0.75));
+        let mut input = composite(Mode::Fallback, &primary, &secondary);
+
+        primary.down.set(true);
+        input.sample().unwrap();
+        assert_eq!(primary.reads(), 1);
+
+        // waiting for the first backoff
+        advance(FIRST_BACKOFF_MS - 1);
+        input.sample().unwrap();
+        assert_eq!(primary.log.borrow().len(), 1);
+
+        // retried with init, fails again and waits twice as long
+        advance(1);
+        input.sample().unwrap();
+        assert_eq!(*primary.log.borrow(), ["read", "init"]);
+        advance(2 * FIRST_BACKOFF_MS - 1);
+        input.sample().unwrap();
+        assert_eq!(primary.log.borrow().len(), 2);
+
+        primary.down.set(false);
+        advance(1);
+        assert_eq!(input.sample(), at(Input::Absolute(0.25)));
+        assert_eq!(input.health(), (true, true));
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let primary = Fake::new(Input::Absolute(0.25));
+        let mut channel = Channel::new(primary);
+        for _ in 0..40 {
+            channel.failed(0);
+        }
+        assert_eq!(channel.retry_at, MAX_BACKOFF_MS);
+    }
+
+    #[test]
+    fn error_only_when_both_are_down() {
+        let primary = Fake::new(Input::Absolute(0.25));
+        let secondary = Fake::new(Input::Absolute(0.75));
+        let mut input = composite(Mode::Merge, &primary, &secondary);
+
+        primary.down.set(true);
+        secondary.down.set(true);
+        assert_eq!(input.sample(), Err(CompositeError::Secondary(2)));
+        assert_eq!(input.sample(), Err(CompositeError::Unavailable));
+    }
+
+    #[test]
+    fn init_needs_one_device() {
+        let primary = Fake::new(Input::Absolute(0.25));
+        let secondary = Fake::new(Input::Absolute(0.75));
+        primary.down.set(true);
+        let mut input = composite(Mode::Fallback, &primary, &secondary);
+
+        assert_eq!(input.init(), Ok(()));
+        assert_eq!(input.health(), (false, true));
+
+        secondary.down.set(true);
+        assert_eq!(input.init(), Err(CompositeError::Primary(1)));
+    }
+
+    #[test]
+    fn primary_sleeps_and_wakes() {
+        let primary = Fake::new(Input::Absolute(0.25));
+        let secondary = Fake::new(Input::Flap);
+        let mut input = composite(Mode::Merge, &primary, &secondary);
+
+        assert_eq!(input.sleep(), Ok(true));
+        assert_eq!(input.poll_wake(), Ok(true));
+        assert_eq!(*primary.log.borrow(), ["sleep", "wake"]);
+        assert!(secondary.log.borrow().is_empty());
+        assert_eq!(input.self_test(), Ok(None));
+    }
+}
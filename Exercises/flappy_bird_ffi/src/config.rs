@@ -19,10 +19,17 @@ pub const PLAYER_HEIGHT: u32 = 30;
 
 pub const GRAVITY: i32 = 0;
 
+// flap play, pixels per frame
+pub const FLAP_VELOCITY: i32 = -7;
+pub const FALL_ACCEL: i32 = 1;
+pub const MAX_FALL_SPEED: i32 = 6;
+
 pub const GROUND_Y_POS: Coord = 210;
 
 
 pub const MPU6050_DEV_ADDR: u8 = 0x68;
+// touch controller of the display, on the same bus
+pub const CST816S_DEV_ADDR: u8 = 0x15;
 
 pub const PLAYER_Y_MIN: Coord = SCORE_BOARD_HEIGHT as Coord;
 pub const PLAYER_Y_MAX: Coord = (LCD_HEIGHT - PLANTS_HEIGHT - PLAYER_HEIGHT) as Coord;
@@ -0,0 +1,81 @@
+// outcome of a sensor self-test, as the self-test page shows it.
+// each input device converts the report of its own driver into this one,
+// so the game does not depend on any sensor driver.
+
+// a 6 axis IMU has the most axes of the sensors the game runs on
+pub const MAX_AXES: usize = 6;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct AxisResult {
+    // short name on the page, e.g. "AX" for the x accelerometer
+    pub name: &'static str,
+    // deviation of the measured response from what it should be, None if there is nothing to compare with
+    pub change_percent: Option<f32>,
+    pub passed: bool,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SelfTestReport {
+    axes: [AxisResult; MAX_AXES],
+    len: usize,
+}
+
+impl SelfTestReport {
+    pub fn new() -> Self {
+        let unused = AxisResult {
+            name: "",
+            change_percent: None,
+            passed: false,
+        };
+        Self {
+            axes: [unused; MAX_AXES],
+            len: 0,
+        }
+    }
+
+    //adds an axis, the ones beyond MAX_AXES are dropped
+    pub fn push(&mut self, axis: AxisResult) {
+        if let Some(slot) = self.axes.get_mut(self.len) {
+            *slot = axis;
+            self.len += 1;
+        }
+    }
+
+    pub fn axes(&self) -> &[AxisResult] {
+        &self.axes[..self.len]
+    }
+
+    //all axes passed
+    pub fn passed(&self) -> bool {
+        self.axes().iter().all(|axis| axis.passed)
+    }
+}
+
+impl Default for SelfTestReport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn axes_beyond_the_last_are_dropped() {
+        let ok = AxisResult {
+            name: "AX",
+            change_percent: Some(1.0),
+            passed: true,
+        };
+        let mut report = SelfTestReport::new();
+        for _ in 0..MAX_AXES {
+            report.push(ok);
+        }
+        assert!(report.passed());
+
+        report.push(AxisResult { passed: false, ..ok });
+        assert_eq!(report.axes().len(), MAX_AXES);
+        assert!(report.passed());
+    }
+}
@@ -1,6 +1,9 @@
+use crate::color::Recolor;
 use crate::config::*;
+use crate::font::{self, Align, TextStyle, TextWriter};
+use crate::image::{self, Image, Rect};
+use crate::sprite::{self, Sprite};
 use core::convert::TryInto;
-use core::ffi;
 
 extern "C" {
     fn display_register_driver(driver: *const DisplayDriver);
@@ -8,17 +11,39 @@ extern "C" {
     fn display_draw_image(x: u16, w: u16, y: u16, h: u16, img_data: *const u16);
     fn display_fill_screen(color: u16);
     fn display_fill_rectangle(x: u16, w: u16, y: u16, h: u16, color: u16);
-    fn display_write_string(
-        x: u16,
-        y: u16,
-        str_ptr: *const ffi::c_char,
-        font: FontDef,
-        color: u16,
-        bgcolor: u16,
-    );
+    fn display_draw_pixel(x: u16, y: u16, color: u16);
+}
+
+// low level drawing operations of the panel.
+// the C display layer implements them on the board, tests can plug in a framebuffer
+pub trait DisplayBackend {
+    fn draw_pixel(&mut self, x: u16, y: u16, color: u16);
+    fn fill_rectangle(&mut self, x: u16, w: u16, y: u16, h: u16, color: u16);
+    fn draw_image(&mut self, x: u16, w: u16, y: u16, h: u16, image_data: &[u16]);
+}
 
-    static Font_16x26: FontDef;
+// backend which forwards to the registered C display driver
+pub struct FfiBackend;
+
+impl DisplayBackend for FfiBackend {
+    fn draw_pixel(&mut self, x: u16, y: u16, color: u16) {
+        unsafe {
+            display_draw_pixel(x, y, color);
+        }
+    }
 
+    fn fill_rectangle(&mut self, x: u16, w: u16, y: u16, h: u16, color: u16) {
+        unsafe {
+            display_fill_rectangle(x, w, y, h, color);
+        }
+    }
+
+    fn draw_image(&mut self, x: u16, w: u16, y: u16, h: u16, image_data: &[u16]) {
+        assert!(image_data.len() >= w as usize * h as usize, "image data too short");
+        unsafe {
+            display_draw_image(x, w, y, h, image_data.as_ptr());
+        }
+    }
 }
 
 #[repr(C)]
@@ -26,14 +51,6 @@ pub struct DisplayDriver {
     __private: [u8; 0],
 }
 
-#[derive(Copy, Clone)]
-#[repr(C)]
-pub struct FontDef {
-    width: ffi::c_uchar,
-    height: ffi::c_uchar,
-    data: *const u16,
-}
-
 pub fn register_driver(driver: &DisplayDriver) {
     unsafe {
         display_register_driver(driver as *const DisplayDriver);
@@ -46,14 +63,27 @@ pub fn init() {
     }
 }
 
-pub fn draw_image(x: Coord, w: u32, y: Coord, h: u32, image_data: &[u16]) {
-    let x: u16 = x.try_into().expect("X co-ordinate is out of range");
-    let y: u16 = y.try_into().expect("y co-ordinate is out of range");
-    let w: u16 = w.try_into().expect("width out of range");
-    let h: u16 = h.try_into().expect("height out of range");
-    unsafe {
-        display_draw_image(x, w, y, h, image_data.as_ptr());
-    }
+pub fn draw_image(x: Coord, y: Coord, image: &Image) {
+    image::blit(&mut FfiBackend, x, y, image, image.bounds());
+}
+
+//draws only the `src` part of the image, its top left corner goes to (x, y)
+pub fn draw_image_region(x: Coord, y: Coord, image: &Image, src: Rect) {
+    image::blit(&mut FfiBackend, x, y, image, src);
+}
+
+//draws the whole image with one colour replaced (see Theme::asset_recolor)
+pub fn draw_image_recolored(x: Coord, y: Coord, image: &Image, recolor: Recolor) {
+    image::blit_recolored(&mut FfiBackend, x, y, image, image.bounds(), recolor);
+}
+
+//decodes a compressed sprite onto the panel, row by row
+pub fn draw_sprite(x: Coord, y: Coord, sprite: &Sprite) {
+    sprite::draw_sprite(&mut FfiBackend, x, y, sprite).expect("corrupt sprite data");
+}
+
+pub fn draw_sprite_recolored(x: Coord, y: Coord, sprite: &Sprite, recolor: Recolor) {
+    sprite::draw_sprite_recolored(&mut FfiBackend, x, y, sprite, recolor).expect("corrupt sprite data");
 }
 
 pub fn set_background_color(bg_color: u16) {
@@ -72,11 +102,16 @@ pub fn draw_rect_angle(x: Coord, w: u32, y: Coord, h: u32, color: u16) {
     }
 }
 
-pub fn write_string(x: Coord, y: Coord, c_str: &ffi::CStr, color: u16, bgcolor: u16) {
-    let x: u16 = x.try_into().expect("X co-ordinate is out of range");
-    let y: u16 = y.try_into().expect("y co-ordinate is out of range");
-    unsafe {
-        //call  display_write_string
-        display_write_string(x, y, c_str.as_ptr(), Font_16x26, color, bgcolor);
-    }
+pub fn write_string(x: Coord, y: Coord, text: &str, style: &TextStyle) {
+    font::draw_str(&mut FfiBackend, x, y, Align::Left, text, style);
+}
+
+//`x` is the left edge, the centre or the right edge of the text depending on `align`
+pub fn write_string_aligned(x: Coord, y: Coord, align: Align, text: &str, style: &TextStyle) {
+    font::draw_str(&mut FfiBackend, x, y, align, text, style);
+}
+
+//text cursor at (x, y) for write!()
+pub fn text_writer<'a>(x: Coord, y: Coord, style: TextStyle<'a>) -> TextWriter<'a, FfiBackend> {
+    TextWriter::new(FfiBackend, x, y, style)
 }
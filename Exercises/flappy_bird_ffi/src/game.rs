@@ -1,27 +1,130 @@
-use core::ffi;
+use core::fmt::Write;
 
 use crate::assets;
-use crate::color;
+use crate::calibration::{Calibration, CalibrationOutcome, CalibrationUi, Pose};
+use embedded_hal_1::delay::DelayNs;
+use crate::color::Theme;
 use crate::config::PLAYER_Y_MAX;
 use crate::config::PLAYER_Y_MIN;
+use crate::diagnostics::SelfTestReport;
 use crate::config::{
     self,
     Coord
 };
 use crate::display;
+use crate::font::{self, TextStyle};
+use crate::menu::{self, MainEntry, Menu, MenuEvent, MAIN_MENU, MENU_DWELL_MS, PAGE_DWELL_MS};
 use crate::obstacle;
 use crate::player;
+use crate::response::ResponseCurve;
+use crate::settings::{self, InputSource, Settings, SettingsStore};
 
 extern "C" {
     fn HAL_GetTick() -> u32;
 }
 
-
+// time the game over screen stays before the menu comes back
+const GAME_OVER_MS: u32 = 3000;
+// failed input reads in a row before the game gives up and shows the fault screen,
+// single glitches are skipped
+const FAULT_ERRORS: u32 = 5;
+// time between attempts to bring the input device back
+const FAULT_RETRY_MS: u32 = 1000;
+// menu time without the pointer moving before the input device is put to sleep
+const IDLE_SLEEP_MS: u32 = 60_000;
+// pointer movement that counts as someone holding the board, in parts of the range
+const IDLE_MOVEMENT: f32 = 0.05;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum GameState {
+    Menu,
+    // page opened from the menu (high scores, credits, ..)
+    Page,
     Start,
     Running,
     End,
     Halt,
+    // the input device does not answer, retried every FAULT_RETRY_MS
+    Fault,
+    // the input device sleeps until it is moved
+    Sleep,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Gesture {
+    // short jolt of the board
+    Flick,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Input {
+    // position in the device's range, 0.0 = top .. 1.0 = bottom
+    Absolute(f32),
+    // movement since the last sample, in parts of the whole range, positive is down
+    Relative(f32),
+    // the player wants the bird to flap (button, tap, flick in flap play)
+    Flap,
+    Gesture(Gesture),
+    // nothing new
+    None,
+}
+
+// one reading of an input device
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct InputSample {
+    // tick when the reading was taken
+    pub time_ms: u32,
+    pub input: Input,
+}
+
+impl InputSample {
+    pub fn new(input: Input, time_ms: u32) -> Self {
+        Self { time_ms, input }
+    }
+
+    //flaps and gestures, everything that chooses or flaps rather than moves
+    pub fn is_tap(&self) -> bool {
+        matches!(self.input, Input::Flap | Input::Gesture(_))
+    }
+}
+
+// last position of the input, built up from the samples and mapped to the screen by the game
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Pointer {
+    // 0.0 = top .. 1.0 = bottom
+    position: f32,
+}
+
+impl Pointer {
+    pub fn new() -> Self {
+        Self { position: 0.0 }
+    }
+
+    //takes the position out of `sample`, true if the sample moved the pointer
+    pub fn feed(&mut self, sample: &InputSample) -> bool {
+        match sample.input {
+            Input::Absolute(position) => self.position = position.clamp(0.0, 1.0),
+            Input::Relative(delta) => self.position = (self.position + delta).clamp(0.0, 1.0),
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn position(&self) -> f32 {
+        self.position
+    }
+
+    //position on the screen, between y_min and y_max
+    pub fn y(&self, y_min: Coord, y_max: Coord) -> Coord {
+        let mapped = y_min as f32 + self.position * (y_max - y_min) as f32;
+        (mapped + 0.5) as Coord
+    }
+}
+
+impl Default for Pointer {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 pub trait InputDevice {
@@ -30,65 +133,144 @@ pub trait InputDevice {
     fn log_data(&mut self) {
 
     }
-    fn is_tap(&mut self, y_min: Coord, y_max: Coord) -> Result<(Coord, bool), Self::Error>;
+    //the next reading, Input::None if there is nothing new
+    fn sample(&mut self) -> Result<InputSample, Self::Error>;
+
+    //devices which can read more than one kind of movement switch to `source`
+    fn set_input_source(&mut self, _source: InputSource) {
+
+    }
+
+    //guided calibration, prompts go through `ui`
+    fn calibrate<U: CalibrationUi>(&mut self, _ui: &mut U) -> Result<CalibrationOutcome, Self::Error> {
+        Ok(CalibrationOutcome::NotSupported)
+    }
+
+    //calibration of the current input source from an earlier calibrate(),
+    //Calibration::default() for a source which was never calibrated
+    fn set_calibration(&mut self, _calibration: &Calibration) {
+
+    }
+
+    //devices with a continuous position shape it with `response`
+    fn set_response(&mut self, _response: &ResponseCurve) {
+
+    }
+
+    //factory self-test of the sensor, None for devices without one
+    fn self_test(&mut self) -> Result<Option<SelfTestReport>, Self::Error> {
+        Ok(None)
+    }
+
+    //low power until the device is moved, false if it cannot sleep
+    fn sleep(&mut self) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+
+    //true once the sleeping device was moved, it is awake again then
+    fn poll_wake(&mut self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
 }
 
-pub struct Game<T: InputDevice> {
+pub struct Game<T: InputDevice, S: SettingsStore> {
     state: GameState,
     score: u32,
     countdown_start_time: u32,
+    game_over_time: u32,
     obstacle: obstacle::Obstacle,
     player: player::Player,
+    theme: &'static Theme,
+    menu: Menu,
+    settings: Settings,
+    store: S,
+    pointer: Pointer,
+    // failed input reads in a row
+    input_errors: u32,
+    fault_time: u32,
+    // tick and pointer position of the last movement in the menu
+    idle_since: u32,
+    idle_position: f32,
+    // main menu entry the cursor goes back to after a page or a round
+    menu_cursor: usize,
+    // changed in the menu and not saved yet, the flash page is written once the menu is left
+    settings_changed: bool,
     pub input_device: T,
 }
 
-impl<T: InputDevice> Game<T> {
-    pub fn init(mut input_device: T) -> Result<Self, T::Error> {
+impl<T: InputDevice, S: SettingsStore> Game<T, S> {
+    //a device which does not start puts the game into the fault state
+    pub fn init(mut input_device: T, mut store: S) -> Self {
 
-        input_device.init()?;
+        let state = match input_device.init() {
+            Ok(()) => GameState::Menu,
+            Err(_) => GameState::Fault,
+        };
 
-        let game = Game {
-            state: GameState::Start,
+        let settings = settings::load(&mut store);
+        input_device.set_input_source(settings.input_source);
+        input_device.set_response(&settings.profile.response());
+        input_device.set_calibration(&settings.calibration(settings.input_source).unwrap_or_default());
+
+        Game {
+            state,
             score: 0,
             countdown_start_time: 0,
-            obstacle: obstacle::Obstacle::init(),
+            game_over_time: 0,
+            obstacle: obstacle::Obstacle::init(settings.difficulty.obstacle_speed()),
             player: player::Player::init(),
+            theme: settings.theme(),
+            menu: Menu::new(MAIN_MENU.len(), MENU_DWELL_MS),
+            settings,
+            store,
+            pointer: Pointer::new(),
+            input_errors: 0,
+            fault_time: tick(),
+            idle_since: tick(),
+            idle_position: 0.0,
+            menu_cursor: 0,
+            settings_changed: false,
             input_device,
-            
-        };
+        }
+    }
 
-        Ok(game)
+    pub fn settings(&self) -> &Settings {
+        &self.settings
     }
 
     pub fn update(&mut self) {
         match self.state {
+            GameState::Menu => self.update_menu(),
+
+            GameState::Page => {
+                if let MenuEvent::Chosen(_) = self.read_menu_event() {
+                    self.open_main_menu();
+                }
+            }
+
             GameState::Start => {
                 if self.run_countdown() {
-                    Game::<T>::set_background();
+                    self.set_background();
                     self.state = GameState::Running;
                 }
             }
 
             GameState::Running => {
                 
-                let (_, player_curr_y) = self.player.get_xy();
-
-                if let Ok(data) = self.input_device.is_tap(0, 239) {
-                   
-                   let new_y = data.0;
-                   let is_tap = data.1;
-
-                   if is_tap {
-                        self.player.move_player(new_y.clamp(PLAYER_Y_MIN, PLAYER_Y_MAX));
+                if let Some(sample) = self.read_input() {
+                   if self.settings.input_source == InputSource::Flap {
+                        self.player.fall(sample.is_tap(), self.theme);
                    } else {
-                        self.player.move_player(player_curr_y);
+                        self.pointer.feed(&sample);
+                        let new_y = self.pointer.y(0, config::LCD_HEIGHT as Coord - 1);
+                        self.player.move_player(new_y.clamp(PLAYER_Y_MIN, PLAYER_Y_MAX), self.theme);
                    }
                     
-                } else {
-                    panic!("Input device error");
+                } else if self.state == GameState::Fault {
+                    return;
                 }
                 
-                self.obstacle.move_obstacle();
+                self.obstacle.move_obstacle(self.theme);
 
                 if self.is_collison() {
                     self.state = GameState::End;
@@ -98,62 +280,326 @@ impl<T: InputDevice> Game<T> {
             }
 
             GameState::End => {
-                Game::<T>::draw_game_over_screen();
+                self.draw_game_over_screen();
                 self.show_score(96, 156);
+                if self.settings.record_score(self.score).is_some() {
+                    self.save_settings();
+                }
+                self.game_over_time = tick();
                 self.state = GameState::Halt;
             }
 
             GameState::Halt => {
+                if tick().wrapping_sub(self.game_over_time) >= GAME_OVER_MS {
+                    self.reset_round();
+                    self.open_main_menu();
+                }
+            }
+
+            GameState::Fault => {
+                let now = tick();
+                if now.wrapping_sub(self.fault_time) >= FAULT_RETRY_MS {
+                    self.fault_time = now;
+                    if self.input_device.init().is_ok() && self.input_device.sample().is_ok() {
+                        self.input_errors = 0;
+                        self.reset_round();
+                        self.open_main_menu();
+                    }
+                }
+            }
+
+            GameState::Sleep => match self.input_device.poll_wake() {
+                Ok(false) => {}
+                // a failed read shows up as a fault in the menu
+                _ => self.open_main_menu(),
+            },
+        }
+    }
+
+    //None if the read failed, after FAULT_ERRORS failures in a row the game is in the fault state
+    fn read_input(&mut self) -> Option<InputSample> {
+        match self.input_device.sample() {
+            Ok(sample) => {
+                self.input_errors = 0;
+                Some(sample)
+            }
+            Err(_) => {
+                self.input_errors += 1;
+                if self.input_errors >= FAULT_ERRORS {
+                    self.state = GameState::Fault;
+                    self.fault_time = tick();
+                    self.draw_fault_screen();
+                }
+                None
+            }
+        }
+    }
+
+    //the position moves the cursor, a tap chooses without waiting for the dwell time
+    fn read_menu_event(&mut self) -> MenuEvent {
+        let Some(sample) = self.read_input() else {
+            return MenuEvent::None;
+        };
+
+        if sample.is_tap() {
+            self.idle_since = sample.time_ms;
+            return self.menu.tap();
+        }
+        let moved = self.pointer.feed(&sample);
+        if (self.pointer.position() - self.idle_position).abs() > IDLE_MOVEMENT {
+            self.idle_position = self.pointer.position();
+            self.idle_since = sample.time_ms;
+        }
+        let (y_min, y_max) = self.menu.input_range();
+        self.menu.update(self.pointer.y(y_min, y_max), moved, sample.time_ms)
+    }
+
+    fn update_menu(&mut self) {
+        let now = tick();
+
+        match self.read_menu_event() {
+            _ if self.state == GameState::Fault => {}
+            MenuEvent::Moved { from } => {
+                self.draw_menu_entry(from);
+                self.draw_menu_entry(self.menu.cursor());
+            }
+            MenuEvent::Chosen(index) => {
+                self.menu_cursor = index;
+                self.choose(MAIN_MENU[index]);
+            }
+            MenuEvent::None => menu::draw_progress(self.menu.cursor(), self.menu.progress(now), self.theme),
+        }
+
+        if self.state == GameState::Menu && now.wrapping_sub(self.idle_since) >= IDLE_SLEEP_MS {
+            self.menu_cursor = self.menu.cursor();
+            self.sleep();
+        }
+
+        if self.state != GameState::Menu && self.settings_changed {
+            self.save_settings();
+        }
+    }
+
+    // devices which cannot sleep keep the menu up
+    fn sleep(&mut self) {
+        if let Ok(true) = self.input_device.sleep() {
+            self.state = GameState::Sleep;
+            self.draw_sleep_screen();
+        } else {
+            self.idle_since = tick();
+        }
+    }
+
+    fn choose(&mut self, entry: MainEntry) {
+        match entry {
+            MainEntry::Start => {
+                self.reset_round();
+                self.draw_start_screen();
+                self.state = GameState::Start;
+            }
+            MainEntry::Difficulty => {
+                self.settings.difficulty = self.settings.difficulty.next();
+                self.settings_changed = true;
+                self.draw_menu_entry(self.menu.cursor());
+            }
+            MainEntry::InputSource => {
+                self.settings.input_source = self.settings.input_source.next();
+                let source = self.settings.input_source;
+                self.input_device.set_input_source(source);
+                // a range measured with the old source does not fit the new one
+                self.input_device.set_calibration(&self.settings.calibration(source).unwrap_or_default());
+                self.settings_changed = true;
+                self.draw_menu_entry(self.menu.cursor());
+                self.draw_menu_entry(MainEntry::Calibrate as usize);
+            }
+            MainEntry::Profile => {
+                self.settings.profile = self.settings.profile.next();
+                self.input_device.set_response(&self.settings.profile.response());
+                self.settings_changed = true;
+                self.draw_menu_entry(self.menu.cursor());
+            }
+            MainEntry::Theme => {
+                self.settings.set_theme(self.theme.next());
+                self.settings_changed = true;
+                self.set_theme(self.settings.theme());
+            }
+            MainEntry::Calibrate => {
+                let mut screen = CalibrationScreen { theme: self.theme };
+                let result = match self.input_device.calibrate(&mut screen) {
+                    Ok(CalibrationOutcome::Done(calibration)) => {
+                        self.settings.set_calibration(self.settings.input_source, calibration);
+                        self.settings_changed = true;
+                        "Done"
+                    }
+                    Ok(CalibrationOutcome::NotSupported) => "Nothing to\ncalibrate",
+                    Ok(CalibrationOutcome::Aborted) => "Board did not\nkeep still",
+                    Err(_) => "Failed",
+                };
+                self.open_page("CALIBRATE", result);
+            }
+            MainEntry::SelfTest => self.run_self_test(),
+            MainEntry::HighScores => {
+                self.open_page("SCORES", "");
+                menu::draw_high_scores(&self.settings.high_scores, self.theme);
+            }
+            MainEntry::Credits => self.open_page("CREDITS", menu::CREDITS),
+        }
+    }
+
+    fn draw_menu_entry(&self, index: usize) {
+        let entry = MAIN_MENU[index];
+        let selected = index == self.menu.cursor();
+        menu::draw_entry(index, entry.label(), entry.value(&self.settings), selected, self.theme);
+    }
+
+    pub fn draw_main_menu(&self) {
+        menu::draw_main_menu(&self.menu, &self.settings, self.theme);
+    }
+
+    //first screen after init: the fault screen, or the boot diagnostics when a finger is held
+    //on the panel or the board is flicked while starting, otherwise the menu
+    pub fn draw_screen(&mut self) {
+        if self.state == GameState::Fault {
+            self.draw_fault_screen();
+        } else if self.input_device.sample().is_ok_and(|sample| sample.is_tap()) {
+            self.run_self_test();
+        } else {
+            self.draw_main_menu();
+        }
+    }
 
+    // the result stays on a page until it is left
+    fn run_self_test(&mut self) {
+        menu::draw_title("SELF TEST", self.theme);
+        menu::draw_text("Keep the board\nstill", self.theme);
+        match self.input_device.self_test() {
+            Ok(Some(report)) => {
+                self.open_page("SELF TEST", "");
+                menu::draw_self_test(&report, self.theme);
             }
+            Ok(None) => self.open_page("SELF TEST", "Nothing to\ntest"),
+            Err(_) => self.open_page("SELF TEST", "Failed"),
         }
     }
 
-    pub fn draw_game_over_screen() {
-        Game::<T>::set_background();
-        display::draw_image(40, 160, 40, 80, &assets::GAME_OVER_IMAGE_DATA);
+    fn draw_sleep_screen(&self) {
+        menu::draw_title("SLEEP", self.theme);
+        menu::draw_text("Pick up the\nboard to play", self.theme);
     }
 
-    pub fn draw_start_screen() {
-        Game::<T>::set_background();
-        display::draw_image(40, 160, 40, 80, &assets::GAME_NAME_IMG_DATA);
-        let text = c"Game Starts In";
-        display::write_string(0, 120, text, color::RED, color::BACKGROUND);
+    fn draw_fault_screen(&self) {
+        menu::draw_title("FAULT", self.theme);
+        menu::draw_text("Input device\nnot answering\n\nretrying..", self.theme);
     }
 
-    pub fn set_background() {
+    fn open_main_menu(&mut self) {
+        self.idle_since = tick();
+        // a board held still does not choose the same entry again
+        self.menu = Menu::resume(MAIN_MENU.len(), MENU_DWELL_MS, self.menu_cursor);
+        self.state = GameState::Menu;
+        self.draw_main_menu();
+    }
+
+    fn open_page(&mut self, title: &str, text: &str) {
+        self.menu = Menu::new(1, PAGE_DWELL_MS);
+        self.state = GameState::Page;
+        menu::draw_page(title, text, self.theme);
+    }
+
+    // a write that fails only loses the change at the next power cycle
+    fn save_settings(&mut self) {
+        self.settings_changed = false;
+        let _ = settings::save(&mut self.store, &self.settings);
+    }
+
+    //fresh bird, obstacle and score for the next round
+    fn reset_round(&mut self) {
+        self.score = 0;
+        self.countdown_start_time = 0;
+        self.obstacle = obstacle::Obstacle::init(self.settings.difficulty.obstacle_speed());
+        self.player = player::Player::init();
+    }
+
+    pub fn theme(&self) -> &'static Theme {
+        self.theme
+    }
+
+    //switches the colours, the current screen is redrawn with the new theme
+    pub fn set_theme(&mut self, theme: &'static Theme) {
+        if theme == self.theme {
+            return;
+        }
+        self.theme = theme;
+
+        match self.state {
+            GameState::Menu => self.draw_main_menu(),
+            GameState::Page => self.open_main_menu(),
+            GameState::Start => self.draw_start_screen(),
+            GameState::Running => {
+                // pipes and bird are drawn again with the next update
+                self.set_background();
+                self.show_score(96, 0);
+            }
+            GameState::End => {}
+            GameState::Halt => {
+                self.draw_game_over_screen();
+                self.show_score(96, 156);
+            }
+            GameState::Fault => self.draw_fault_screen(),
+            GameState::Sleep => self.draw_sleep_screen(),
+        }
+    }
+
+    pub fn draw_game_over_screen(&self) {
+        self.set_background();
+        display::draw_sprite_recolored(40, 40, &assets::GAME_OVER_IMAGE, self.theme.asset_recolor());
+    }
+
+    pub fn draw_start_screen(&self) {
+        let theme = self.theme;
+        self.set_background();
+        display::draw_sprite_recolored(40, 40, &assets::GAME_NAME_IMG, theme.asset_recolor());
+        display::write_string(0, 120, "Game Starts In", &text_style(theme.highlight, theme.background));
+    }
+
+    pub fn set_background(&self) {
+        let theme = self.theme;
+
         //1. set the background color
-        display::set_background_color(color::BACKGROUND);
+        display::set_background_color(theme.background);
 
         //2. print the scoreboard area
-        print_score_card_background();
+        print_score_card_background(theme);
 
         //3. print the plant
-        display::draw_image(0, 60, 210, config::PLANTS_HEIGHT, &assets::PLANT_IMG_DATA);
-        display::draw_image(60, 60, 210, config::PLANTS_HEIGHT, &assets::PLANT_IMG_DATA);
-        display::draw_image(120, 60, 210, config::PLANTS_HEIGHT, &assets::PLANT_IMG_DATA);
-        display::draw_image(180, 60, 210, config::PLANTS_HEIGHT, &assets::PLANT_IMG_DATA);
+        let plant = &assets::PLANT_IMG;
+        let plant_y = (config::LCD_HEIGHT - plant.height()) as Coord;
+        for x in (0..config::LCD_WIDTH).step_by(plant.width() as usize) {
+            // the last plant ends flush with the right edge
+            let x = x.min(config::LCD_WIDTH - plant.width());
+            display::draw_image_recolored(x as Coord, plant_y, plant, theme.asset_recolor());
+        }
     }
 
     //returns 'true' if countdown is over , otherwise 'false'
     fn run_countdown(&mut self) -> bool {
         if self.countdown_start_time == 0 {
-            self.countdown_start_time = unsafe { HAL_GetTick() };
+            self.countdown_start_time = tick();
         }
 
-        let elapsed = unsafe { HAL_GetTick() } - self.countdown_start_time;
+        let elapsed = tick() - self.countdown_start_time;
         let number = if elapsed < 1000 {
-            c"3"
+            "3"
         } else if elapsed < 2000 {
-            c"2"
+            "2"
         } else if elapsed < 3000 {
-            c"1"
+            "1"
         } else {
             self.countdown_start_time = 0;
             return true;
         };
 
-        display::write_string(112, 156, number, color::BLACK, color::BACKGROUND);
+        display::write_string(112, 156, number, &text_style(self.theme.text, self.theme.background));
 
         false
     }
@@ -212,31 +658,14 @@ impl<T: InputDevice> Game<T> {
     }
 
     fn show_score(&self, x: config::Coord, y: config::Coord) {
-        let mut buf = [0u8; 4];
+        let mut writer = display::text_writer(x, y, text_style(self.theme.text, self.theme.score));
 
-        let score = self.score;
-
-        if score >= 1000 {
-            buf[0] = b'W';
-            buf[1] = b'I';
-            buf[2] = b'N';
+        // drawing text never fails, the fmt::Result is always Ok
+        let _ = if self.score >= 1000 {
+            writer.write_str("WIN")
         } else {
-            buf[0] = b'0' + ((score / 100) % 10) as u8;
-            buf[1] = b'0' + ((score / 10) % 10) as u8;
-            buf[2] = b'0' + (score % 10) as u8;
-        }
-
-        buf[3] = b'\0';
-
-        let score_str = ffi::CStr::from_bytes_with_nul(&buf);
-            display::write_string(
-                x ,
-                y,
-                score_str.unwrap(),
-                color::BLACK,
-                color::SCORE,
-            );
-        
+            write!(writer, "{:03}", self.score)
+        };
     }
 
 
@@ -250,7 +679,276 @@ impl<T: InputDevice> Game<T> {
     }
 }
 
-fn print_score_card_background() {
-    display::draw_rect_angle(0, 240, 0, 28, color::WHITE);
-    display::draw_rect_angle(0, 240, 28, 2, color::BLACK);
+// prompts of the calibration routine
+struct CalibrationScreen {
+    theme: &'static Theme,
+}
+
+impl CalibrationUi for CalibrationScreen {
+    fn show_pose(&mut self, pose: Pose) {
+        menu::draw_title("CALIBRATE", self.theme);
+        menu::draw_text(pose.prompt(), self.theme);
+    }
+
+    fn now_ms(&mut self) -> u32 {
+        tick()
+    }
+}
+
+pub fn tick() -> u32 {
+    unsafe { HAL_GetTick() }
+}
+
+// busy waits on the HAL tick, in whole milliseconds
+pub struct TickDelay;
+
+impl DelayNs for TickDelay {
+    fn delay_ns(&mut self, ns: u32) {
+        let ms = ns.div_ceil(1_000_000);
+        let start = tick();
+        while tick().wrapping_sub(start) < ms {}
+    }
+}
+
+fn text_style(color: u16, bgcolor: u16) -> TextStyle<'static> {
+    TextStyle {
+        font: &font::FONT_16X26,
+        color,
+        bgcolor,
+    }
+}
+
+fn print_score_card_background(theme: &Theme) {
+    display::draw_rect_angle(0, 240, 0, 28, theme.hud);
+    display::draw_rect_angle(0, 240, 28, 2, theme.hud_border);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::replay::ScriptedInput;
+    use crate::settings::{Difficulty, RamStore, RECORD_LEN};
+    use crate::test_support::set_tick;
+
+    // RamStore which counts the writes, every one of them erases the flash page on the board
+    #[derive(Default)]
+    struct CountingStore {
+        store: RamStore,
+        writes: usize,
+    }
+
+    impl SettingsStore for CountingStore {
+        type Error = core::convert::Infallible;
+
+        fn read(&mut self, buf: &mut [u8; RECORD_LEN]) -> Result<(), Self::Error> {
+            self.store.read(buf)
+        }
+
+        fn write(&mut self, data: &[u8; RECORD_LEN]) -> Result<(), Self::Error> {
+            self.writes += 1;
+            self.store.write(data)
+        }
+    }
+
+    // main menu entry `index` under the pointer
+    fn on_entry(index: usize) -> Input {
+        let (_, y_max) = Menu::new(MAIN_MENU.len(), MENU_DWELL_MS).input_range();
+        Input::Absolute((index as f32 + 0.5) * 16.0 / y_max as f32)
+    }
+
+    // `input` every 10 ms from `start_ms` for `duration_ms`
+    fn hold(input: Input, start_ms: u32, duration_ms: u32) -> impl Iterator<Item = InputSample> {
+        (start_ms..start_ms + duration_ms).step_by(10).map(move |t| InputSample::new(input, t))
+    }
+
+    // one update per sample, with the tick at the time of the sample
+    fn play<T: InputDevice, S: SettingsStore>(game: &mut Game<T, S>, samples: &[InputSample]) {
+        for sample in samples {
+            set_tick(sample.time_ms);
+            game.update();
+        }
+    }
+
+    #[test]
+    fn settings_are_saved_once_the_menu_is_left() {
+        let samples: Vec<_> = hold(on_entry(1), 0, 10_000).chain(hold(on_entry(0), 10_000, 2000)).collect();
+        set_tick(0);
+        let mut game = Game::init(ScriptedInput::new(&samples), CountingStore::default());
+
+        play(&mut game, &samples[..1000]);
+        // holding still on "Level" changed it once and wrote nothing yet
+        assert_eq!(game.state, GameState::Menu);
+        assert_eq!(game.settings().difficulty, Difficulty::Normal.next());
+        assert_eq!(game.store.writes, 0);
+
+        play(&mut game, &samples[1000..]);
+        assert_eq!(game.state, GameState::Start);
+        assert_eq!(game.store.writes, 1);
+        assert_eq!(settings::load(&mut game.store).difficulty, Difficulty::Normal.next());
+    }
+
+    #[test]
+    fn pointer_maps_to_screen() {
+        let mut pointer = Pointer::new();
+        assert_eq!(pointer.y(0, 200), 0);
+
+        pointer.feed(&InputSample::new(Input::Absolute(0.5), 0));
+        assert_eq!(pointer.y(0, 200), 100);
+        assert_eq!(pointer.y(30, 180), 105);
+
+        pointer.feed(&InputSample::new(Input::Absolute(1.7), 10));
+        assert_eq!(pointer.y(0, 200), 200);
+    }
+
+    #[test]
+    fn relative_samples_move_the_pointer() {
+        let mut pointer = Pointer::new();
+        assert!(pointer.feed(&InputSample::new(Input::Relative(0.25), 0)));
+        assert!(pointer.feed(&InputSample::new(Input::Relative(0.25), 10)));
+        assert_eq!(pointer.position(), 0.5);
+        pointer.feed(&InputSample::new(Input::Relative(-2.0), 20));
+        assert_eq!(pointer.position(), 0.0);
+    }
+
+    #[test]
+    fn taps_do_not_move_the_pointer() {
+        let mut pointer = Pointer::new();
+        pointer.feed(&InputSample::new(Input::Absolute(0.3), 0));
+
+        for input in [Input::Flap, Input::Gesture(Gesture::Flick), Input::None] {
+            let sample = InputSample::new(input, 10);
+            assert!(!pointer.feed(&sample));
+            assert_eq!(sample.is_tap(), input != Input::None);
+        }
+        assert_eq!(pointer.position(), 0.3);
+    }
+
+    // scripted input which keeps the calibration the game hands it
+    struct CalibratedInput<'a> {
+        input: ScriptedInput<'a>,
+        calibration: Option<Calibration>,
+    }
+
+    impl InputDevice for CalibratedInput<'_> {
+        type Error = core::convert::Infallible;
+
+        fn init(&mut self) -> Result<(), Self::Error> {
+            self.input.init()
+        }
+
+        fn sample(&mut self) -> Result<InputSample, Self::Error> {
+            self.input.sample()
+        }
+
+        fn set_calibration(&mut self, calibration: &Calibration) {
+            self.calibration = Some(*calibration);
+        }
+    }
+
+    #[test]
+    fn calibration_follows_the_input_source() {
+        let roll = Calibration {
+            tilt_top: 5.0,
+            tilt_bottom: 60.0,
+            ..Calibration::default()
+        };
+        let mut store = CountingStore::default();
+        let mut settings = Settings::default();
+        settings.set_calibration(InputSource::Roll, roll);
+        settings::save(&mut store, &settings).unwrap();
+
+        let samples: Vec<_> = hold(on_entry(2), 0, 2000).collect();
+        let input = CalibratedInput {
+            input: ScriptedInput::new(&samples),
+            calibration: None,
+        };
+        set_tick(0);
+        let mut game = Game::init(input, store);
+        assert_eq!(game.input_device.calibration, Some(roll));
+
+        // "Input" switches from Roll to Pitch, which was never calibrated
+        play(&mut game, &samples);
+        assert_eq!(game.settings().input_source, InputSource::Pitch);
+        assert_eq!(game.input_device.calibration, Some(Calibration::default()));
+        assert_eq!(MainEntry::Calibrate.value(game.settings()), Some("none"));
+    }
+
+    #[test]
+    fn idle_menu_sleeps_until_moved() {
+        // asleep, the still board reads as Input::None until it is moved
+        let samples: Vec<_> = hold(on_entry(3), 0, 60_010)
+            .chain(hold(Input::None, 60_010, 3000))
+            .chain(hold(on_entry(5), 63_010, 10))
+            .collect();
+        set_tick(0);
+        let mut game = Game::init(ScriptedInput::new(&samples), CountingStore::default());
+
+        // the held entry is chosen once, after that nothing moves
+        play(&mut game, &samples[..6000]);
+        assert_eq!(game.state, GameState::Menu);
+        assert_eq!(game.store.writes, 0);
+
+        play(&mut game, &samples[6000..6001]);
+        assert_eq!(game.state, GameState::Sleep);
+        // the changed profile went to flash when the menu was left
+        assert_eq!(game.store.writes, 1);
+
+        play(&mut game, &samples[6001..6301]);
+        assert_eq!(game.state, GameState::Sleep);
+        play(&mut game, &samples[6301..]);
+        assert_eq!(game.state, GameState::Menu);
+        assert!(game.input_device.is_finished());
+    }
+
+    // scripted input with a self-test, counts the runs
+    struct SelfTestingInput<'a> {
+        input: ScriptedInput<'a>,
+        runs: usize,
+    }
+
+    impl InputDevice for SelfTestingInput<'_> {
+        type Error = core::convert::Infallible;
+
+        fn init(&mut self) -> Result<(), Self::Error> {
+            self.input.init()
+        }
+
+        fn sample(&mut self) -> Result<InputSample, Self::Error> {
+            self.input.sample()
+        }
+
+        fn self_test(&mut self) -> Result<Option<SelfTestReport>, Self::Error> {
+            self.runs += 1;
+            Ok(Some(SelfTestReport::new()))
+        }
+    }
+
+    #[test]
+    fn tap_while_starting_runs_the_self_test() {
+        let held: Vec<_> = hold(Input::Flap, 0, 10).chain(hold(Input::Absolute(0.5), 10, 3100)).collect();
+        set_tick(0);
+        let input = SelfTestingInput {
+            input: ScriptedInput::new(&held),
+            runs: 0,
+        };
+        let mut game = Game::init(input, CountingStore::default());
+        game.draw_screen();
+        assert_eq!(game.state, GameState::Page);
+        assert_eq!(game.input_device.runs, 1);
+
+        // the page is left like the one opened from the menu
+        play(&mut game, &held[1..]);
+        assert_eq!(game.state, GameState::Menu);
+        assert_eq!(game.input_device.runs, 1);
+
+        let still: Vec<_> = hold(Input::None, 0, 10).collect();
+        let input = SelfTestingInput {
+            input: ScriptedInput::new(&still),
+            runs: 0,
+        };
+        let mut game = Game::init(input, CountingStore::default());
+        game.draw_screen();
+        assert_eq!(game.state, GameState::Menu);
+        assert_eq!(game.input_device.runs, 0);
+    }
 }
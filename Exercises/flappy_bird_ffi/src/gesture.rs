@@ -0,0 +1,210 @@
+// flick detection on the accelerometer stream.
+// a flick is a short, sharp jolt of the board: the acceleration moves away
+// from 1 g by more than `magnitude` and changes faster than `jerk` on the way.
+// slow tilting keeps the magnitude at 1 g, hand tremor stays below both.
+// after a flick nothing is reported for `refractory_ms`, and not before the
+// board has settled again, so one jolt gives one flick.
+
+use crate::calibration::ONE_G;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GestureConfig {
+    // distance of the acceleration from 1 g, in g
+    pub magnitude: f32,
+    // change of the acceleration, in g/s
+    pub jerk: f32,
+    pub refractory_ms: u32,
+}
+
+impl Default for GestureConfig {
+    fn default() -> Self {
+        Self {
+            // the most the motion interrupt can be set to, so both ways see the same flicks
+            magnitude: 0.5,
+            jerk: 40.0,
+            refractory_ms: 250,
+        }
+    }
+}
+
+impl GestureConfig {
+    //MOT_THR value (2 mg per count) for the MPU6050 motion interrupt,
+    //None if the magnitude is outside the 2 .. 510 mg the register holds
+    pub fn motion_threshold(&self) -> Option<u8> {
+        let counts = libm::roundf(self.magnitude * 500.0);
+        (1.0..=255.0).contains(&counts).then_some(counts as u8)
+    }
+}
+
+pub struct GestureDetector {
+    config: GestureConfig,
+    // last sample and its tick
+    last: Option<([f32; 3], u32)>,
+    last_flick: Option<u32>,
+    // false from a flick until the board has settled
+    armed: bool,
+}
+
+impl GestureDetector {
+    pub fn new(config: GestureConfig) -> Self {
+        Self {
+            config,
+            last: None,
+            last_flick: None,
+            armed: true,
+        }
+    }
+
+    pub fn config(&self) -> &GestureConfig {
+        &self.config
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new(self.config);
+    }
+
+    //feeds an offset corrected raw reading taken at tick `now_ms`, true on a flick
+    pub fn update(&mut self, accel: [f32; 3], now_ms: u32) -> bool {
+        let one_g = ONE_G as f32;
+        let deviation = (norm(accel) - one_g).abs() / one_g;
+
+        let Some((last, last_ms)) = self.last else {
+            self.last = Some((accel, now_ms));
+            return false;
+        };
+        let dt_ms = now_ms.wrapping_sub(last_ms);
+        if dt_ms == 0 {
+            return false;
+        }
+        self.last = Some((accel, now_ms));
+
+        if deviation < self.config.magnitude / 2.0 {
+            self.armed = true;
+        }
+
+        let change = [accel[0] - last[0], accel[1] - last[1], accel[2] - last[2]];
+        let jerk = norm(change) / one_g * 1000.0 / dt_ms as f32;
+
+        let flick = self.armed
+            && deviation > self.config.magnitude
+            && jerk > self.config.jerk
+            && self.confirm(now_ms);
+        if flick {
+            self.armed = false;
+        }
+        flick
+    }
+
+    //a flick seen somewhere else (the motion interrupt), true if it is not too close to the last one
+    pub fn confirm(&mut self, now_ms: u32) -> bool {
+        if let Some(last) = self.last_flick {
+            if now_ms.wrapping_sub(last) < self.config.refractory_ms {
+                return false;
+            }
+        }
+
+        self.last_flick = Some(now_ms);
+        true
+    }
+}
+
+fn norm(v: [f32; 3]) -> f32 {
+    libm::sqrtf(v[0] * v[0] + v[1] * v[1] + v[2] * v[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const G: f32 = ONE_G as f32;
+
+    // board lying flat, 100 Hz, with a jolt of `g` extra on z at samples `at`
+    fn flat_with_jolts(len: usize, at: &[usize], g: f32) -> Vec<[f32; 3]> {
+        (0..len)
+            .map(|i| {
+                let jolt = if at.contains(&i) { g * G } else { 0.0 };
+                [0.0, 0.0, G + jolt]
+            })
+            .collect()
+    }
+
+    fn flicks(detector: &mut GestureDetector, samples: &[[f32; 3]]) -> Vec<usize> {
+        samples
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &s)| detector.update(s, 10 * i as u32).then_some(i))
+            .collect()
+    }
+
+    #[test]
+    fn jolt_is_a_flick() {
+        let mut detector = GestureDetector::new(GestureConfig::default());
+        let samples = flat_with_jolts(50, &[10, 11, 40], 1.5);
+        // two samples of the same jolt are one flick
+        assert_eq!(flicks(&mut detector, &samples), [10, 40]);
+    }
+
+    #[test]
+    fn refractory_period() {
+        let mut detector = GestureDetector::new(GestureConfig::default());
+        // settled in between, but only 100 ms apart
+        let samples = flat_with_jolts(50, &[10, 20, 40], 1.5);
+        assert_eq!(flicks(&mut detector, &samples), [10, 40]);
+    }
+
+    #[test]
+    fn slow_tilt_and_small_shakes_are_not_flicks() {
+        let mut detector = GestureDetector::new(GestureConfig::default());
+
+        // 90 degrees in one second
+        let tilt: Vec<[f32; 3]> = (0..100)
+            .map(|i| {
+                let rad = (i as f32 * 0.9).to_radians();
+                [0.0, G * rad.sin(), G * rad.cos()]
+            })
+            .collect();
+        assert!(flicks(&mut detector, &tilt).is_empty());
+
+        let shake = flat_with_jolts(50, &[10, 20, 30], 0.3);
+        assert!(flicks(&mut detector, &shake).is_empty());
+    }
+
+    #[test]
+    fn tremor_in_the_tilt_trace_is_not_a_flick() {
+        let mut detector = GestureDetector::new(GestureConfig::default());
+        let found = include_str!("../testdata/tilt_trace_synthetic.csv")
+            .lines()
+            .filter(|line| !line.starts_with('#') && !line.starts_with("t_ms"))
+            .filter(|line| {
+                let v: Vec<f32> = line.split(',').map(|f| f.parse().unwrap()).collect();
+                detector.update([v[1], v[2], v[3]], v[0] as u32)
+            })
+            .count();
+        assert_eq!(found, 0);
+    }
+
+    #[test]
+    fn interrupt_flicks_keep_the_refractory_period() {
+        let mut detector = GestureDetector::new(GestureConfig::default());
+        assert!(detector.confirm(1000));
+        assert!(!detector.confirm(1100));
+        assert!(detector.confirm(1300));
+    }
+
+    #[test]
+    fn motion_threshold_counts() {
+        let config = GestureConfig {
+            magnitude: 0.1,
+            ..GestureConfig::default()
+        };
+        assert_eq!(config.motion_threshold(), Some(50));
+        assert_eq!(GestureConfig::default().motion_threshold(), Some(250));
+
+        // more than the register holds
+        let config = GestureConfig {
+            magnitude: 0.6,
+            ..GestureConfig::default()
+        };
+        assert_eq!(config.motion_threshold(), None);
+    }
+}
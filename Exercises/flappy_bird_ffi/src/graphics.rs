@@ -0,0 +1,253 @@
+// embedded-graphics support for the display layer
+// every drawing request is routed to the cheapest call of the display backend:
+// 1. solid fills            -> fill_rectangle (display_fill_rectangle)
+// 2. contiguous blits       -> draw_image (display_draw_image), one row at a time
+// 3. everything else        -> draw_pixel (display_draw_pixel)
+//
+// on the board: let mut screen = Screen::new(display::FfiBackend);
+
+use core::convert::Infallible;
+
+use embedded_graphics::pixelcolor::raw::RawU16;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+
+use crate::color;
+use crate::config::{LCD_HEIGHT, LCD_WIDTH};
+use crate::display::DisplayBackend;
+
+pub struct Screen<B: DisplayBackend> {
+    backend: B,
+}
+
+impl<B: DisplayBackend> Screen<B> {
+    pub fn new(backend: B) -> Self {
+        Self { backend }
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    pub fn release(self) -> B {
+        self.backend
+    }
+}
+
+fn to_raw(color: Rgb565) -> u16 {
+    RawU16::from(color).into_inner()
+}
+
+impl<B: DisplayBackend> OriginDimensions for Screen<B> {
+    fn size(&self) -> Size {
+        Size::new(LCD_WIDTH, LCD_HEIGHT)
+    }
+}
+
+impl<B: DisplayBackend> DrawTarget for Screen<B> {
+    type Color = Rgb565;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let screen = self.bounding_box();
+
+        for Pixel(point, color) in pixels {
+            if screen.contains(point) {
+                self.backend
+                    .draw_pixel(point.x as u16, point.y as u16, to_raw(color));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let visible = area.intersection(&self.bounding_box());
+        if visible.is_zero_sized() {
+            return Ok(());
+        }
+
+        // colors are given row by row for the whole (unclipped) area,
+        // only the visible part of every row is sent to the panel
+        let area_w = area.size.width as usize;
+        let visible_w = visible.size.width as usize;
+        let skip_left = (visible.top_left.x - area.top_left.x) as usize;
+        let skip_top = (visible.top_left.y - area.top_left.y) as usize;
+
+        let mut colors = colors.into_iter();
+        if skip_top > 0 && colors.nth(skip_top * area_w - 1).is_none() {
+            return Ok(());
+        }
+
+        // a single row of the panel is buffered, never the whole image
+        let mut row = [0u16; LCD_WIDTH as usize];
+
+        for y in visible.rows() {
+            let mut taken: usize = 0;
+            for (i, color) in colors.by_ref().take(area_w).enumerate() {
+                if i >= skip_left && i < skip_left + visible_w {
+                    row[i - skip_left] = color::image_order(to_raw(color));
+                }
+                taken += 1;
+            }
+
+            let filled = taken.saturating_sub(skip_left).min(visible_w);
+            if filled > 0 {
+                self.backend.draw_image(
+                    visible.top_left.x as u16,
+                    filled as u16,
+                    y as u16,
+                    1,
+                    &row[..filled],
+                );
+            }
+
+            // iterator ran out early, the rest of the area stays untouched
+            if taken < area_w {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let visible = area.intersection(&self.bounding_box());
+        if visible.is_zero_sized() {
+            return Ok(());
+        }
+
+        self.backend.fill_rectangle(
+            visible.top_left.x as u16,
+            visible.size.width as u16,
+            visible.top_left.y as u16,
+            visible.size.height as u16,
+            to_raw(color),
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::FrameBuffer;
+    use embedded_graphics::image::{Image, ImageRaw};
+    use embedded_graphics::primitives::{Line, PrimitiveStyle};
+
+    fn screen() -> Screen<FrameBuffer> {
+        Screen::new(FrameBuffer::new())
+    }
+
+    #[test]
+    fn size_matches_lcd() {
+        assert_eq!(screen().size(), Size::new(LCD_WIDTH, LCD_HEIGHT));
+    }
+
+    #[test]
+    fn solid_fill_is_one_rectangle() {
+        let mut screen = screen();
+        screen
+            .fill_solid(&Rectangle::new(Point::new(10, 20), Size::new(5, 4)), Rgb565::RED)
+            .unwrap();
+
+        let fb = screen.release();
+        assert_eq!(fb.rect_calls, 1);
+        assert_eq!(fb.pixel_calls, 0);
+        assert_eq!(fb.at(10, 20), 0xF800);
+        assert_eq!(fb.at(14, 23), 0xF800);
+        assert_eq!(fb.at(15, 23), 0);
+        assert_eq!(fb.at(14, 24), 0);
+    }
+
+    #[test]
+    fn solid_fill_is_clipped_to_screen() {
+        let mut screen = screen();
+        screen
+            .fill_solid(&Rectangle::new(Point::new(-5, 235), Size::new(10, 10)), Rgb565::WHITE)
+            .unwrap();
+        screen
+            .fill_solid(&Rectangle::new(Point::new(300, 0), Size::new(10, 10)), Rgb565::WHITE)
+            .unwrap();
+
+        let fb = screen.release();
+        assert_eq!(fb.rect_calls, 1);
+        assert_eq!(fb.at(0, 235), 0xFFFF);
+        assert_eq!(fb.at(4, 239), 0xFFFF);
+        assert_eq!(fb.at(5, 239), 0);
+    }
+
+    #[test]
+    fn image_is_blitted_row_by_row() {
+        let data: [u8; 12] = [
+            0x00, 0x01, 0x00, 0x02, 0x00, 0x03, //
+            0x00, 0x04, 0x00, 0x05, 0x00, 0x06,
+        ];
+        let raw = ImageRaw::<Rgb565>::new(&data, 3);
+
+        let mut screen = screen();
+        Image::new(&raw, Point::new(100, 50)).draw(&mut screen).unwrap();
+
+        let fb = screen.release();
+        assert_eq!(fb.image_calls, 2);
+        assert_eq!(fb.pixel_calls, 0);
+        assert_eq!(fb.at(100, 50), 1);
+        assert_eq!(fb.at(102, 50), 3);
+        assert_eq!(fb.at(100, 51), 4);
+        assert_eq!(fb.at(102, 51), 6);
+    }
+
+    #[test]
+    fn image_is_clipped_at_the_edges() {
+        let data: [u8; 12] = [
+            0x00, 0x01, 0x00, 0x02, 0x00, 0x03, //
+            0x00, 0x04, 0x00, 0x05, 0x00, 0x06,
+        ];
+        let raw = ImageRaw::<Rgb565>::new(&data, 3);
+
+        let mut screen = screen();
+        Image::new(&raw, Point::new(-1, -1)).draw(&mut screen).unwrap();
+
+        let fb = screen.release();
+        assert_eq!(fb.image_calls, 1);
+        assert_eq!(fb.at(0, 0), 5);
+        assert_eq!(fb.at(1, 0), 6);
+        assert_eq!(fb.at(2, 0), 0);
+    }
+
+    #[test]
+    fn short_color_iterator_leaves_rest_untouched() {
+        let mut screen = screen();
+        let area = Rectangle::new(Point::new(0, 0), Size::new(4, 2));
+        let colors = [Rgb565::WHITE; 6];
+        screen.fill_contiguous(&area, colors).unwrap();
+
+        let fb = screen.release();
+        assert_eq!(fb.at(3, 0), 0xFFFF);
+        assert_eq!(fb.at(1, 1), 0xFFFF);
+        assert_eq!(fb.at(2, 1), 0);
+    }
+
+    #[test]
+    fn other_shapes_fall_back_to_pixels() {
+        let mut screen = screen();
+        Line::new(Point::new(0, 0), Point::new(3, 3))
+            .into_styled(PrimitiveStyle::with_stroke(Rgb565::BLUE, 1))
+            .draw(&mut screen)
+            .unwrap();
+        Pixel(Point::new(-1, 0), Rgb565::BLUE).draw(&mut screen).unwrap();
+        Pixel(Point::new(0, 240), Rgb565::BLUE).draw(&mut screen).unwrap();
+
+        let fb = screen.release();
+        assert_eq!(fb.pixel_calls, 4);
+        assert_eq!(fb.at(2, 2), 0x001F);
+    }
+}
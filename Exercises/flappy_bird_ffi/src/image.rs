@@ -0,0 +1,196 @@
+// uncompressed RGB565 image with its dimensions.
+// Image::new is a const fn: for a static or const image a pixel count which
+// does not match width * height stops the build instead of reading past the
+// end of the array in the C driver.
+
+use crate::color::Recolor;
+use crate::config::*;
+use crate::display::DisplayBackend;
+
+#[derive(Copy, Clone)]
+pub struct Image<'a> {
+    width: u32,
+    height: u32,
+    pixels: &'a [u16],
+}
+
+// area of an image in image pixels
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub w: u32,
+    pub h: u32,
+}
+
+impl<'a> Image<'a> {
+    pub const fn new(width: u32, height: u32, pixels: &'a [u16]) -> Self {
+        assert!(
+            pixels.len() == width as usize * height as usize,
+            "pixel count does not match width * height"
+        );
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &'a [u16] {
+        self.pixels
+    }
+
+    pub fn bounds(&self) -> Rect {
+        Rect {
+            x: 0,
+            y: 0,
+            w: self.width,
+            h: self.height,
+        }
+    }
+
+    // `w` pixels of row `y`, starting at column `x`
+    pub fn row(&self, x: u32, y: u32, w: u32) -> &'a [u16] {
+        let start = (y * self.width + x) as usize;
+        &self.pixels[start..start + w as usize]
+    }
+}
+
+impl Rect {
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        other.x >= self.x
+            && other.y >= self.y
+            && other.x + other.w <= self.x + self.w
+            && other.y + other.h <= self.y + self.h
+    }
+}
+
+// draws the `src` part of `image` with its top left corner at (x, y).
+// full width parts go out in one draw_image call, narrower ones row by row
+// because their rows are not contiguous in memory.
+pub fn blit<B: DisplayBackend>(backend: &mut B, x: Coord, y: Coord, image: &Image, src: Rect) {
+    assert!(image.bounds().contains_rect(&src), "source rectangle outside the image");
+    if src.w == 0 || src.h == 0 {
+        return;
+    }
+
+    let x: u16 = x.try_into().expect("X co-ordinate is out of range");
+    let y: u16 = y.try_into().expect("y co-ordinate is out of range");
+    let w: u16 = src.w.try_into().expect("width out of range");
+    let h: u16 = src.h.try_into().expect("height out of range");
+
+    if src.w == image.width {
+        let start = (src.y * image.width) as usize;
+        let end = start + (src.w * src.h) as usize;
+        backend.draw_image(x, w, y, h, &image.pixels[start..end]);
+    } else {
+        for row in 0..h {
+            backend.draw_image(x, w, y + row, 1, image.row(src.x, src.y + row as u32, src.w));
+        }
+    }
+}
+
+// like blit(), with `recolor` applied to every pixel on the way to the panel.
+// pixels are copied through a one row buffer.
+pub fn blit_recolored<B: DisplayBackend>(
+    backend: &mut B,
+    x: Coord,
+    y: Coord,
+    image: &Image,
+    src: Rect,
+    recolor: Recolor,
+) {
+    if recolor.is_identity() {
+        return blit(backend, x, y, image, src);
+    }
+
+    assert!(image.bounds().contains_rect(&src), "source rectangle outside the image");
+    let x: u16 = x.try_into().expect("X co-ordinate is out of range");
+    let y: u16 = y.try_into().expect("y co-ordinate is out of range");
+    let w: u16 = src.w.try_into().expect("width out of range");
+
+    let mut buf = [0u16; LCD_WIDTH as usize];
+    let buf = &mut buf[..src.w as usize];
+    for row in 0..src.h {
+        for (dst, &px) in buf.iter_mut().zip(image.row(src.x, src.y + row, src.w)) {
+            *dst = recolor.apply(px);
+        }
+        backend.draw_image(x, w, y + row as u16, 1, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::FrameBuffer;
+
+    // 4x3 image, pixel value = 10 * row + column
+    static PIXELS: [u16; 12] = [0, 1, 2, 3, 10, 11, 12, 13, 20, 21, 22, 23];
+    static IMAGE: Image = Image::new(4, 3, &PIXELS);
+
+    #[test]
+    fn whole_image_is_one_call() {
+        let mut fb = FrameBuffer::new();
+        blit(&mut fb, 10, 20, &IMAGE, IMAGE.bounds());
+
+        assert_eq!((fb.image_calls, fb.pixel_calls, fb.rect_calls), (1, 0, 0));
+        assert_eq!(fb.at(10, 20), 0);
+        assert_eq!(fb.at(13, 22), 23);
+    }
+
+    #[test]
+    fn full_width_band_is_one_call() {
+        let mut fb = FrameBuffer::new();
+        blit(&mut fb, 0, 0, &IMAGE, Rect { x: 0, y: 1, w: 4, h: 2 });
+
+        assert_eq!(fb.image_calls, 1);
+        assert_eq!(fb.at(0, 0), 10);
+        assert_eq!(fb.at(3, 1), 23);
+    }
+
+    #[test]
+    fn sub_rectangle_goes_row_by_row() {
+        let mut fb = FrameBuffer::new();
+        blit(&mut fb, 5, 5, &IMAGE, Rect { x: 1, y: 1, w: 2, h: 2 });
+
+        assert_eq!(fb.image_calls, 2);
+        assert_eq!(fb.at(5, 5), 11);
+        assert_eq!(fb.at(6, 5), 12);
+        assert_eq!(fb.at(5, 6), 21);
+        assert_eq!(fb.at(7, 5), 0);
+    }
+
+    #[test]
+    fn recolor_replaces_one_colour() {
+        let mut fb = FrameBuffer::new();
+        let recolor = Recolor { from: 11, to: 99 };
+        blit_recolored(&mut fb, 0, 0, &IMAGE, IMAGE.bounds(), recolor);
+
+        assert_eq!(fb.image_calls, 3);
+        assert_eq!(fb.at(1, 1), 99);
+        assert_eq!(fb.at(2, 1), 12);
+    }
+
+    #[test]
+    #[should_panic(expected = "source rectangle outside the image")]
+    fn source_outside_image_panics() {
+        let mut fb = FrameBuffer::new();
+        blit(&mut fb, 0, 0, &IMAGE, Rect { x: 2, y: 0, w: 3, h: 1 });
+    }
+
+    #[test]
+    #[should_panic(expected = "pixel count does not match width * height")]
+    fn wrong_pixel_count_is_rejected() {
+        // in a static this is a build error, at run time it panics
+        let w = std::hint::black_box(5);
+        Image::new(w, 3, &PIXELS);
+    }
+}
@@ -1,21 +1,45 @@
-#![no_std]
-#![no_main]
+#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(test), no_main)]
 #![allow(dead_code)]
 
 mod assets;
+mod calibration;
 mod color;
+mod composite;
 mod config;
+mod diagnostics;
 mod display;
+mod font;
+mod gesture;
+mod graphics;
+mod image;
+mod menu;
 mod game;
 mod obstacle;
+mod orientation;
 mod player;
+#[cfg(test)]
+mod replay;
+mod response;
+mod rle;
+mod settings;
+mod sprite;
+#[cfg(test)]
+mod test_support;
 mod mpu6050;
 mod i2c_adapter;
+mod touch;
 
+use core::cell::RefCell;
 use core::pin::Pin;
 
+use composite::{Composite, Mode};
+use embedded_hal_bus::i2c::RefCellDevice;
+
 use game::{Game, InputDevice};
 use i2c_adapter::I2cAdapter;
+use settings::FlashStore;
+#[cfg(not(test))]
 use panic_halt as _;
 use stm32f3xx_hal::flash::FlashExt;
 use stm32f3xx_hal::gpio::GpioExt;
@@ -40,30 +64,29 @@ extern "C" {
 }
 
 // #[entry]
-#[no_mangle]
+#[cfg_attr(not(test), no_mangle)]
 extern "C" fn main() -> ! {
 
-    let i2c = I2cAdapter::new(i2c_init());
+    // the MPU6050 and the touch controller share I2C1
+    let i2c = RefCell::new(I2cAdapter::new(i2c_init()));
 
     c_init();
 
     display_init();
     
-    let sensor_input = mpu6050::SensorInput::new(MPU6050_DEV_ADDR, i2c);
-
+    let sensor_input = mpu6050::SensorInput::new(MPU6050_DEV_ADDR, RefCellDevice::new(&i2c));
+    let touch_input = touch::TouchInput::new(CST816S_DEV_ADDR, RefCellDevice::new(&i2c));
 
-    let mut game = 
-        game_init(sensor_input).expect("Game init failed");
+    // tilt steers, a tap on the panel chooses and flaps as well
+    let mut game = game_init(Composite::new(Mode::Merge, sensor_input, touch_input));
 
     loop {
-        if !game.is_over() {
-            unsafe {
-                //HAL_Delay(1);
-            }
-            
-            game.input_device.log_data();
-            game.update();
+        unsafe {
+            //HAL_Delay(1);
         }
+        
+        game.input_device.log_data();
+        game.update();
     }
 }
 
@@ -84,10 +107,10 @@ fn display_init() {
     display::init();
 }
 
-fn game_init<T: InputDevice>(input_device: T) -> Result<Game<T>, T::Error> {
-    let game = Game::init(input_device)?;
-    Game::<T>::draw_start_screen();
-    Ok(game)
+fn game_init<T: InputDevice>(input_device: T) -> Game<T, FlashStore> {
+    let mut game = Game::init(input_device, FlashStore);
+    game.draw_screen();
+    game
 }
 
 
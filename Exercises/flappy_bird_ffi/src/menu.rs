@@ -0,0 +1,437 @@
+// on-device menus: a title and a vertical list of entries.
+// works with any InputDevice: the game maps the input position into
+// Menu::input_range(), the position picks the entry under the cursor and
+// holding the cursor on one entry for the dwell time chooses it, once: the dwell
+// starts again only after the cursor moved to another entry or the input stopped.
+// samples with `false` (no input) stop the dwell, Menu::tap() chooses right away.
+
+use core::fmt::{self, Write};
+
+use crate::color::Theme;
+use crate::config::*;
+use crate::diagnostics::SelfTestReport;
+use crate::display;
+use crate::font::{self, Align, TextStyle};
+use crate::settings::Settings;
+
+// time the cursor has to stay on an entry to choose it
+pub const MENU_DWELL_MS: u32 = 1500;
+// pages with a single "Back" entry
+pub const PAGE_DWELL_MS: u32 = 3000;
+
+// input positions per entry
+const POSITION_STEP: Coord = 16;
+
+const TITLE_Y: Coord = 14;
+const FIRST_ROW_Y: Coord = 40;
+const ROW_HEIGHT: Coord = 21;
+const PROGRESS_HEIGHT: u32 = 3;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MenuEvent {
+    None,
+    // the cursor left entry `from`
+    Moved { from: usize },
+    Chosen(usize),
+}
+
+pub struct Menu {
+    len: usize,
+    cursor: usize,
+    dwell_ms: u32,
+    // tick when the cursor arrived at the current entry
+    since: Option<u32>,
+    // false after a choice, until the cursor moves or the input stops
+    armed: bool,
+}
+
+impl Menu {
+    pub fn new(len: usize, dwell_ms: u32) -> Self {
+        assert!(len > 0, "menu without entries");
+        Self {
+            len,
+            cursor: 0,
+            dwell_ms,
+            since: None,
+            armed: true,
+        }
+    }
+
+    //menu shown again with the cursor on `cursor`, nothing is chosen before the cursor moves
+    pub fn resume(len: usize, dwell_ms: u32, cursor: usize) -> Self {
+        Self {
+            cursor: cursor.min(len - 1),
+            armed: false,
+            ..Self::new(len, dwell_ms)
+        }
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    //(y_min, y_max) the game maps the input position to
+    pub fn input_range(&self) -> (Coord, Coord) {
+        (0, self.len as Coord * POSITION_STEP - 1)
+    }
+
+    //feeds one input sample taken at tick `now`
+    pub fn update(&mut self, position: Coord, active: bool, now: u32) -> MenuEvent {
+        if !active {
+            self.since = None;
+            self.armed = true;
+            return MenuEvent::None;
+        }
+
+        let entry = (position / POSITION_STEP).clamp(0, self.len as Coord - 1) as usize;
+        if entry != self.cursor {
+            let from = self.cursor;
+            self.cursor = entry;
+            self.since = Some(now);
+            self.armed = true;
+            return MenuEvent::Moved { from };
+        }
+        if !self.armed {
+            return MenuEvent::None;
+        }
+
+        let since = *self.since.get_or_insert(now);
+        if now.wrapping_sub(since) >= self.dwell_ms {
+            self.choose();
+            return MenuEvent::Chosen(entry);
+        }
+
+        MenuEvent::None
+    }
+
+    //a tap chooses the entry under the cursor right away
+    pub fn tap(&mut self) -> MenuEvent {
+        self.choose();
+        MenuEvent::Chosen(self.cursor)
+    }
+
+    // holding on after a choice does not choose again
+    fn choose(&mut self) {
+        self.since = None;
+        self.armed = false;
+    }
+
+    //dwell progress of the current entry in percent
+    pub fn progress(&self, now: u32) -> u32 {
+        match self.since {
+            Some(since) => (now.wrapping_sub(since).min(self.dwell_ms) * 100) / self.dwell_ms,
+            None => 0,
+        }
+    }
+}
+
+// entries of the main menu, top to bottom: `entry as usize` is its row
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MainEntry {
+    Start,
+    Difficulty,
+    InputSource,
+    Profile,
+    Theme,
+    Calibrate,
+    SelfTest,
+    HighScores,
+    Credits,
+}
+
+pub const MAIN_MENU: [MainEntry; 9] = [
+    MainEntry::Start,
+    MainEntry::Difficulty,
+    MainEntry::InputSource,
+    MainEntry::Profile,
+    MainEntry::Theme,
+    MainEntry::Calibrate,
+    MainEntry::SelfTest,
+    MainEntry::HighScores,
+    MainEntry::Credits,
+];
+
+impl MainEntry {
+    pub fn label(self) -> &'static str {
+        match self {
+            MainEntry::Start => "Start",
+            MainEntry::Difficulty => "Level",
+            MainEntry::InputSource => "Input",
+            MainEntry::Profile => "Profile",
+            MainEntry::Theme => "Theme",
+            MainEntry::Calibrate => "Calibrate",
+            MainEntry::SelfTest => "Self test",
+            MainEntry::HighScores => "High scores",
+            MainEntry::Credits => "Credits",
+        }
+    }
+
+    //current setting shown next to the label
+    pub fn value(self, settings: &Settings) -> Option<&'static str> {
+        match self {
+            MainEntry::Difficulty => Some(settings.difficulty.name()),
+            MainEntry::InputSource => Some(settings.input_source.name()),
+            MainEntry::Profile => Some(settings.profile.name()),
+            MainEntry::Theme => Some(settings.theme().name),
+            MainEntry::Calibrate => settings.calibration(settings.input_source).is_none().then_some("none"),
+            _ => None,
+        }
+    }
+}
+
+pub const CREDITS: &str = "Flappy Bird\nRust + C drivers\nover FFI\n\nSTM32F303\nGC9A01A, MPU6050";
+
+fn title_style(theme: &Theme) -> TextStyle<'static> {
+    TextStyle {
+        font: &font::FONT_16X26,
+        color: theme.highlight,
+        bgcolor: theme.background,
+    }
+}
+
+fn entry_style(theme: &Theme, selected: bool) -> TextStyle<'static> {
+    TextStyle {
+        font: &font::FONT_11X18,
+        color: theme.text,
+        bgcolor: if selected { theme.score } else { theme.background },
+    }
+}
+
+fn row_y(index: usize) -> Coord {
+    FIRST_ROW_Y + index as Coord * ROW_HEIGHT
+}
+
+//clears the screen and draws the title
+pub fn draw_title(title: &str, theme: &Theme) {
+    display::set_background_color(theme.background);
+    display::write_string_aligned(LCD_WIDTH as Coord / 2, TITLE_Y, Align::Center, title, &title_style(theme));
+}
+
+//one row of a menu, `value` is drawn after the label
+pub fn draw_entry(index: usize, label: &str, value: Option<&str>, selected: bool, theme: &Theme) {
+    let style = entry_style(theme, selected);
+    let y = row_y(index);
+
+    display::draw_rect_angle(0, LCD_WIDTH, y, ROW_HEIGHT as u32, style.bgcolor);
+
+    let sep = ": ";
+    let (label_w, _) = style.font.measure(label);
+    let (sep_w, _) = style.font.measure(sep);
+    let value_w = value.map_or(0, |v| sep_w + style.font.measure(v).0);
+    let text_y = y + (ROW_HEIGHT - style.font.height() as Coord) / 2;
+
+    // label and value are centred together
+    let x = Align::Center.start_x(LCD_WIDTH as Coord / 2, label_w + value_w);
+    display::write_string(x, text_y, label, &style);
+    if let Some(value) = value {
+        let x = x + label_w as Coord;
+        display::write_string(x, text_y, sep, &style);
+        display::write_string(x + sep_w as Coord, text_y, value, &style);
+    }
+}
+
+//bar at the bottom of the selected row, `percent` of the dwell time is over
+pub fn draw_progress(index: usize, percent: u32, theme: &Theme) {
+    let y = row_y(index) + ROW_HEIGHT - PROGRESS_HEIGHT as Coord;
+    let full = LCD_WIDTH / 2;
+    let done = full * percent.min(100) / 100;
+    let x = (LCD_WIDTH / 4) as Coord;
+
+    display::draw_rect_angle(x, done, y, PROGRESS_HEIGHT, theme.highlight);
+    display::draw_rect_angle(x + done as Coord, full - done, y, PROGRESS_HEIGHT, theme.score);
+}
+
+//centered lines of text below the title, for pages like the credits
+pub fn draw_text(text: &str, theme: &Theme) {
+    let style = entry_style(theme, false);
+    display::write_string_aligned(LCD_WIDTH as Coord / 2, FIRST_ROW_Y, Align::Center, text, &style);
+}
+
+pub fn draw_high_scores(scores: &[u32], theme: &Theme) {
+    let style = entry_style(theme, false);
+    // "1. 000" lines, centred as a block
+    let x = Align::Center.start_x(LCD_WIDTH as Coord / 2, style.font.width() * 6);
+    let mut writer = display::text_writer(x, FIRST_ROW_Y, style);
+    for (place, score) in scores.iter().enumerate() {
+        let _ = writeln!(writer, "{}. {:03}", place + 1, score);
+    }
+}
+
+//one line per axis: name, deviation from the factory trim and the verdict
+pub fn write_self_test(w: &mut impl Write, report: &SelfTestReport) -> fmt::Result {
+    for axis in report.axes() {
+        let verdict = if axis.passed { "ok" } else { "FAIL" };
+        let name = axis.name;
+        match axis.change_percent {
+            Some(change) => writeln!(w, "{} {:+4.0}% {}", name, change, verdict)?,
+            // no factory trim to compare with
+            None => writeln!(w, "{}    -- {}", name, verdict)?,
+        }
+    }
+    Ok(())
+}
+
+pub fn draw_self_test(report: &SelfTestReport, theme: &Theme) {
+    let style = entry_style(theme, false);
+    // "AX  +12% FAIL" lines, centred as a block
+    let x = Align::Center.start_x(LCD_WIDTH as Coord / 2, style.font.width() * 13);
+    let mut writer = display::text_writer(x, FIRST_ROW_Y, style);
+    let _ = write_self_test(&mut writer, report);
+}
+
+pub fn draw_main_menu(menu: &Menu, settings: &Settings, theme: &Theme) {
+    draw_title("MENU", theme);
+    for (i, entry) in MAIN_MENU.iter().enumerate() {
+        draw_entry(i, entry.label(), entry.value(settings), i == menu.cursor(), theme);
+    }
+}
+
+//page with some text and a single "Back" entry in the last row
+pub fn draw_page(title: &str, text: &str, theme: &Theme) {
+    draw_title(title, theme);
+    draw_text(text, theme);
+    draw_entry(MAIN_MENU.len() - 1, "Back", None, true, theme);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::AxisResult;
+
+    #[test]
+    fn position_selects_entry() {
+        let mut menu = Menu::new(4, 1000);
+        assert_eq!(menu.input_range(), (0, 63));
+
+        assert_eq!(menu.update(40, true, 0), MenuEvent::Moved { from: 0 });
+        assert_eq!(menu.cursor(), 2);
+        assert_eq!(menu.update(47, true, 10), MenuEvent::None);
+        // out of range positions stick to the ends
+        assert_eq!(menu.update(500, true, 20), MenuEvent::Moved { from: 2 });
+        assert_eq!(menu.cursor(), 3);
+        assert_eq!(menu.update(-3, true, 30), MenuEvent::Moved { from: 3 });
+        assert_eq!(menu.cursor(), 0);
+    }
+
+    #[test]
+    fn dwell_chooses_entry() {
+        let mut menu = Menu::new(3, 1000);
+        menu.update(20, true, 100);
+        assert_eq!(menu.update(20, true, 600), MenuEvent::None);
+        assert_eq!(menu.progress(600), 50);
+        assert_eq!(menu.update(20, true, 1100), MenuEvent::Chosen(1));
+        assert_eq!(menu.progress(1100), 0);
+    }
+
+    #[test]
+    fn holding_on_chooses_once() {
+        let mut menu = Menu::new(3, 1000);
+        let chosen = (0..50)
+            .map(|i| menu.update(20, true, i * 100))
+            .filter(|event| matches!(event, MenuEvent::Chosen(_)))
+            .count();
+        assert_eq!(chosen, 1);
+        assert_eq!(menu.progress(5000), 0);
+    }
+
+    #[test]
+    fn dwell_rearms_after_moving_or_no_input() {
+        let mut menu = Menu::new(3, 1000);
+        menu.update(20, true, 0);
+        assert_eq!(menu.update(20, true, 1000), MenuEvent::Chosen(1));
+
+        // to another entry and back
+        menu.update(40, true, 1100);
+        menu.update(20, true, 1200);
+        assert_eq!(menu.update(20, true, 2200), MenuEvent::Chosen(1));
+
+        // the input stopped for a moment
+        menu.update(20, false, 2300);
+        menu.update(20, true, 2400);
+        assert_eq!(menu.update(20, true, 3400), MenuEvent::Chosen(1));
+    }
+
+    #[test]
+    fn resumed_menu_waits_for_a_move() {
+        let mut menu = Menu::resume(3, 1000, 2);
+        assert_eq!(menu.cursor(), 2);
+        menu.update(40, true, 0);
+        assert_eq!(menu.update(40, true, 5000), MenuEvent::None);
+        assert_eq!(menu.update(20, true, 5100), MenuEvent::Moved { from: 2 });
+        assert_eq!(menu.update(20, true, 6100), MenuEvent::Chosen(1));
+    }
+
+    #[test]
+    fn moving_restarts_the_dwell() {
+        let mut menu = Menu::new(3, 1000);
+        menu.update(0, true, 0);
+        menu.update(0, true, 900);
+        menu.update(20, true, 950);
+        assert_eq!(menu.update(20, true, 1100), MenuEvent::None);
+        assert_eq!(menu.progress(1100), 15);
+    }
+
+    #[test]
+    fn no_input_stops_the_dwell() {
+        let mut menu = Menu::new(2, 1000);
+        menu.update(0, true, 0);
+        assert_eq!(menu.update(0, false, 900), MenuEvent::None);
+        assert_eq!(menu.progress(900), 0);
+        assert_eq!(menu.update(0, true, 1000), MenuEvent::None);
+        assert_eq!(menu.update(0, true, 2000), MenuEvent::Chosen(0));
+    }
+
+    #[test]
+    fn tap_chooses_and_stops_the_dwell() {
+        let mut menu = Menu::new(3, 1000);
+        menu.update(20, true, 0);
+        menu.update(20, true, 900);
+        assert_eq!(menu.tap(), MenuEvent::Chosen(1));
+        assert_eq!(menu.update(20, true, 1000), MenuEvent::None);
+        assert_eq!(menu.update(20, true, 3000), MenuEvent::None);
+        assert_eq!(menu.progress(3000), 0);
+    }
+
+    #[test]
+    fn tick_wrap_around() {
+        let mut menu = Menu::new(1, 1000);
+        menu.update(0, true, u32::MAX - 200);
+        assert_eq!(menu.update(0, true, 800), MenuEvent::Chosen(0));
+    }
+
+    #[test]
+    fn main_menu_rows() {
+        for (row, entry) in MAIN_MENU.iter().enumerate() {
+            assert_eq!(*entry as usize, row);
+        }
+    }
+
+    #[test]
+    fn main_menu_values() {
+        let settings = Settings::default();
+        assert_eq!(MainEntry::Difficulty.value(&settings), Some("Normal"));
+        assert_eq!(MainEntry::Theme.value(&settings), Some("Day"));
+        assert_eq!(MainEntry::Profile.value(&settings), Some("Classic"));
+        assert_eq!(MainEntry::Start.value(&settings), None);
+        assert_eq!(MainEntry::Calibrate.value(&settings), Some("none"));
+    }
+
+    #[test]
+    fn self_test_lines() {
+        let axis = |name, change_percent, passed| AxisResult { name, change_percent, passed };
+        let mut report = SelfTestReport::new();
+        report.push(axis("AX", Some(3.4), true));
+        report.push(axis("AY", Some(-20.0), false));
+        report.push(axis("GZ", None, false));
+        let mut text = String::new();
+        write_self_test(&mut text, &report).unwrap();
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines[0], "AX   +3% ok");
+        assert_eq!(lines[1], "AY  -20% FAIL");
+        assert_eq!(lines[2], "GZ    -- FAIL");
+    }
+}
@@ -2,24 +2,183 @@
 //here will keep code which reads the sensor data and enables us to control the birds's movement
 //1. read the raw accelerometer data
 //2. calculate the roll angle
-//3. map the roll angle to screen position( px 0 -- px 239); angle ---> pixel position on y axis
+//3. turn the roll angle into a position in the calibrated tilt range (the game maps it to the screen)
 
-use embedded_hal_mpu6050_driver::mpu6050::Mpu6050;
+use embedded_hal_mpu6050_driver::mpu6050::{Error, Mpu6050};
+use embedded_hal_mpu6050_driver::power::WakeRate;
+use embedded_hal_mpu6050_driver::range::{AccelRange, GyroRange};
+use embedded_hal_mpu6050_driver::self_test;
 use embedded_hal_1::i2c::I2c;
 
-use crate::{config::Coord, game::InputDevice};
+use crate::calibration::{self, Calibration, CalibrationOutcome, CalibrationUi, ONE_G};
+use crate::diagnostics::{AxisResult, SelfTestReport};
+use crate::gesture::{GestureConfig, GestureDetector};
+use crate::orientation::{Fusion, Orientation, DEFAULT_TIME_CONSTANT};
+use crate::response::{Response, ResponseCurve};
+use crate::game::{self, Gesture, Input, InputDevice, InputSample};
+use crate::settings::InputSource;
 use rtt_target::{rtt_init_print, rprintln};
 
 
 pub struct SensorInput<T: I2c> {
-    pub mpu6050: Mpu6050<T>
+    pub mpu6050: Mpu6050<T>,
+    source: InputSource,
+    calibration: Calibration,
+    // fused accelerometer + gyro tilt of the current source
+    orientation: Orientation,
+    response: Response,
+    gesture: GestureDetector,
+    // flicks come from the MPU6050 motion interrupt instead of the detector
+    motion_interrupt: bool,
+}
+
+// configures a SensorInput, anything not set keeps the defaults of SensorInput::new
+pub struct SensorInputBuilder<T: I2c> {
+    device_addr: u8,
+    i2c_interface: T,
+    source: InputSource,
+    calibration: Calibration,
+    fusion: Fusion,
+    time_constant: f32,
+    response: ResponseCurve,
+    gesture: GestureConfig,
+    motion_interrupt: bool,
+}
+
+impl<T: I2c> SensorInputBuilder<T> {
+    pub fn input_source(mut self, source: InputSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    pub fn calibration(mut self, calibration: Calibration) -> Self {
+        self.calibration = calibration;
+        self
+    }
+
+    //`time_constant` in seconds, longer is smoother but slower
+    pub fn fusion(mut self, fusion: Fusion, time_constant: f32) -> Self {
+        self.fusion = fusion;
+        self.time_constant = time_constant;
+        self
+    }
+
+    pub fn response(mut self, response: ResponseCurve) -> Self {
+        self.response = response;
+        self
+    }
+
+    pub fn gesture(mut self, gesture: GestureConfig) -> Self {
+        self.gesture = gesture;
+        self
+    }
+
+    //let the MPU6050 detect flicks, with the magnitude of the gesture config.
+    //init fails with Error::InvalidConfig if MOT_THR can not hold that magnitude
+    pub fn motion_interrupt(mut self, enabled: bool) -> Self {
+        self.motion_interrupt = enabled;
+        self
+    }
+
+    pub fn build(self) -> SensorInput<T> {
+        SensorInput {
+            mpu6050: Mpu6050::new(self.i2c_interface, self.device_addr),
+            source: self.source,
+            calibration: self.calibration,
+            orientation: Orientation::new(self.fusion, self.time_constant),
+            response: Response::new(self.response),
+            gesture: GestureDetector::new(self.gesture),
+            motion_interrupt: self.motion_interrupt,
+        }
+    }
 }
 
 impl<T: I2c> SensorInput<T> {
 
     pub fn new(device_addr: u8, i2c_interface: T) -> Self {
-        Self {
-           mpu6050:  Mpu6050::new(i2c_interface, device_addr)
+        Self::builder(device_addr, i2c_interface).build()
+    }
+
+    pub fn builder(device_addr: u8, i2c_interface: T) -> SensorInputBuilder<T> {
+        SensorInputBuilder {
+            device_addr,
+            i2c_interface,
+            source: InputSource::Roll,
+            calibration: Calibration::default(),
+            fusion: Fusion::Complementary,
+            time_constant: DEFAULT_TIME_CONSTANT,
+            response: ResponseCurve::LINEAR,
+            gesture: GestureConfig::default(),
+            motion_interrupt: false,
+        }
+    }
+
+    //tilt angle in degrees of an offset corrected reading
+    fn tilt_angle(source: InputSource, accel: [f32; 3]) -> f32 {
+        match source {
+            // flap play still uses the roll in the menus
+            InputSource::Roll | InputSource::Flap => get_roll_angle(accel[1], accel[2]),
+            InputSource::Pitch => get_pitch_angle(accel[0], accel[2]),
+        }
+    }
+
+    //one sample stamped with `now`, the tick the filters run on
+    pub fn sample_at(&mut self, now: u32) -> Result<InputSample, Error<T::Error>> {
+        // one burst, so accel and gyro are from the same instant
+        let reading = self.mpu6050.read_all()?;
+        let accel_data = to_one_g_counts(self.mpu6050.accel_range(), reading.accel);
+        let gyro_data = reading.gyro;
+
+        let roll_min = self.calibration.tilt_top;
+        let roll_max = self.calibration.tilt_bottom;
+
+
+        let accel = self.calibration.apply(accel_data);
+        let accel_angle = Self::tilt_angle(self.source, accel);
+        let fused_angle = self.orientation.update(
+            accel_angle,
+            Self::tilt_rate(self.source, self.mpu6050.gyro_range(), gyro_data),
+            now,
+        );
+        let roll_angle = self.response.apply(fused_angle, roll_min, roll_max, now);
+        
+        let flick = if self.motion_interrupt {
+            self.mpu6050.motion_detected()? && self.gesture.confirm(now)
+        } else {
+            self.gesture.update(accel, now)
+        };
+
+        let input = match (flick, self.source) {
+            (true, InputSource::Flap) => Input::Flap,
+            (true, _) => Input::Gesture(Gesture::Flick),
+            (false, _) => Input::Absolute(tilt_to_position(roll_angle, roll_min, roll_max)),
+        };
+
+        Ok(InputSample::new(input, now))
+    }
+
+    // wakes the sensor and sets it up for play
+    fn configure(&mut self) -> Result<(), Error<T::Error>> {
+        self.mpu6050.init()?;
+        // the ranges survive a reset of the microcontroller, one during self_test leaves +-8 g
+        self.mpu6050.set_accel_range(AccelRange::G2)?;
+        self.mpu6050.set_gyro_range(GyroRange::Dps250)?;
+        self.mpu6050.set_low_pass_filter(5)?;
+        if self.motion_interrupt {
+            // a flick is over in a few milliseconds
+            const MOTION_DURATION_MS: u8 = 2;
+            let threshold = self.gesture.config().motion_threshold().ok_or(Error::InvalidConfig)?;
+            self.mpu6050.enable_motion_interrupt(threshold, MOTION_DURATION_MS)?;
+        }
+        Ok(())
+    }
+
+    //rate of the tilt angle in degrees/s from a raw gyro reading at `range`
+    fn tilt_rate(source: InputSource, range: GyroRange, gyro: [i16; 3]) -> f32 {
+        match source {
+            InputSource::Roll | InputSource::Flap => range.to_dps(gyro[0]),
+            // positive rotation around y lowers the pitch angle
+            InputSource::Pitch => -range.to_dps(gyro[1]),
         }
     }
 
@@ -27,65 +186,255 @@ impl<T: I2c> SensorInput<T> {
 
 //trait implementation
 impl<T: I2c> InputDevice for SensorInput<T> {
-    type Error = T::Error;
+    type Error = Error<T::Error>;
     fn init(&mut self) -> Result<(), Self::Error> {
 
         #[cfg(feature  = "log")]
         rtt_init_print!();
 
-        self.mpu6050.init()?;
-        self.mpu6050.set_low_pass_filter(5)?;
-        Ok(())
+        self.configure()
     }
 
-     #[cfg(feature  = "log")]
+    //prints a recording line (see replay.rs), the RTT output of a session is a capture for the tests
+    #[cfg(feature  = "log")]
     fn log_data(&mut self) {
-        let data = 
-            self.mpu6050.read_accel_data_raw();
-        if let Ok(accel) = data {
-            rprintln!("acc: X={}, y={}, Z={}", accel[0], accel[1], accel[2])
+        if let Ok(reading) = self.mpu6050.read_all() {
+            let (a, g) = (reading.accel, reading.gyro);
+            rprintln!("{},{},{},{},{},{},{}", game::tick(), a[0], a[1], a[2], g[0], g[1], g[2]);
         }
     }
 
-    fn is_tap(&mut self, y_min: Coord, y_max: Coord) -> Result<(Coord, bool), T::Error> {
-        
-        let accel_data = self.mpu6050.read_accel_data_raw()?;
+    fn sample(&mut self) -> Result<InputSample, Self::Error> {
+        self.sample_at(game::tick())
+    }
 
-        let roll_min = -10_f32;
-        let roll_max = 90_f32;
+    fn set_input_source(&mut self, source: InputSource) {
+        self.source = source;
+        self.orientation.reset();
+        self.response.reset();
+        self.gesture.reset();
+    }
 
+    fn calibrate<U: CalibrationUi>(&mut self, ui: &mut U) -> Result<CalibrationOutcome, Self::Error> {
+        let source = self.source;
+        let range = self.mpu6050.accel_range();
+        let mpu6050 = &mut self.mpu6050;
+        let outcome = calibration::run(
+            &mut || mpu6050.read_accel_data_raw().map(|raw| to_one_g_counts(range, raw)),
+            |accel| Self::tilt_angle(source, accel),
+            ui,
+        )?;
 
-        let roll_angle = 
-            get_roll_angle(accel_data[1] as f32, accel_data[2] as f32);
-        
-        let new_y = 
-            map_roll_angle_to_y(roll_angle, roll_min, roll_max, y_min, y_max);
+        if let CalibrationOutcome::Done(calibration) = outcome {
+            self.calibration = calibration;
+        }
+        self.orientation.reset();
+        self.response.reset();
+        self.gesture.reset();
+        Ok(outcome)
+    }
+
+    fn set_calibration(&mut self, calibration: &Calibration) {
+        self.calibration = *calibration;
+        self.orientation.reset();
+        self.response.reset();
+        self.gesture.reset();
+    }
+
+    fn set_response(&mut self, response: &ResponseCurve) {
+        self.response.set_curve(*response);
+    }
+
+    fn sleep(&mut self) -> Result<bool, Self::Error> {
+        // 80 mg for one sample, picking the board up is more than that
+        const WAKE_THRESHOLD: u8 = 40;
+        const WAKE_DURATION_MS: u8 = 1;
+        self.mpu6050.enable_wake_on_motion(WAKE_THRESHOLD, WAKE_DURATION_MS, WakeRate::Hz5)?;
+        Ok(true)
+    }
 
-        Ok((new_y as Coord, true))
+    fn poll_wake(&mut self) -> Result<bool, Self::Error> {
+        if !self.mpu6050.motion_detected()? {
+            return Ok(false);
+        }
+        self.mpu6050.disable_wake_on_motion()?;
+        self.configure()?;
+        self.orientation.reset();
+        self.response.reset();
+        self.gesture.reset();
+        Ok(true)
+    }
 
+    fn self_test(&mut self) -> Result<Option<SelfTestReport>, Self::Error> {
+        let report = self.mpu6050.self_test(&mut game::TickDelay)?;
+        // the filters saw the self-test readings
+        self.orientation.reset();
+        self.response.reset();
+        self.gesture.reset();
+        Ok(Some(report.into()))
     }
 
 }
 
 
+// the driver's report for the self-test page, accelerometer axes first
+impl From<self_test::SelfTestReport> for SelfTestReport {
+    fn from(report: self_test::SelfTestReport) -> Self {
+        let names = ["AX", "AY", "AZ", "GX", "GY", "GZ"];
+        let axes = report.accel.iter().chain(&report.gyro);
+        let mut converted = SelfTestReport::new();
+        for (name, axis) in names.into_iter().zip(axes) {
+            converted.push(AxisResult {
+                name,
+                change_percent: axis.change_percent,
+                passed: axis.passed,
+            });
+        }
+        converted
+    }
+}
+
 pub fn get_roll_angle(y: f32, z: f32) -> f32 {
     libm::atan2f(y, z) * (180.0_f32 / core::f32::consts::PI)
 }
 
+//tilt around the short axis, same sign convention as the roll angle
+pub fn get_pitch_angle(x: f32, z: f32) -> f32 {
+    libm::atan2f(x, z) * (180.0_f32 / core::f32::consts::PI)
+}
+
 
-fn map_roll_angle_to_y(roll: f32, roll_min: f32, roll_max: f32, y_min: Coord, y_max: Coord) -> Coord {
-    let clamped_roll = if roll < roll_min {
-        roll_min
-    } else if roll > roll_max {
-        roll_max
+//a raw accel reading at `range` in counts of the +-2 g range (ONE_G per g), which the
+//calibration offsets and the gesture thresholds are in. saturates like the +-2 g range would
+fn to_one_g_counts(range: AccelRange, raw: [i16; 3]) -> [i16; 3] {
+    let scale = ONE_G as i32 / range.lsb_per_g();
+    raw.map(|a| (a as i32 * scale).clamp(i16::MIN as i32, i16::MAX as i32) as i16)
+}
+
+//0.0 at roll_min .. 1.0 at roll_max, roll_min > roll_max flips the direction.
+//an empty range (tilt_top == tilt_bottom) keeps the middle
+fn tilt_to_position(roll: f32, roll_min: f32, roll_max: f32) -> f32 {
+    if roll_max == roll_min {
+        return 0.5;
+    }
+    let (low, high) = if roll_min <= roll_max {
+        (roll_min, roll_max)
+    } else {
+        (roll_max, roll_min)
+    };
+    let clamped_roll = if roll < low {
+        low
+    } else if roll > high {
+        high
     } else {
         roll
     };
 
-    let normalized = (clamped_roll - roll_min) / (roll_max - roll_min);
-    let y_range = (y_max - y_min) as f32;
-    
-    // rounding
-    let mapped = y_min as f32 + normalized * y_range;
-    (mapped + 0.5) as Coord
-}
\ No newline at end of file
+    (clamped_roll - roll_min) / (roll_max - roll_min)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roll_range_maps_to_position() {
+        assert_eq!(tilt_to_position(-10.0, -10.0, 90.0), 0.0);
+        assert_eq!(tilt_to_position(40.0, -10.0, 90.0), 0.5);
+        assert_eq!(tilt_to_position(120.0, -10.0, 90.0), 1.0);
+    }
+
+    #[test]
+    fn flipped_roll_range() {
+        assert_eq!(tilt_to_position(30.0, 30.0, -10.0), 0.0);
+        assert_eq!(tilt_to_position(0.0, 30.0, -10.0), 0.75);
+        assert_eq!(tilt_to_position(-45.0, 30.0, -10.0), 1.0);
+    }
+
+    #[test]
+    fn empty_roll_range_keeps_the_middle() {
+        for roll in [-40.0, 20.0, 80.0] {
+            assert_eq!(tilt_to_position(roll, 20.0, 20.0), 0.5);
+        }
+    }
+
+    #[test]
+    fn motion_threshold_out_of_range_is_refused() {
+        use crate::config::MPU6050_DEV_ADDR;
+        use crate::replay::RecordedBus;
+
+        let gesture = GestureConfig {
+            magnitude: 0.6,
+            ..GestureConfig::default()
+        };
+        let mut input = SensorInput::builder(MPU6050_DEV_ADDR, RecordedBus::new())
+            .gesture(gesture)
+            .motion_interrupt(true)
+            .build();
+        assert!(matches!(input.init(), Err(Error::InvalidConfig)));
+    }
+
+    #[test]
+    fn sleeps_until_moved() {
+        use crate::config::MPU6050_DEV_ADDR;
+        use crate::replay::RecordedBus;
+
+        let mut input = SensorInput::new(MPU6050_DEV_ADDR, RecordedBus::new());
+        input.init().expect("init");
+        assert!(input.sleep().expect("sleep"));
+        // accelerometer only cycle mode
+        assert_eq!(input.mpu6050.i2c_mut().register(0x6B), 0x28);
+        assert!(!input.poll_wake().expect("poll"));
+
+        // motion interrupt raised
+        input.mpu6050.i2c_mut().write(MPU6050_DEV_ADDR, &[0x3A, 0x40]).expect("write");
+        assert!(input.poll_wake().expect("poll"));
+        assert_eq!(input.mpu6050.i2c_mut().register(0x6B), 0x01);
+        assert_eq!(input.mpu6050.i2c_mut().register(0x6C), 0x40);
+    }
+
+    #[test]
+    fn ranges_left_by_a_reset_are_put_back() {
+        use crate::config::MPU6050_DEV_ADDR;
+        use crate::replay::RecordedBus;
+
+        // +-8 g and +-2000 dps, as a reset during the self-test leaves them
+        let mut bus = RecordedBus::new();
+        bus.write(MPU6050_DEV_ADDR, &[0x1B, 0x18, 0x10]).expect("write");
+
+        let mut input = SensorInput::new(MPU6050_DEV_ADDR, bus);
+        input.init().expect("init");
+        assert_eq!(input.mpu6050.i2c_mut().register(0x1B), 0x00);
+        assert_eq!(input.mpu6050.i2c_mut().register(0x1C), 0x00);
+        assert_eq!(input.mpu6050.accel_range(), AccelRange::G2);
+        assert_eq!(input.mpu6050.gyro_range(), GyroRange::Dps250);
+    }
+
+    #[test]
+    fn readings_are_scaled_by_the_range() {
+        assert_eq!(to_one_g_counts(AccelRange::G2, [100, -200, ONE_G]), [100, -200, ONE_G]);
+        assert_eq!(to_one_g_counts(AccelRange::G8, [4096, -1024, 0]), [ONE_G, -4096, 0]);
+        assert_eq!(to_one_g_counts(AccelRange::G16, [i16::MAX, i16::MIN, 1]), [i16::MAX, i16::MIN, 8]);
+
+        let rate = SensorInput::<crate::replay::RecordedBus>::tilt_rate;
+        assert_eq!(rate(InputSource::Roll, GyroRange::Dps250, [131, 0, 0]), 1.0);
+        assert_eq!(rate(InputSource::Pitch, GyroRange::Dps500, [0, 655, 0]), -10.0);
+    }
+
+    #[test]
+    fn driver_self_test_report_is_converted() {
+        let ok = self_test::AxisResult { change_percent: Some(2.0), passed: true };
+        let untrimmed = self_test::AxisResult { change_percent: None, passed: false };
+        let report = SelfTestReport::from(self_test::SelfTestReport {
+            accel: [ok; 3],
+            gyro: [ok, ok, untrimmed],
+        });
+
+        let names: Vec<_> = report.axes().iter().map(|axis| axis.name).collect();
+        assert_eq!(names, ["AX", "AY", "AZ", "GX", "GY", "GZ"]);
+        assert_eq!(report.axes()[0].change_percent, Some(2.0));
+        assert_eq!(report.axes()[5], AxisResult { name: "GZ", change_percent: None, passed: false });
+        assert!(!report.passed());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::color;
+use crate::color::Theme;
 use crate::config::*;
 use crate::display;
 
@@ -14,70 +14,70 @@ pub struct Obstacle {
 }
 
 impl Obstacle {
-    pub fn init() -> Self {
+    pub fn init(speed: u32) -> Self {
         Obstacle {
             x_top: 240,
             y_top: 0 + SCORE_BOARD_HEIGHT as Coord,
             x_btm: 240,
             y_btm: 180,
-            speed: SPEED,
+            speed,
             height_top: 100,
             height_btm: 60 - PLANTS_HEIGHT,
             already_scored:  false,
         }
     }
 
-    fn draw(&self) {
-        self.draw_top();
-        self.draw_bottom();
+    fn draw(&self, theme: &Theme) {
+        self.draw_top(theme);
+        self.draw_bottom(theme);
     }
 
-    fn clear_top(&self, x: Coord, width: u32) {
-        display::draw_rect_angle(x, width, self.y_top, self.height_top, color::BACKGROUND);
+    fn clear_top(&self, x: Coord, width: u32, theme: &Theme) {
+        display::draw_rect_angle(x, width, self.y_top, self.height_top, theme.background);
     }
 
-    fn clear_bottom(&self, x: Coord, width: u32) {
-        display::draw_rect_angle(x, width, self.y_btm, self.height_btm, color::BACKGROUND);
+    fn clear_bottom(&self, x: Coord, width: u32, theme: &Theme) {
+        display::draw_rect_angle(x, width, self.y_btm, self.height_btm, theme.background);
     }
 
-    fn clear(&self) {
-        self.clear_top(self.x_top + OBSTACLE_WIDTH as Coord, self.speed);
-        self.clear_bottom(self.x_btm + OBSTACLE_WIDTH as Coord, self.speed);
+    fn clear(&self, theme: &Theme) {
+        self.clear_top(self.x_top + OBSTACLE_WIDTH as Coord, self.speed, theme);
+        self.clear_bottom(self.x_btm + OBSTACLE_WIDTH as Coord, self.speed, theme);
 
         if self.x_top <= LCD_BIGIN {
-            self.clear_top(LCD_BIGIN, OBSTACLE_WIDTH);
+            self.clear_top(LCD_BIGIN, OBSTACLE_WIDTH, theme);
         }
 
         if self.x_btm <= LCD_BIGIN {
-            self.clear_bottom(LCD_BIGIN, OBSTACLE_WIDTH);
+            self.clear_bottom(LCD_BIGIN, OBSTACLE_WIDTH, theme);
         }
     }
 
-    fn draw_top(&self) {
+    fn draw_top(&self, theme: &Theme) {
         display::draw_rect_angle(
             self.x_top,
             OBSTACLE_WIDTH,
             self.y_top,
             self.height_top,
-            color::BLACK,
+            theme.pipes,
         );
     }
 
-    fn draw_bottom(&self) {
+    fn draw_bottom(&self, theme: &Theme) {
         display::draw_rect_angle(
             self.x_btm,
             OBSTACLE_WIDTH,
             self.y_btm,
             self.height_btm,
-            color::BLACK,
+            theme.pipes,
         );
     }
 
-    pub fn move_obstacle(&mut self) {
+    pub fn move_obstacle(&mut self, theme: &Theme) {
         self.x_top -= self.speed as Coord;
         self.x_btm -= self.speed as Coord;
-        self.draw();
-        self.clear();
+        self.draw(theme);
+        self.clear(theme);
 
         if self.x_top <= LCD_BIGIN {
             self.x_top = LCD_END;
@@ -0,0 +1,346 @@
+// tilt angle estimate from accelerometer and gyro.
+// the accelerometer angle is right on average but picks up every hand tremor
+// and bump, the integrated gyro rate is smooth but drifts. both filters fuse
+// the two; `time_constant` (seconds) is roughly how long the gyro alone is
+// trusted: shorter follows the accelerometer faster, longer is smoother.
+
+// gyro reading of 1 degree/s at the default +-250 dps full scale
+pub const GYRO_LSB_PER_DPS: f32 = 131.0;
+
+pub const DEFAULT_TIME_CONSTANT: f32 = 0.3;
+
+// after a longer pause between samples (menu, calibration) the estimate
+// starts again from the accelerometer
+const MAX_DT: f32 = 0.5;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Fusion {
+    Complementary,
+    // also estimates the gyro bias, so it does not lag behind on a biased gyro
+    Kalman,
+}
+
+pub struct ComplementaryFilter {
+    time_constant: f32,
+    angle: Option<f32>,
+}
+
+impl ComplementaryFilter {
+    pub fn new(time_constant: f32) -> Self {
+        Self {
+            time_constant,
+            angle: None,
+        }
+    }
+
+    //`accel_angle` in degrees, `rate` in degrees/s, `dt` in seconds
+    pub fn update(&mut self, accel_angle: f32, rate: f32, dt: f32) -> f32 {
+        let angle = match self.angle {
+            Some(angle) => {
+                let alpha = self.time_constant / (self.time_constant + dt);
+                alpha * (angle + rate * dt) + (1.0 - alpha) * accel_angle
+            }
+            None => accel_angle,
+        };
+        self.angle = Some(angle);
+        angle
+    }
+
+    pub fn reset(&mut self) {
+        self.angle = None;
+    }
+}
+
+// two state (angle, gyro bias) Kalman filter for one axis
+pub struct KalmanFilter {
+    time_constant: f32,
+    // process noise of the angle and of the bias, per second
+    q_angle: f32,
+    q_bias: f32,
+    angle: Option<f32>,
+    bias: f32,
+    p: [[f32; 2]; 2],
+}
+
+impl KalmanFilter {
+    pub fn new(time_constant: f32) -> Self {
+        Self {
+            time_constant,
+            q_angle: 0.001,
+            q_bias: 0.003,
+            angle: None,
+            bias: 0.0,
+            p: [[0.0; 2]; 2],
+        }
+    }
+
+    pub fn bias(&self) -> f32 {
+        self.bias
+    }
+
+    //`accel_angle` in degrees, `rate` in degrees/s, `dt` in seconds (> 0)
+    pub fn update(&mut self, accel_angle: f32, rate: f32, dt: f32) -> f32 {
+        let Some(mut angle) = self.angle else {
+            self.angle = Some(accel_angle);
+            return accel_angle;
+        };
+
+        // predict
+        angle += dt * (rate - self.bias);
+        let p = &mut self.p;
+        p[0][0] += dt * (dt * p[1][1] - p[0][1] - p[1][0] + self.q_angle);
+        p[0][1] -= dt * p[1][1];
+        p[1][0] -= dt * p[1][1];
+        p[1][1] += self.q_bias * dt;
+
+        // measurement noise chosen so that the steady state gain is about
+        // dt / time_constant, like the complementary filter
+        let r = self.q_angle * self.time_constant * self.time_constant / dt;
+
+        // correct
+        let s = p[0][0] + r;
+        let k = [p[0][0] / s, p[1][0] / s];
+        let y = accel_angle - angle;
+        angle += k[0] * y;
+        self.bias += k[1] * y;
+
+        let (p00, p01) = (p[0][0], p[0][1]);
+        p[0][0] -= k[0] * p00;
+        p[0][1] -= k[0] * p01;
+        p[1][0] -= k[1] * p00;
+        p[1][1] -= k[1] * p01;
+
+        self.angle = Some(angle);
+        angle
+    }
+
+    pub fn reset(&mut self) {
+        self.angle = None;
+        self.p = [[0.0; 2]; 2];
+    }
+}
+
+enum Filter {
+    Complementary(ComplementaryFilter),
+    Kalman(KalmanFilter),
+}
+
+// one axis estimate fed with timestamped samples
+pub struct Orientation {
+    filter: Filter,
+    last_ms: Option<u32>,
+    angle: f32,
+}
+
+impl Orientation {
+    pub fn new(fusion: Fusion, time_constant: f32) -> Self {
+        let filter = match fusion {
+            Fusion::Complementary => Filter::Complementary(ComplementaryFilter::new(time_constant)),
+            Fusion::Kalman => Filter::Kalman(KalmanFilter::new(time_constant)),
+        };
+        Self {
+            filter,
+            last_ms: None,
+            angle: 0.0,
+        }
+    }
+
+    //`accel_angle` in degrees, `rate` in degrees/s, `now_ms` a wrapping millisecond tick
+    pub fn update(&mut self, accel_angle: f32, rate: f32, now_ms: u32) -> f32 {
+        let dt = match self.last_ms {
+            Some(last) => now_ms.wrapping_sub(last) as f32 / 1000.0,
+            None => 0.0,
+        };
+
+        if dt > MAX_DT {
+            self.reset();
+        } else if self.last_ms.is_some() && dt <= 0.0 {
+            // same tick again, nothing to integrate
+            return self.angle;
+        }
+        self.last_ms = Some(now_ms);
+
+        self.angle = match &mut self.filter {
+            Filter::Complementary(filter) => filter.update(accel_angle, rate, dt),
+            Filter::Kalman(filter) => filter.update(accel_angle, rate, dt),
+        };
+        self.angle
+    }
+
+    pub fn angle(&self) -> f32 {
+        self.angle
+    }
+
+    pub fn reset(&mut self) {
+        self.last_ms = None;
+        match &mut self.filter {
+            Filter::Complementary(filter) => filter.reset(),
+            Filter::Kalman(filter) => filter.reset(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mpu6050::get_roll_angle;
+    use crate::test_support::{board_capture, jitter};
+
+    struct Sample {
+        t_ms: u32,
+        accel: [f32; 3],
+        gyro: [f32; 3],
+        roll: f32,
+    }
+
+    fn trace() -> Vec<Sample> {
+        parse(include_str!("../testdata/tilt_trace_synthetic.csv"))
+    }
+
+    // board traces have no true roll column, it reads as 0
+    fn parse(csv: &str) -> Vec<Sample> {
+        csv.lines()
+            .filter(|line| !line.starts_with('#') && !line.starts_with("t_ms"))
+            .map(|line| {
+                let v: Vec<f32> = line.split(',').map(|f| f.parse().unwrap()).collect();
+                Sample {
+                    t_ms: v[0] as u32,
+                    accel: [v[1], v[2], v[3]],
+                    gyro: [v[4], v[5], v[6]],
+                    roll: v.get(7).copied().unwrap_or(0.0),
+                }
+            })
+            .collect()
+    }
+
+    // estimates for every sample of the trace
+    fn run(trace: &[Sample], mut estimate: impl FnMut(&Sample) -> f32) -> Vec<f32> {
+        trace.iter().map(&mut estimate).collect()
+    }
+
+    fn fused(trace: &[Sample], fusion: Fusion, time_constant: f32) -> Vec<f32> {
+        let mut orientation = Orientation::new(fusion, time_constant);
+        run(trace, |s| {
+            let accel_angle = get_roll_angle(s.accel[1], s.accel[2]);
+            orientation.update(accel_angle, s.gyro[0] / GYRO_LSB_PER_DPS, s.t_ms)
+        })
+    }
+
+    fn accel_only(trace: &[Sample]) -> Vec<f32> {
+        run(trace, |s| get_roll_angle(s.accel[1], s.accel[2]))
+    }
+
+    fn rms_error(trace: &[Sample], angles: &[f32]) -> f32 {
+        let sum: f32 = trace.iter().zip(angles).map(|(s, a)| (a - s.roll).powi(2)).sum();
+        (sum / angles.len() as f32).sqrt()
+    }
+
+    // estimate minus the true angle, its jitter is the movement which is not in the true angle
+    fn error(trace: &[Sample], angles: &[f32]) -> Vec<f32> {
+        trace.iter().zip(angles).map(|(s, a)| a - s.roll).collect()
+    }
+
+    // mean error while the board is held at 30 degrees
+    fn hold_error(trace: &[Sample], angles: &[f32]) -> f32 {
+        let hold: Vec<f32> = trace
+            .iter()
+            .zip(angles)
+            .filter(|(s, _)| s.t_ms >= 4500)
+            .map(|(s, a)| a - s.roll)
+            .collect();
+        hold.iter().sum::<f32>() / hold.len() as f32
+    }
+
+    #[test]
+    fn fusion_beats_the_accelerometer() {
+        let trace = trace();
+        let raw = accel_only(&trace);
+
+        for fusion in [Fusion::Complementary, Fusion::Kalman] {
+            let angles = fused(&trace, fusion, DEFAULT_TIME_CONSTANT);
+            assert!(
+                jitter(&error(&trace, &angles)) < jitter(&error(&trace, &raw)) / 4.0,
+                "{:?}: jitter {} vs {}",
+                fusion,
+                jitter(&error(&trace, &angles)),
+                jitter(&error(&trace, &raw))
+            );
+            assert!(
+                rms_error(&trace, &angles) < rms_error(&trace, &raw) / 2.0,
+                "{:?}: error {} vs {}",
+                fusion,
+                rms_error(&trace, &angles),
+                rms_error(&trace, &raw)
+            );
+        }
+    }
+
+    #[test]
+    fn kalman_removes_the_gyro_bias() {
+        let trace = trace();
+
+        let complementary = fused(&trace, Fusion::Complementary, 1.0);
+        let kalman = fused(&trace, Fusion::Kalman, 1.0);
+
+        // the trace has a 2 dps bias, the complementary filter lags by about bias * time constant
+        assert!(hold_error(&trace, &complementary).abs() > 1.0);
+        assert!(hold_error(&trace, &kalman).abs() < 0.5, "{}", hold_error(&trace, &kalman));
+    }
+
+    #[test]
+    fn longer_time_constant_is_smoother() {
+        let trace = trace();
+        for fusion in [Fusion::Complementary, Fusion::Kalman] {
+            let fast = fused(&trace, fusion, 0.1);
+            let slow = fused(&trace, fusion, 0.5);
+            assert!(jitter(&error(&trace, &slow)) < jitter(&error(&trace, &fast)), "{:?}", fusion);
+        }
+    }
+
+    #[test]
+    fn starts_from_the_accelerometer() {
+        let mut orientation = Orientation::new(Fusion::Kalman, 0.3);
+        assert_eq!(orientation.update(25.0, 100.0, 1000), 25.0);
+
+        // a pause restarts the estimate instead of integrating over it
+        orientation.update(25.0, 0.0, 1010);
+        assert_eq!(orientation.update(-40.0, 0.0, 5000), -40.0);
+
+        // a sample with the same tick changes nothing
+        assert_eq!(orientation.update(10.0, 0.0, 5000), -40.0);
+    }
+
+    #[test]
+    fn complementary_follows_a_gyro_turn() {
+        let mut filter = ComplementaryFilter::new(0.5);
+        filter.update(0.0, 0.0, 0.01);
+        // the gyro reports the turn before the accelerometer agrees
+        let angle = filter.update(0.0, 100.0, 0.01);
+        assert!(angle > 0.9 && angle < 1.0, "{}", angle);
+    }
+
+    #[test]
+    #[ignore = "needs testdata/tilt_trace_board.csv recorded on the board"]
+    fn fusion_smooths_the_board_trace() {
+        // still, rolled to about 45 degrees and back, still again
+        let trace = parse(&board_capture("tilt_trace_board.csv"));
+        let raw = accel_only(&trace);
+        let mean = |angles: &[f32]| angles.iter().sum::<f32>() / angles.len() as f32;
+        // the last second, when the board is still again
+        let still = trace.len() - 100;
+
+        for fusion in [Fusion::Complementary, Fusion::Kalman] {
+            let angles = fused(&trace, fusion, DEFAULT_TIME_CONSTANT);
+            // without a true roll, jitter is the plain sample to sample movement
+            assert!(
+                jitter(&error(&trace, &angles)) < jitter(&error(&trace, &raw)) / 2.0,
+                "{:?}: jitter {} vs {}",
+                fusion,
+                jitter(&error(&trace, &angles)),
+                jitter(&error(&trace, &raw))
+            );
+            let offset = mean(&angles[still..]) - mean(&raw[still..]);
+            assert!(offset.abs() < 2.0, "{:?}: {} degrees off once still", fusion, offset);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::assets;
-use crate::color;
+use crate::color::Theme;
 use crate::{config::*, display};
 
 pub struct Player {
@@ -7,6 +7,8 @@ pub struct Player {
     y: Coord,
     w: u32,
     h: u32,
+    // pixels per frame in flap play, positive is down
+    velocity: Coord,
 }
 
 impl Player {
@@ -16,10 +18,26 @@ impl Player {
             y: INIT_PLAYER_POS_Y,
             w: PLAYER_WIDTH,
             h: PLAYER_HEIGHT,
+            velocity: 0,
         }
     }
 
-    pub fn move_player(&mut self, new_y: Coord) {
+    //one frame of flap play: `flap` throws the bird up, otherwise it falls faster
+    pub fn fall(&mut self, flap: bool, theme: &Theme) {
+        self.velocity = if flap {
+            FLAP_VELOCITY
+        } else {
+            (self.velocity + FALL_ACCEL).min(MAX_FALL_SPEED)
+        };
+
+        let new_y = (self.y + self.velocity).clamp(PLAYER_Y_MIN, PLAYER_Y_MAX);
+        if new_y != self.y + self.velocity {
+            self.velocity = 0;
+        }
+        self.move_player(new_y, theme);
+    }
+
+    pub fn move_player(&mut self, new_y: Coord, theme: &Theme) {
         let old_y = self.y;
 
         if old_y == new_y {
@@ -28,15 +46,15 @@ impl Player {
             self.y = new_y;
         }
         
-        self.draw();
-        self.clear(old_y);
+        self.draw(theme);
+        self.clear(old_y, theme);
     }
 
-    fn draw(&self) {
-        display::draw_image(self.x, self.w, self.y, self.h, &assets::BIRD_IMG_DATA);
+    fn draw(&self, theme: &Theme) {
+        display::draw_image_recolored(self.x, self.y, &assets::BIRD_IMG, theme.asset_recolor());
     }
 
-    fn clear(&self, old_y: Coord) {
+    fn clear(&self, old_y: Coord, theme: &Theme) {
         let change_of_y = self.y - old_y;
         let clear_y = if change_of_y.is_negative() {
             self.y + PLAYER_HEIGHT as Coord
@@ -49,7 +67,7 @@ impl Player {
             PLAYER_WIDTH,
             clear_y,
             change_of_y.unsigned_abs(),
-            color::BACKGROUND,
+            theme.background,
         );
     }
 
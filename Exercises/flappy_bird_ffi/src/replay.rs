@@ -0,0 +1,446 @@
+// deterministic input devices, to drive the game and tune the input on the host.
+//   ScriptedInput -> replays a list of InputSamples
+//   RecordedInput -> replays a captured MPU6050 session through the real SensorInput,
+//                    so filters, response curves and gestures run as on the board
+//
+// recordings are CSV, one reading per line, '#' lines and the header are skipped:
+//   t_ms,ax,ay,az[,gx,gy,gz[,..]]
+// raw register values as read_accel_data_raw / read_gyro_raw return them,
+// missing gyro columns read as 0, further columns are ignored.
+// with the log feature the board prints these lines over RTT, see SensorInput::log_data.
+
+use core::convert::Infallible;
+
+use embedded_hal_1::i2c::{ErrorType, I2c, Operation};
+use embedded_hal_mpu6050_driver::mpu6050::WHO_AM_I_VALUE;
+use embedded_hal_mpu6050_driver::reg::{
+    MPU6050_REG_ACCEL_XOUT_H, MPU6050_REG_GYRO_XOUT_H, MPU6050_REG_INT_ENABLE, MPU6050_REG_INT_STATUS,
+    MPU6050_REG_MOT_THR, MPU6050_REG_WHO_AM_I,
+};
+
+use crate::calibration::Calibration;
+use crate::config::MPU6050_DEV_ADDR;
+use crate::game::{Input, InputDevice, InputSample};
+use crate::mpu6050::SensorInput;
+use crate::response::ResponseCurve;
+use crate::settings::InputSource;
+
+// MOT_INT in INT_ENABLE and INT_STATUS
+const MOT_INT: u8 = 0x40;
+
+// one line of a recording: t_ms, accel, gyro
+type Reading = (u32, [i16; 3], [i16; 3]);
+
+pub struct ScriptedInput<'a> {
+    samples: &'a [InputSample],
+    next: usize,
+}
+
+impl<'a> ScriptedInput<'a> {
+    pub fn new(samples: &'a [InputSample]) -> Self {
+        Self { samples, next: 0 }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.samples.len()
+    }
+
+    pub fn rewind(&mut self) {
+        self.next = 0;
+    }
+}
+
+impl InputDevice for ScriptedInput<'_> {
+    type Error = Infallible;
+
+    fn init(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    //the next sample, Input::None (at the time of the last one) after the end
+    fn sample(&mut self) -> Result<InputSample, Self::Error> {
+        let Some(sample) = self.samples.get(self.next) else {
+            let time_ms = self.samples.last().map_or(0, |s| s.time_ms);
+            return Ok(InputSample::new(Input::None, time_ms));
+        };
+        self.next += 1;
+        Ok(*sample)
+    }
+
+    //sleeps like a sensor with wake-on-motion
+    fn sleep(&mut self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+
+    //takes the next sample, anything but Input::None wakes the device
+    fn poll_wake(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.sample()?.input != Input::None)
+    }
+}
+
+// I2C bus with the register file of an MPU6050, RecordedInput puts each reading into it.
+// with the motion interrupt enabled a reading raises MOT_INT like the sensor would,
+// reading INT_STATUS clears it
+pub struct RecordedBus {
+    regs: [u8; 128],
+    pointer: usize,
+    // acceleration of the last reading, for the motion interrupt
+    last_accel: Option<[i16; 3]>,
+}
+
+impl RecordedBus {
+    pub fn new() -> Self {
+        let mut regs = [0; 128];
+        regs[MPU6050_REG_WHO_AM_I as usize] = WHO_AM_I_VALUE;
+        Self {
+            regs,
+            pointer: 0,
+            last_accel: None,
+        }
+    }
+
+    //register `reg`, as last written by the driver or a reading
+    pub fn register(&self, reg: u8) -> u8 {
+        self.regs[reg as usize % self.regs.len()]
+    }
+
+    fn load(&mut self, accel: [i16; 3], gyro: [i16; 3]) {
+        if self.motion(accel) {
+            self.regs[MPU6050_REG_INT_STATUS as usize] |= MOT_INT;
+        }
+        self.last_accel = Some(accel);
+        for (i, value) in accel.iter().enumerate() {
+            self.regs[MPU6050_REG_ACCEL_XOUT_H as usize + 2 * i..][..2].copy_from_slice(&value.to_be_bytes());
+        }
+        for (i, value) in gyro.iter().enumerate() {
+            self.regs[MPU6050_REG_GYRO_XOUT_H as usize + 2 * i..][..2].copy_from_slice(&value.to_be_bytes());
+        }
+    }
+}
+
+impl RecordedBus {
+    // the sensor compares the high passed acceleration of each axis with MOT_THR,
+    // the change from the last reading stands in for the high pass filter here
+    fn motion(&self, accel: [i16; 3]) -> bool {
+        let threshold = self.register(MPU6050_REG_MOT_THR) as i32;
+        if self.register(MPU6050_REG_INT_ENABLE) & MOT_INT == 0 || threshold == 0 {
+            return false;
+        }
+        let Some(last) = self.last_accel else {
+            return false;
+        };
+        // 2 mg per count, 16384 counts per g at +-2 g
+        (0..3).any(|i| (accel[i] as i32 - last[i] as i32).abs() * 500 > threshold * 16384)
+    }
+}
+
+impl Default for RecordedBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ErrorType for RecordedBus {
+    type Error = Infallible;
+}
+
+impl I2c for RecordedBus {
+    // a write sets the register pointer, more bytes go into the registers from there,
+    // reads continue at the pointer
+    fn transaction(&mut self, _address: u8, operations: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+        let len = self.regs.len();
+        for operation in operations {
+            match operation {
+                Operation::Write(bytes) => {
+                    if let Some((&reg, data)) = bytes.split_first() {
+                        self.pointer = reg as usize % len;
+                        for &byte in data {
+                            self.regs[self.pointer] = byte;
+                            self.pointer = (self.pointer + 1) % len;
+                        }
+                    }
+                }
+                Operation::Read(buf) => {
+                    for byte in buf.iter_mut() {
+                        *byte = self.regs[self.pointer];
+                        if self.pointer == MPU6050_REG_INT_STATUS as usize {
+                            self.regs[self.pointer] = 0;
+                        }
+                        self.pointer = (self.pointer + 1) % len;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// line of the recording (counted from 1) which could not be read
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct BadLine(pub usize);
+
+pub struct RecordedInput<'a> {
+    sensor: SensorInput<RecordedBus>,
+    lines: core::iter::Enumerate<core::str::Lines<'a>>,
+    last_ms: u32,
+    finished: bool,
+}
+
+impl<'a> RecordedInput<'a> {
+    //replays `csv` through a SensorInput with default settings
+    pub fn new(csv: &'a str) -> Self {
+        Self::with_sensor(csv, SensorInput::new(MPU6050_DEV_ADDR, RecordedBus::new()))
+    }
+
+    //replays `csv` through `sensor`, e.g. one made with SensorInput::builder
+    pub fn with_sensor(csv: &'a str, sensor: SensorInput<RecordedBus>) -> Self {
+        Self {
+            sensor,
+            lines: csv.lines().enumerate(),
+            last_ms: 0,
+            finished: false,
+        }
+    }
+
+    pub fn sensor(&mut self) -> &mut SensorInput<RecordedBus> {
+        &mut self.sensor
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn next_reading(&mut self) -> Result<Option<Reading>, BadLine> {
+        for (index, line) in self.lines.by_ref() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("t_ms") {
+                continue;
+            }
+
+            let bad = BadLine(index + 1);
+            let mut fields = line.split(',').map(str::trim);
+            let t_ms = fields.next().and_then(|f| f.parse().ok()).ok_or(bad)?;
+            let mut values = [0i16; 6];
+            for (i, value) in values.iter_mut().enumerate() {
+                match fields.next() {
+                    Some(field) => *value = field.parse().map_err(|_| bad)?,
+                    // the accelerometer is required, the gyro is not
+                    None if i < 3 => return Err(bad),
+                    None => break,
+                }
+            }
+
+            return Ok(Some((
+                t_ms,
+                [values[0], values[1], values[2]],
+                [values[3], values[4], values[5]],
+            )));
+        }
+        Ok(None)
+    }
+}
+
+impl InputDevice for RecordedInput<'_> {
+    type Error = BadLine;
+
+    fn init(&mut self) -> Result<(), Self::Error> {
+        // the bus never fails and answers WHO_AM_I like the sensor
+        self.sensor.init().expect("recorded bus looks like an MPU6050");
+        Ok(())
+    }
+
+    //the next reading through the sensor, Input::None after the end
+    fn sample(&mut self) -> Result<InputSample, Self::Error> {
+        let Some((t_ms, accel, gyro)) = self.next_reading()? else {
+            self.finished = true;
+            return Ok(InputSample::new(Input::None, self.last_ms));
+        };
+
+        self.last_ms = t_ms;
+        self.sensor.mpu6050.i2c_mut().load(accel, gyro);
+        Ok(self.sensor.sample_at(t_ms).expect("recorded bus never fails"))
+    }
+
+    fn set_input_source(&mut self, source: InputSource) {
+        self.sensor.set_input_source(source);
+    }
+
+    fn set_calibration(&mut self, calibration: &Calibration) {
+        self.sensor.set_calibration(calibration);
+    }
+
+    fn set_response(&mut self, response: &ResponseCurve) {
+        self.sensor.set_response(response);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Gesture;
+    use crate::settings::Profile;
+    use crate::test_support::{board_capture, jitter};
+
+    const TILT_TRACE: &str = include_str!("../testdata/tilt_trace_synthetic.csv");
+    const FLICK_SESSION: &str = include_str!("../testdata/flick_session_synthetic.csv");
+
+    fn replay(input: &mut RecordedInput) -> Vec<InputSample> {
+        input.init().unwrap();
+        let mut samples = Vec::new();
+        loop {
+            let sample = input.sample().unwrap();
+            if input.is_finished() {
+                return samples;
+            }
+            samples.push(sample);
+        }
+    }
+
+    fn positions(samples: &[InputSample]) -> Vec<f32> {
+        samples
+            .iter()
+            .filter_map(|s| match s.input {
+                Input::Absolute(p) => Some(p),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // true roll of the tilt trace, as a position in the default -10..90 degree range
+    fn trace_truth() -> Vec<f32> {
+        TILT_TRACE
+            .lines()
+            .filter(|line| !line.starts_with('#') && !line.starts_with("t_ms"))
+            .map(|line| {
+                let roll: f32 = line.rsplit(',').next().unwrap().parse().unwrap();
+                ((roll + 10.0) / 100.0).clamp(0.0, 1.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn scripted_samples_in_order() {
+        let script = [
+            InputSample::new(Input::Absolute(0.2), 0),
+            InputSample::new(Input::Flap, 16),
+            InputSample::new(Input::Relative(0.1), 32),
+        ];
+        let mut input = ScriptedInput::new(&script);
+
+        for expected in script {
+            assert_eq!(input.sample(), Ok(expected));
+        }
+        assert!(input.is_finished());
+        assert_eq!(input.sample(), Ok(InputSample::new(Input::None, 32)));
+
+        input.rewind();
+        assert_eq!(input.sample(), Ok(script[0]));
+    }
+
+    #[test]
+    fn recording_goes_through_the_sensor() {
+        let mut input = RecordedInput::new(TILT_TRACE);
+        let samples = replay(&mut input);
+        let truth = trace_truth();
+
+        assert_eq!(samples.len(), truth.len());
+        assert_eq!(samples[1].time_ms, 10);
+        // the driver's init reached the register file
+        // awake on the gyro clock
+        assert_eq!(input.sensor().mpu6050.i2c_mut().register(0x6B), 0x01);
+        assert_eq!(input.sensor().mpu6050.i2c_mut().register(0x1A), 5);
+
+        let got = positions(&samples);
+        assert_eq!(got.len(), truth.len(), "no gestures in the tilt trace");
+        let sum: f32 = got.iter().zip(&truth).map(|(g, t)| (g - t).powi(2)).sum();
+        let rms = (sum / got.len() as f32).sqrt();
+        assert!(rms < 0.03, "rms {}", rms);
+    }
+
+    #[test]
+    fn smooth_profile_is_smoother_on_the_trace() {
+        let classic = positions(&replay(&mut RecordedInput::new(TILT_TRACE)));
+
+        let mut input = RecordedInput::new(TILT_TRACE);
+        input.set_response(&Profile::Smooth.response());
+        let smooth = positions(&replay(&mut input));
+
+        assert!(
+            jitter(&smooth) < jitter(&classic),
+            "{} vs {}",
+            jitter(&smooth),
+            jitter(&classic)
+        );
+    }
+
+    #[test]
+    fn recorded_flicks() {
+        let samples = replay(&mut RecordedInput::new(FLICK_SESSION));
+        let flicks: Vec<u32> = samples
+            .iter()
+            .filter(|s| s.input == Input::Gesture(Gesture::Flick))
+            .map(|s| s.time_ms)
+            .collect();
+        assert_eq!(flicks, [1000, 3200]);
+
+        // at the end the board is held at 10 degrees
+        let end = *positions(&samples).last().unwrap();
+        assert!((end - 0.2).abs() < 0.02, "{}", end);
+    }
+
+    #[test]
+    fn flicks_flap_in_flap_play() {
+        let sensor = SensorInput::builder(MPU6050_DEV_ADDR, RecordedBus::new())
+            .input_source(InputSource::Flap)
+            .build();
+        let samples = replay(&mut RecordedInput::with_sensor(FLICK_SESSION, sensor));
+        assert_eq!(samples.iter().filter(|s| s.input == Input::Flap).count(), 2);
+    }
+
+    fn flick_times(samples: &[InputSample]) -> Vec<u32> {
+        samples.iter().filter(|s| s.is_tap()).map(|s| s.time_ms).collect()
+    }
+
+    #[test]
+    fn motion_interrupt_sees_the_same_flicks() {
+        for csv in [FLICK_SESSION, TILT_TRACE] {
+            let detector = flick_times(&replay(&mut RecordedInput::new(csv)));
+            let sensor = SensorInput::builder(MPU6050_DEV_ADDR, RecordedBus::new())
+                .motion_interrupt(true)
+                .build();
+            let interrupt = flick_times(&replay(&mut RecordedInput::with_sensor(csv, sensor)));
+            assert_eq!(detector, interrupt);
+        }
+    }
+
+    #[test]
+    #[ignore = "needs testdata/flick_session_board.csv recorded on the board"]
+    fn board_session_flicks() {
+        // still, three flicks about 2 s apart, still again
+        let session = board_capture("flick_session_board.csv");
+        let detector = flick_times(&replay(&mut RecordedInput::new(&session)));
+        assert_eq!(detector.len(), 3, "{:?}", detector);
+        assert!(detector.windows(2).all(|w| w[1] - w[0] > 1000), "{:?}", detector);
+
+        // the interrupt sees each flick within a few samples of the detector
+        let sensor = SensorInput::builder(MPU6050_DEV_ADDR, RecordedBus::new())
+            .motion_interrupt(true)
+            .build();
+        let interrupt = flick_times(&replay(&mut RecordedInput::with_sensor(&session, sensor)));
+        assert_eq!(interrupt.len(), 3, "{:?}", interrupt);
+        for (d, i) in detector.iter().zip(&interrupt) {
+            assert!(d.abs_diff(*i) <= 50, "{:?} vs {:?}", detector, interrupt);
+        }
+    }
+
+    #[test]
+    fn short_and_broken_lines() {
+        let csv = "t_ms,ax,ay,az\n0,0,0,16384\n10,0,0\n";
+        let mut input = RecordedInput::new(csv);
+        assert!(input.sample().is_ok());
+        assert_eq!(input.sample(), Err(BadLine(3)));
+
+        let mut input = RecordedInput::new("# nothing\n\n5,0,x,16384\n");
+        assert_eq!(input.sample(), Err(BadLine(3)));
+    }
+}
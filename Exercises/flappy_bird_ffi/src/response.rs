@@ -0,0 +1,277 @@
+// shaping of the tilt angle before it is mapped to a screen position.
+// the angle is normalised over the calibrated tilt range (0 = top, 1 = bottom)
+// and goes through: smoothing -> dead zone -> curve -> rate limit.
+// the result is an angle again, inside the tilt range.
+
+// longest median window
+pub const MAX_MEDIAN_WINDOW: usize = 7;
+
+// after a longer pause between samples smoothing and rate limit start over
+const MAX_GAP_MS: u32 = 500;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Curve {
+    Linear,
+    // 0..1, more means finer control around the middle, faster at the ends
+    Expo(f32),
+    // 0..1, more means faster through the middle, finer at the ends
+    Sigmoid(f32),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Smoothing {
+    None,
+    // weight of the new sample, 0..1, smaller is smoother
+    Ema(f32),
+    // median of the last n samples (up to MAX_MEDIAN_WINDOW), drops single spikes
+    Median(usize),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ResponseCurve {
+    pub smoothing: Smoothing,
+    // part of the range around the middle which maps to the middle, 0..1
+    pub dead_zone: f32,
+    pub curve: Curve,
+    // fastest change in tilt ranges per second, 0 for no limit
+    pub max_rate: f32,
+}
+
+impl ResponseCurve {
+    // straight mapping, what SensorInput did before there were curves
+    pub const LINEAR: Self = Self {
+        smoothing: Smoothing::None,
+        dead_zone: 0.0,
+        curve: Curve::Linear,
+        max_rate: 0.0,
+    };
+
+    //dead zone and curve for a position `t` in 0..1
+    pub fn shape(&self, t: f32) -> f32 {
+        // -1..1 around the middle
+        let u = (2.0 * t - 1.0).clamp(-1.0, 1.0);
+
+        let u = if self.dead_zone <= 0.0 {
+            u
+        } else if u.abs() <= self.dead_zone {
+            0.0
+        } else {
+            u.signum() * (u.abs() - self.dead_zone) / (1.0 - self.dead_zone)
+        };
+
+        let u = match self.curve {
+            Curve::Linear => u,
+            Curve::Expo(k) => (1.0 - k) * u + k * u * u * u,
+            Curve::Sigmoid(k) => (1.0 - k) * u + k * (3.0 * u - u * u * u) / 2.0,
+        };
+
+        (u + 1.0) / 2.0
+    }
+}
+
+impl Default for ResponseCurve {
+    fn default() -> Self {
+        Self::LINEAR
+    }
+}
+
+// a ResponseCurve together with its smoothing and rate limit state
+pub struct Response {
+    curve: ResponseCurve,
+    ema: Option<f32>,
+    window: [f32; MAX_MEDIAN_WINDOW],
+    window_len: usize,
+    window_next: usize,
+    // last output (0..1) and its tick
+    last: Option<(f32, u32)>,
+}
+
+impl Response {
+    pub fn new(curve: ResponseCurve) -> Self {
+        Self {
+            curve,
+            ema: None,
+            window: [0.0; MAX_MEDIAN_WINDOW],
+            window_len: 0,
+            window_next: 0,
+            last: None,
+        }
+    }
+
+    pub fn curve(&self) -> &ResponseCurve {
+        &self.curve
+    }
+
+    pub fn set_curve(&mut self, curve: ResponseCurve) {
+        *self = Self::new(curve);
+    }
+
+    pub fn reset(&mut self) {
+        self.set_curve(self.curve);
+    }
+
+    //shapes `angle` inside the range tilt_top..tilt_bottom (either order), sampled at tick `now_ms`
+    pub fn apply(&mut self, angle: f32, tilt_top: f32, tilt_bottom: f32, now_ms: u32) -> f32 {
+        let range = tilt_bottom - tilt_top;
+        if range == 0.0 {
+            return angle;
+        }
+
+        if let Some((_, last_ms)) = self.last {
+            if now_ms.wrapping_sub(last_ms) > MAX_GAP_MS {
+                self.reset();
+            }
+        }
+
+        let t = ((angle - tilt_top) / range).clamp(0.0, 1.0);
+        let t = self.smooth(t);
+        let t = self.curve.shape(t);
+        let t = self.limit_rate(t, now_ms);
+
+        tilt_top + t * range
+    }
+
+    fn smooth(&mut self, t: f32) -> f32 {
+        match self.curve.smoothing {
+            Smoothing::None => t,
+            Smoothing::Ema(weight) => {
+                let smoothed = match self.ema {
+                    Some(previous) => previous + weight * (t - previous),
+                    None => t,
+                };
+                self.ema = Some(smoothed);
+                smoothed
+            }
+            Smoothing::Median(n) => {
+                let n = n.clamp(1, MAX_MEDIAN_WINDOW);
+                self.window[self.window_next] = t;
+                self.window_next = (self.window_next + 1) % n;
+                self.window_len = (self.window_len + 1).min(n);
+
+                let mut sorted = self.window;
+                let sorted = &mut sorted[..self.window_len];
+                sorted.sort_unstable_by(|a, b| a.total_cmp(b));
+                sorted[sorted.len() / 2]
+            }
+        }
+    }
+
+    fn limit_rate(&mut self, t: f32, now_ms: u32) -> f32 {
+        let t = match self.last {
+            Some((last, last_ms)) if self.curve.max_rate > 0.0 => {
+                let step = self.curve.max_rate * now_ms.wrapping_sub(last_ms) as f32 / 1000.0;
+                t.clamp(last - step, last + step)
+            }
+            _ => t,
+        };
+        self.last = Some((t, now_ms));
+        t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn linear_keeps_the_angle() {
+        let mut response = Response::new(ResponseCurve::LINEAR);
+        assert!(close(response.apply(40.0, -10.0, 90.0, 0), 40.0));
+        // outside of the range sticks to its ends
+        assert!(close(response.apply(120.0, -10.0, 90.0, 10), 90.0));
+        // flipped range
+        assert!(close(response.apply(0.0, 30.0, -10.0, 20), 0.0));
+    }
+
+    #[test]
+    fn dead_zone_holds_the_middle() {
+        let curve = ResponseCurve {
+            dead_zone: 0.2,
+            ..ResponseCurve::LINEAR
+        };
+        assert!(close(curve.shape(0.45), 0.5));
+        assert!(close(curve.shape(0.55), 0.5));
+        assert!(close(curve.shape(0.0), 0.0));
+        assert!(close(curve.shape(1.0), 1.0));
+        // the rest of the range is stretched to fill the gap
+        assert!(close(curve.shape(0.8), 0.5 + 0.5 * (0.6 - 0.2) / 0.8));
+    }
+
+    #[test]
+    fn curves_keep_the_ends_and_middle() {
+        for curve in [Curve::Expo(0.7), Curve::Sigmoid(0.7)] {
+            let curve = ResponseCurve {
+                curve,
+                ..ResponseCurve::LINEAR
+            };
+            assert!(close(curve.shape(0.0), 0.0));
+            assert!(close(curve.shape(0.5), 0.5));
+            assert!(close(curve.shape(1.0), 1.0));
+        }
+
+        let expo = ResponseCurve {
+            curve: Curve::Expo(0.7),
+            ..ResponseCurve::LINEAR
+        };
+        let s_curve = ResponseCurve {
+            curve: Curve::Sigmoid(0.7),
+            ..ResponseCurve::LINEAR
+        };
+        // expo moves less than linear near the middle, the s-curve more
+        assert!(expo.shape(0.6) < 0.6);
+        assert!(s_curve.shape(0.6) > 0.6);
+        // and both stay monotonic
+        let mut previous = [0.0; 2];
+        for i in 0..=100 {
+            let t = i as f32 / 100.0;
+            let now = [expo.shape(t), s_curve.shape(t)];
+            assert!(now[0] >= previous[0] && now[1] >= previous[1]);
+            previous = now;
+        }
+    }
+
+    #[test]
+    fn ema_smooths_steps() {
+        let mut response = Response::new(ResponseCurve {
+            smoothing: Smoothing::Ema(0.25),
+            ..ResponseCurve::LINEAR
+        });
+        assert!(close(response.apply(0.0, 0.0, 100.0, 0), 0.0));
+        assert!(close(response.apply(100.0, 0.0, 100.0, 10), 25.0));
+        assert!(close(response.apply(100.0, 0.0, 100.0, 20), 43.75));
+    }
+
+    #[test]
+    fn median_drops_spikes() {
+        let mut response = Response::new(ResponseCurve {
+            smoothing: Smoothing::Median(3),
+            ..ResponseCurve::LINEAR
+        });
+        let out: Vec<f32> = [20.0, 21.0, 95.0, 22.0, 23.0]
+            .iter()
+            .enumerate()
+            .map(|(i, &angle)| response.apply(angle, 0.0, 100.0, 10 * i as u32))
+            .collect();
+        assert!(out.iter().all(|&a| a < 30.0), "{:?}", out);
+        assert!(close(out[4], 23.0));
+    }
+
+    #[test]
+    fn rate_limit_and_gaps() {
+        // a whole range takes at least 0.5 s
+        let mut response = Response::new(ResponseCurve {
+            max_rate: 2.0,
+            ..ResponseCurve::LINEAR
+        });
+        response.apply(0.0, 0.0, 100.0, 1000);
+        assert!(close(response.apply(100.0, 0.0, 100.0, 1100), 20.0));
+        assert!(close(response.apply(100.0, 0.0, 100.0, 1200), 40.0));
+
+        // after a pause the position jumps to the input
+        assert!(close(response.apply(100.0, 0.0, 100.0, 3000), 100.0));
+    }
+}
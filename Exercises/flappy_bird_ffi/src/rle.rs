@@ -0,0 +1,230 @@
+// run length encoding of RGB565 images, row by row.
+// this file is also compiled into build.rs (encoder side), so it must not
+// depend on anything else in the crate.
+//
+// every row is a sequence of packets, packets never cross a row boundary:
+//   header bit7 = 1 : run,     (header & 0x7F) + 1 copies of the next pixel
+//   header bit7 = 0 : literal, header + 1 pixels follow
+// a pixel is one byte (palette index) for indexed images,
+// or two bytes (RGB565, little endian) for direct colour images.
+
+const RUN_FLAG: u8 = 0x80;
+const MAX_PACKET_LEN: usize = 128;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DecodeError {
+    // data ended in the middle of a row
+    Truncated,
+    // a packet runs past the end of the row
+    RowOverflow,
+    // palette index out of range
+    BadIndex,
+}
+
+fn pixel_size(palette: Option<&[u16]>) -> usize {
+    if palette.is_some() {
+        1
+    } else {
+        2
+    }
+}
+
+// appends the packets of one row to `out`.
+// with a palette every pixel of the row must be one of its colours.
+pub fn encode_row(row: &[u16], palette: Option<&[u16]>, out: &mut impl FnMut(u8)) {
+    // a run only pays off once it is shorter than the same pixels as literals
+    let min_run = if pixel_size(palette) == 1 { 3 } else { 2 };
+
+    let mut i = 0;
+    let mut literal_start = 0;
+
+    while i < row.len() {
+        let mut run = 1;
+        while i + run < row.len() && row[i + run] == row[i] && run < MAX_PACKET_LEN {
+            run += 1;
+        }
+
+        if run >= min_run {
+            emit_literals(&row[literal_start..i], palette, out);
+            out(RUN_FLAG | (run - 1) as u8);
+            emit_pixel(row[i], palette, out);
+            i += run;
+            literal_start = i;
+        } else {
+            i += 1;
+        }
+    }
+
+    emit_literals(&row[literal_start..], palette, out);
+}
+
+fn emit_literals(pixels: &[u16], palette: Option<&[u16]>, out: &mut impl FnMut(u8)) {
+    for chunk in pixels.chunks(MAX_PACKET_LEN) {
+        out((chunk.len() - 1) as u8);
+        for &px in chunk {
+            emit_pixel(px, palette, out);
+        }
+    }
+}
+
+fn emit_pixel(px: u16, palette: Option<&[u16]>, out: &mut impl FnMut(u8)) {
+    match palette {
+        Some(palette) => {
+            let index = palette
+                .iter()
+                .position(|&c| c == px)
+                .expect("colour missing from palette");
+            out(index as u8);
+        }
+        None => {
+            let [lo, hi] = px.to_le_bytes();
+            out(lo);
+            out(hi);
+        }
+    }
+}
+
+// streaming decoder, keeps only its position in the data
+pub struct RowDecoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+    palette: Option<&'a [u16]>,
+}
+
+impl<'a> RowDecoder<'a> {
+    pub fn new(data: &'a [u8], palette: Option<&'a [u16]>) -> Self {
+        Self {
+            data,
+            pos: 0,
+            palette,
+        }
+    }
+
+    // decodes the next row, `row.len()` must be the image width
+    pub fn next_row(&mut self, row: &mut [u16]) -> Result<(), DecodeError> {
+        let mut x = 0;
+
+        while x < row.len() {
+            let header = self.next_byte()?;
+            let len = (header & !RUN_FLAG) as usize + 1;
+            if x + len > row.len() {
+                return Err(DecodeError::RowOverflow);
+            }
+
+            if header & RUN_FLAG != 0 {
+                let px = self.next_pixel()?;
+                row[x..x + len].fill(px);
+            } else {
+                for px in row[x..x + len].iter_mut() {
+                    *px = self.next_pixel()?;
+                }
+            }
+            x += len;
+        }
+
+        Ok(())
+    }
+
+    fn next_byte(&mut self) -> Result<u8, DecodeError> {
+        let byte = *self.data.get(self.pos).ok_or(DecodeError::Truncated)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn next_pixel(&mut self) -> Result<u16, DecodeError> {
+        match self.palette {
+            Some(palette) => {
+                let index = self.next_byte()? as usize;
+                palette.get(index).copied().ok_or(DecodeError::BadIndex)
+            }
+            None => {
+                let lo = self.next_byte()?;
+                let hi = self.next_byte()?;
+                Ok(u16::from_le_bytes([lo, hi]))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::encode;
+
+    fn decode(data: &[u8], width: usize, height: usize, palette: Option<&[u16]>) -> Result<Vec<u16>, DecodeError> {
+        let mut decoder = RowDecoder::new(data, palette);
+        let mut pixels = vec![0; width * height];
+        for row in pixels.chunks_mut(width) {
+            decoder.next_row(row)?;
+        }
+        Ok(pixels)
+    }
+
+    // deterministic mix of long runs and noise
+    fn test_image(width: usize, height: usize, colours: u16) -> Vec<u16> {
+        let mut seed = 0x1234_5678u32;
+        (0..width * height)
+            .map(|i| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                if (i / 7) % 3 == 0 {
+                    0x9F5E
+                } else {
+                    ((seed >> 16) as u16) % colours
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn direct_round_trip() {
+        let pixels = test_image(160, 80, 0xFFFF);
+        let data = encode(&pixels, 160, None);
+        assert_eq!(decode(&data, 160, 80, None).unwrap(), pixels);
+    }
+
+    #[test]
+    fn indexed_round_trip() {
+        let pixels = test_image(60, 30, 40);
+        let mut palette: Vec<u16> = pixels.clone();
+        palette.sort();
+        palette.dedup();
+
+        let data = encode(&pixels, 60, Some(&palette));
+        assert_eq!(decode(&data, 60, 30, Some(&palette)).unwrap(), pixels);
+    }
+
+    #[test]
+    fn long_runs_are_split_into_packets() {
+        let pixels = vec![0xF800; 300];
+        let data = encode(&pixels, 300, None);
+        // 128 + 128 + 44, three bytes per run packet
+        assert_eq!(data, [0xFF, 0x00, 0xF8, 0xFF, 0x00, 0xF8, 0xAB, 0x00, 0xF8]);
+        assert_eq!(decode(&data, 300, 1, None).unwrap(), pixels);
+    }
+
+    #[test]
+    fn solid_image_compresses_well() {
+        let pixels = vec![0x9F5E; 160 * 80];
+        let data = encode(&pixels, 160, None);
+        // two run packets per row
+        assert_eq!(data.len(), 80 * 2 * 3);
+    }
+
+    #[test]
+    fn runs_do_not_cross_rows() {
+        let pixels = vec![1, 1, 1, 1, 1, 1];
+        let data = encode(&pixels, 3, None);
+        assert_eq!(data, [0x82, 0x01, 0x00, 0x82, 0x01, 0x00]);
+    }
+
+    #[test]
+    fn corrupt_data_is_reported() {
+        let pixels = test_image(16, 4, 0xFFFF);
+        let data = encode(&pixels, 16, None);
+
+        assert_eq!(decode(&data[..data.len() - 1], 16, 4, None), Err(DecodeError::Truncated));
+        // a run of 5 in a row of 4
+        assert_eq!(decode(&[0x84, 0x00, 0x00], 4, 1, None), Err(DecodeError::RowOverflow));
+        assert_eq!(decode(&[0x83, 0x02], 4, 1, Some(&[0, 1])), Err(DecodeError::BadIndex));
+    }
+}
@@ -0,0 +1,539 @@
+// player settings and high scores, kept across power cycles by a SettingsStore.
+// on the board the store is the last flash page (FlashStore), tests use a RamStore.
+//
+// stored record, little endian:
+//   magic u16 | version u8 | difficulty u8 | input source u8 | theme u8 |
+//   calibrated u8 (bit per input source) | profile u8 |
+//   per input source: accel offsets 3 x i16 | tilt top, bottom 2 x i16 (0.1 degree) |
+//   high scores HIGH_SCORE_COUNT x u32 | checksum u16
+
+use crate::calibration::Calibration;
+use crate::color::{self, Theme};
+use crate::config::SPEED;
+use crate::response::{Curve, ResponseCurve, Smoothing};
+
+pub const HIGH_SCORE_COUNT: usize = 5;
+pub const INPUT_SOURCE_COUNT: usize = 3;
+
+const MAGIC: u16 = 0xF1A9;
+const VERSION: u8 = 3;
+const CALIBRATION_AT: usize = 8;
+const CALIBRATION_LEN: usize = 10;
+const SCORES_AT: usize = CALIBRATION_AT + CALIBRATION_LEN * INPUT_SOURCE_COUNT;
+pub const RECORD_LEN: usize = SCORES_AT + 4 * HIGH_SCORE_COUNT + 2;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+// which tilt of the board moves the bird
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum InputSource {
+    // rotation around the long axis of the board
+    Roll,
+    // rotation around the short axis of the board
+    Pitch,
+    // the bird falls, a flick of the board flaps
+    Flap,
+}
+
+// how the tilt is turned into a bird position, see response.rs
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Profile {
+    // straight mapping
+    Classic,
+    // averaged and rate limited, for shaky hands
+    Smooth,
+    // fine control around the middle
+    Precise,
+    // drops spikes, holds the middle
+    Steady,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Settings {
+    pub difficulty: Difficulty,
+    pub input_source: InputSource,
+    pub profile: Profile,
+    // index into color::THEMES
+    pub theme: u8,
+    // per input source, indexed by InputSource as usize: each source tilts the board
+    // its own way. None until the input device has been calibrated with that source
+    pub calibration: [Option<Calibration>; INPUT_SOURCE_COUNT],
+    // best first, unused slots are 0
+    pub high_scores: [u32; HIGH_SCORE_COUNT],
+}
+
+impl Difficulty {
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Easy,
+        }
+    }
+
+    //pixels the obstacles move per frame
+    pub fn obstacle_speed(self) -> u32 {
+        match self {
+            Difficulty::Easy => SPEED - 1,
+            Difficulty::Normal => SPEED,
+            Difficulty::Hard => SPEED + 1,
+        }
+    }
+
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Difficulty::Easy),
+            1 => Some(Difficulty::Normal),
+            2 => Some(Difficulty::Hard),
+            _ => None,
+        }
+    }
+}
+
+impl InputSource {
+    pub fn name(self) -> &'static str {
+        match self {
+            InputSource::Roll => "Roll",
+            InputSource::Pitch => "Pitch",
+            InputSource::Flap => "Flap",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            InputSource::Roll => InputSource::Pitch,
+            InputSource::Pitch => InputSource::Flap,
+            InputSource::Flap => InputSource::Roll,
+        }
+    }
+
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(InputSource::Roll),
+            1 => Some(InputSource::Pitch),
+            2 => Some(InputSource::Flap),
+            _ => None,
+        }
+    }
+}
+
+impl Profile {
+    pub fn name(self) -> &'static str {
+        match self {
+            Profile::Classic => "Classic",
+            Profile::Smooth => "Smooth",
+            Profile::Precise => "Precise",
+            Profile::Steady => "Steady",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Profile::Classic => Profile::Smooth,
+            Profile::Smooth => Profile::Precise,
+            Profile::Precise => Profile::Steady,
+            Profile::Steady => Profile::Classic,
+        }
+    }
+
+    pub fn response(self) -> ResponseCurve {
+        match self {
+            Profile::Classic => ResponseCurve::LINEAR,
+            Profile::Smooth => ResponseCurve {
+                smoothing: Smoothing::Ema(0.3),
+                max_rate: 3.0,
+                ..ResponseCurve::LINEAR
+            },
+            Profile::Precise => ResponseCurve {
+                dead_zone: 0.05,
+                curve: Curve::Expo(0.6),
+                ..ResponseCurve::LINEAR
+            },
+            Profile::Steady => ResponseCurve {
+                smoothing: Smoothing::Median(5),
+                dead_zone: 0.15,
+                curve: Curve::Sigmoid(0.5),
+                max_rate: 0.0,
+            },
+        }
+    }
+
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Profile::Classic),
+            1 => Some(Profile::Smooth),
+            2 => Some(Profile::Precise),
+            3 => Some(Profile::Steady),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            difficulty: Difficulty::Normal,
+            input_source: InputSource::Roll,
+            profile: Profile::Classic,
+            theme: 0,
+            calibration: [None; INPUT_SOURCE_COUNT],
+            high_scores: [0; HIGH_SCORE_COUNT],
+        }
+    }
+}
+
+impl Settings {
+    pub fn theme(&self) -> &'static Theme {
+        color::THEMES[self.theme as usize % color::THEMES.len()]
+    }
+
+    pub fn set_theme(&mut self, theme: &Theme) {
+        self.theme = color::THEMES.iter().position(|t| *t == theme).unwrap_or(0) as u8;
+    }
+
+    //calibration measured with `source`, None if it was never calibrated
+    pub fn calibration(&self, source: InputSource) -> Option<Calibration> {
+        self.calibration[source as usize]
+    }
+
+    pub fn set_calibration(&mut self, source: InputSource, calibration: Calibration) {
+        self.calibration[source as usize] = Some(calibration);
+    }
+
+    //adds `score` to the high score table, returns its place (0 = best) if it made it in
+    pub fn record_score(&mut self, score: u32) -> Option<usize> {
+        if score == 0 {
+            return None;
+        }
+
+        let place = self.high_scores.iter().position(|&s| score > s)?;
+        self.high_scores.copy_within(place..HIGH_SCORE_COUNT - 1, place + 1);
+        self.high_scores[place] = score;
+        Some(place)
+    }
+
+    pub fn to_bytes(self) -> [u8; RECORD_LEN] {
+        let mut buf = [0u8; RECORD_LEN];
+        buf[0..2].copy_from_slice(&MAGIC.to_le_bytes());
+        buf[2] = VERSION;
+        buf[3] = self.difficulty as u8;
+        buf[4] = self.input_source as u8;
+        buf[5] = self.theme;
+        buf[7] = self.profile as u8;
+        for (source, calibration) in self.calibration.iter().enumerate() {
+            let Some(calibration) = calibration else {
+                continue;
+            };
+            buf[6] |= 1 << source;
+            let values = [
+                calibration.accel_offset[0],
+                calibration.accel_offset[1],
+                calibration.accel_offset[2],
+                to_tenths(calibration.tilt_top),
+                to_tenths(calibration.tilt_bottom),
+            ];
+            let at = CALIBRATION_AT + CALIBRATION_LEN * source;
+            for (i, value) in values.iter().enumerate() {
+                buf[at + 2 * i..at + 2 * i + 2].copy_from_slice(&value.to_le_bytes());
+            }
+        }
+        for (i, score) in self.high_scores.iter().enumerate() {
+            let at = SCORES_AT + 4 * i;
+            buf[at..at + 4].copy_from_slice(&score.to_le_bytes());
+        }
+        let sum = checksum(&buf[..RECORD_LEN - 2]);
+        buf[RECORD_LEN - 2..].copy_from_slice(&sum.to_le_bytes());
+        buf
+    }
+
+    //None for erased flash, an old version or a damaged record
+    pub fn from_bytes(buf: &[u8]) -> Option<Self> {
+        let buf = buf.get(..RECORD_LEN)?;
+        let sum = u16::from_le_bytes([buf[RECORD_LEN - 2], buf[RECORD_LEN - 1]]);
+        if u16::from_le_bytes([buf[0], buf[1]]) != MAGIC
+            || buf[2] != VERSION
+            || sum != checksum(&buf[..RECORD_LEN - 2])
+        {
+            return None;
+        }
+
+        let i16_at = |at: usize| i16::from_le_bytes([buf[at], buf[at + 1]]);
+        let calibration = core::array::from_fn(|source| {
+            let at = CALIBRATION_AT + CALIBRATION_LEN * source;
+            (buf[6] & (1 << source) != 0).then(|| Calibration {
+                accel_offset: [i16_at(at), i16_at(at + 2), i16_at(at + 4)],
+                tilt_top: i16_at(at + 6) as f32 / 10.0,
+                tilt_bottom: i16_at(at + 8) as f32 / 10.0,
+            })
+        });
+
+        let mut high_scores = [0; HIGH_SCORE_COUNT];
+        for (i, score) in high_scores.iter_mut().enumerate() {
+            let at = SCORES_AT + 4 * i;
+            *score = u32::from_le_bytes([buf[at], buf[at + 1], buf[at + 2], buf[at + 3]]);
+        }
+
+        Some(Self {
+            difficulty: Difficulty::from_u8(buf[3])?,
+            input_source: InputSource::from_u8(buf[4])?,
+            profile: Profile::from_u8(buf[7])?,
+            theme: buf[5],
+            calibration,
+            high_scores,
+        })
+    }
+}
+
+fn to_tenths(degrees: f32) -> i16 {
+    let tenths = degrees * 10.0;
+    (if tenths < 0.0 { tenths - 0.5 } else { tenths + 0.5 }) as i16
+}
+
+// Fletcher-16
+fn checksum(data: &[u8]) -> u16 {
+    let (mut a, mut b) = (0u16, 0u16);
+    for &byte in data {
+        a = (a + byte as u16) % 255;
+        b = (b + a) % 255;
+    }
+    (b << 8) | a
+}
+
+// somewhere to keep one settings record
+pub trait SettingsStore {
+    type Error;
+    fn read(&mut self, buf: &mut [u8; RECORD_LEN]) -> Result<(), Self::Error>;
+    fn write(&mut self, data: &[u8; RECORD_LEN]) -> Result<(), Self::Error>;
+}
+
+//stored settings, or the defaults if there are none (or they can not be read)
+pub fn load<S: SettingsStore>(store: &mut S) -> Settings {
+    let mut buf = [0u8; RECORD_LEN];
+    match store.read(&mut buf) {
+        Ok(()) => Settings::from_bytes(&buf).unwrap_or_default(),
+        Err(_) => Settings::default(),
+    }
+}
+
+pub fn save<S: SettingsStore>(store: &mut S, settings: &Settings) -> Result<(), S::Error> {
+    store.write(&settings.to_bytes())
+}
+
+// store without persistence, for boards without a flash page to spare and for tests
+pub struct RamStore {
+    data: [u8; RECORD_LEN],
+}
+
+impl RamStore {
+    pub fn new() -> Self {
+        Self {
+            data: [0xFF; RECORD_LEN],
+        }
+    }
+}
+
+impl Default for RamStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SettingsStore for RamStore {
+    type Error = core::convert::Infallible;
+
+    fn read(&mut self, buf: &mut [u8; RECORD_LEN]) -> Result<(), Self::Error> {
+        *buf = self.data;
+        Ok(())
+    }
+
+    fn write(&mut self, data: &[u8; RECORD_LEN]) -> Result<(), Self::Error> {
+        self.data = *data;
+        Ok(())
+    }
+}
+
+extern "C" {
+    fn HAL_FLASH_Unlock() -> u8;
+    fn HAL_FLASH_Lock() -> u8;
+    fn HAL_FLASHEx_Erase(erase_init: *mut FlashEraseInit, page_error: *mut u32) -> u8;
+    fn HAL_FLASH_Program(type_program: u32, address: u32, data: u64) -> u8;
+}
+
+// FLASH_EraseInitTypeDef
+#[repr(C)]
+struct FlashEraseInit {
+    type_erase: u32,
+    page_address: u32,
+    nb_pages: u32,
+}
+
+const HAL_OK: u8 = 0;
+const FLASH_TYPEERASE_PAGES: u32 = 0x00;
+const FLASH_TYPEPROGRAM_HALFWORD: u32 = 0x01;
+
+// last 2K page of the 256K flash, kept out of the program by STM32F303CCTX_FLASH.ld
+pub const SETTINGS_PAGE_ADDR: u32 = 0x0803_F800;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FlashError {
+    Erase,
+    Program,
+}
+
+// settings page in the internal flash, written through the C HAL flash driver
+pub struct FlashStore;
+
+impl SettingsStore for FlashStore {
+    type Error = FlashError;
+
+    fn read(&mut self, buf: &mut [u8; RECORD_LEN]) -> Result<(), Self::Error> {
+        let page = SETTINGS_PAGE_ADDR as *const u8;
+        for (i, byte) in buf.iter_mut().enumerate() {
+            *byte = unsafe { core::ptr::read_volatile(page.add(i)) };
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, data: &[u8; RECORD_LEN]) -> Result<(), Self::Error> {
+        unsafe {
+            HAL_FLASH_Unlock();
+            let result = program_page(data);
+            HAL_FLASH_Lock();
+            result
+        }
+    }
+}
+
+// the page has to be unlocked
+unsafe fn program_page(data: &[u8; RECORD_LEN]) -> Result<(), FlashError> {
+    let mut erase = FlashEraseInit {
+        type_erase: FLASH_TYPEERASE_PAGES,
+        page_address: SETTINGS_PAGE_ADDR,
+        nb_pages: 1,
+    };
+    let mut page_error = 0;
+    if HAL_FLASHEx_Erase(&mut erase, &mut page_error) != HAL_OK {
+        return Err(FlashError::Erase);
+    }
+
+    // flash is programmed a half word at a time, RECORD_LEN is even
+    for (i, half_word) in data.chunks(2).enumerate() {
+        let value = u16::from_le_bytes([half_word[0], half_word[1]]);
+        let address = SETTINGS_PAGE_ADDR + 2 * i as u32;
+        if HAL_FLASH_Program(FLASH_TYPEPROGRAM_HALFWORD, address, value as u64) != HAL_OK {
+            return Err(FlashError::Program);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_round_trip() {
+        let settings = Settings {
+            difficulty: Difficulty::Hard,
+            input_source: InputSource::Pitch,
+            profile: Profile::Steady,
+            theme: 1,
+            calibration: [
+                None,
+                Some(Calibration {
+                    accel_offset: [-412, 95, 730],
+                    tilt_top: -12.5,
+                    tilt_bottom: 71.0,
+                }),
+                Some(Calibration {
+                    accel_offset: [3, -8, 120],
+                    tilt_top: 20.0,
+                    tilt_bottom: -35.4,
+                }),
+            ],
+            high_scores: [120, 64, 7, 0, 0],
+        };
+
+        let bytes = settings.to_bytes();
+        assert_eq!(RECORD_LEN % 2, 0);
+        assert_eq!(Settings::from_bytes(&bytes), Some(settings));
+    }
+
+    #[test]
+    fn calibration_per_input_source() {
+        let mut settings = Settings::default();
+        let pitch = Calibration {
+            tilt_top: 15.0,
+            ..Calibration::default()
+        };
+        settings.set_calibration(InputSource::Pitch, pitch);
+        assert_eq!(settings.calibration(InputSource::Pitch), Some(pitch));
+        assert_eq!(settings.calibration(InputSource::Roll), None);
+        assert_eq!(settings.calibration(InputSource::Flap), None);
+    }
+
+    #[test]
+    fn erased_or_damaged_record_gives_defaults() {
+        let mut store = RamStore::new();
+        assert_eq!(load(&mut store), Settings::default());
+
+        let mut bytes = Settings::default().to_bytes();
+        bytes[7] ^= 0x01;
+        assert_eq!(Settings::from_bytes(&bytes), None);
+    }
+
+    #[test]
+    fn saved_settings_are_loaded_again() {
+        let mut store = RamStore::new();
+        let mut settings = Settings {
+            difficulty: Difficulty::Easy,
+            ..Settings::default()
+        };
+        settings.set_theme(&color::NIGHT);
+        save(&mut store, &settings).unwrap();
+
+        let loaded = load(&mut store);
+        assert_eq!(loaded, settings);
+        assert_eq!(loaded.theme(), &color::NIGHT);
+    }
+
+    #[test]
+    fn high_scores_stay_sorted() {
+        let mut settings = Settings::default();
+        assert_eq!(settings.record_score(10), Some(0));
+        assert_eq!(settings.record_score(30), Some(0));
+        assert_eq!(settings.record_score(20), Some(1));
+        assert_eq!(settings.record_score(0), None);
+        assert_eq!(settings.high_scores, [30, 20, 10, 0, 0]);
+
+        for score in [40, 50, 60] {
+            settings.record_score(score);
+        }
+        assert_eq!(settings.high_scores, [60, 50, 40, 30, 20]);
+        assert_eq!(settings.record_score(5), None);
+        assert_eq!(settings.record_score(20), None);
+    }
+
+    #[test]
+    fn options_cycle() {
+        assert_eq!(Difficulty::Hard.next(), Difficulty::Easy);
+        assert_eq!(InputSource::Pitch.next(), InputSource::Flap);
+        assert_eq!(InputSource::Flap.next(), InputSource::Roll);
+        assert_eq!(Profile::Steady.next(), Profile::Classic);
+        assert!(Difficulty::Easy.obstacle_speed() < Difficulty::Hard.obstacle_speed());
+    }
+}
@@ -0,0 +1,179 @@
+// compressed images (see rle.rs for the format).
+// sprites are decoded while drawing: one row at a time goes to the panel
+// through draw_image, so RAM use is a single row, not the whole image.
+
+use crate::color::Recolor;
+use crate::config::*;
+use crate::display::DisplayBackend;
+use crate::rle::{DecodeError, RowDecoder};
+
+pub struct Sprite<'a> {
+    width: u32,
+    height: u32,
+    // Some(..) for palette indexed data, None for direct RGB565 data
+    palette: Option<&'a [u16]>,
+    data: &'a [u8],
+}
+
+impl<'a> Sprite<'a> {
+    pub const fn new(width: u32, height: u32, palette: Option<&'a [u16]>, data: &'a [u8]) -> Self {
+        assert!(width as usize <= LCD_WIDTH as usize, "sprite wider than the screen");
+        Self {
+            width,
+            height,
+            palette,
+            data,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn rows(&self) -> RowDecoder<'a> {
+        RowDecoder::new(self.data, self.palette)
+    }
+}
+
+// decodes `sprite` straight onto the panel with its top left corner at (x, y).
+// rows and columns outside the screen are decoded but not drawn.
+pub fn draw_sprite<B: DisplayBackend>(
+    backend: &mut B,
+    x: Coord,
+    y: Coord,
+    sprite: &Sprite,
+) -> Result<(), DecodeError> {
+    draw_sprite_recolored(backend, x, y, sprite, Recolor::NONE)
+}
+
+//draw_sprite() with `recolor` applied to every decoded pixel
+pub fn draw_sprite_recolored<B: DisplayBackend>(
+    backend: &mut B,
+    x: Coord,
+    y: Coord,
+    sprite: &Sprite,
+    recolor: Recolor,
+) -> Result<(), DecodeError> {
+    let width = sprite.width as usize;
+    let mut row = [0u16; LCD_WIDTH as usize];
+    let mut rows = sprite.rows();
+
+    // visible columns of every row
+    let first_col = (-x).clamp(0, width as Coord) as usize;
+    let last_col = (LCD_WIDTH as Coord - x).clamp(0, width as Coord) as usize;
+
+    for row_y in y..y + sprite.height as Coord {
+        rows.next_row(&mut row[..width])?;
+
+        if row_y < 0 || row_y >= LCD_HEIGHT as Coord || first_col >= last_col {
+            continue;
+        }
+
+        if !recolor.is_identity() {
+            for px in row[first_col..last_col].iter_mut() {
+                *px = recolor.apply(*px);
+            }
+        }
+
+        backend.draw_image(
+            (x + first_col as Coord) as u16,
+            (last_col - first_col) as u16,
+            row_y as u16,
+            1,
+            &row[first_col..last_col],
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{encode, FrameBuffer};
+
+    // 20x10 image, every pixel encodes its own position
+    fn gradient() -> Vec<u16> {
+        (0..10u16)
+            .flat_map(|y| (0..20u16).map(move |x| (y << 8) | x))
+            .collect()
+    }
+
+    #[test]
+    fn round_trip_through_the_panel() {
+        let pixels = gradient();
+        let data = encode(&pixels, 20, None);
+        let sprite = Sprite::new(20, 10, None, &data);
+
+        let mut fb = FrameBuffer::new();
+        draw_sprite(&mut fb, 100, 50, &sprite).unwrap();
+
+        // drawn row by row
+        assert_eq!((fb.image_calls, fb.pixel_calls, fb.rect_calls), (10, 0, 0));
+        assert_eq!(fb.largest_blit, 20);
+        for y in 0..10 {
+            for x in 0..20 {
+                assert_eq!(fb.at(100 + x, 50 + y), pixels[y * 20 + x]);
+            }
+        }
+    }
+
+    #[test]
+    fn indexed_round_trip_through_the_panel() {
+        let palette = [0x9F5E, 0x0000, 0xFFFF];
+        let pixels: Vec<u16> = (0..60 * 30).map(|i| palette[(i / 13) % 3]).collect();
+        let data = encode(&pixels, 60, Some(&palette));
+        let sprite = Sprite::new(60, 30, Some(&palette), &data);
+
+        let mut fb = FrameBuffer::new();
+        draw_sprite(&mut fb, 0, 210, &sprite).unwrap();
+
+        for y in 0..30 {
+            for x in 0..60 {
+                assert_eq!(fb.at(x, 210 + y), pixels[y * 60 + x]);
+            }
+        }
+    }
+
+    #[test]
+    fn sprite_is_clipped_at_the_edges() {
+        let pixels = gradient();
+        let data = encode(&pixels, 20, None);
+        let sprite = Sprite::new(20, 10, None, &data);
+
+        let mut fb = FrameBuffer::new();
+        draw_sprite(&mut fb, -5, -3, &sprite).unwrap();
+        assert_eq!(fb.image_calls, 7);
+        assert_eq!(fb.at(0, 0), (3 << 8) | 5);
+        assert_eq!(fb.at(14, 6), (9 << 8) | 19);
+
+        let mut fb = FrameBuffer::new();
+        draw_sprite(&mut fb, 230, 235, &sprite).unwrap();
+        assert_eq!(fb.image_calls, 5);
+        assert_eq!(fb.at(239, 239), (4 << 8) | 9);
+    }
+
+    #[test]
+    fn generated_title_sprites_decode() {
+        for sprite in [&crate::assets::GAME_NAME_IMG, &crate::assets::GAME_OVER_IMAGE] {
+            let mut fb = FrameBuffer::new();
+            draw_sprite(&mut fb, 40, 40, sprite).unwrap();
+            assert_eq!(fb.image_calls, 80);
+            // the title art sits on the sky colour
+            assert_eq!(fb.at(40, 40), crate::color::BACKGROUND);
+        }
+    }
+
+    #[test]
+    fn corrupt_sprite_is_an_error() {
+        let data = encode(&gradient(), 20, None);
+        let sprite = Sprite::new(20, 10, None, &data[..data.len() / 2]);
+
+        let mut fb = FrameBuffer::new();
+        assert_eq!(draw_sprite(&mut fb, 0, 0, &sprite), Err(DecodeError::Truncated));
+    }
+}
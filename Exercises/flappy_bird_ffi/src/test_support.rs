@@ -0,0 +1,118 @@
+// helpers shared by the host tests
+
+use std::cell::Cell;
+
+use crate::config::{LCD_HEIGHT, LCD_WIDTH};
+use crate::display::{DisplayBackend, DisplayDriver};
+use crate::rle::encode_row;
+
+const W: usize = LCD_WIDTH as usize;
+const H: usize = LCD_HEIGHT as usize;
+
+// framebuffer backend of the panel size, counts the calls per drawing operation
+pub struct FrameBuffer {
+    pub pixels: Vec<u16>,
+    pub pixel_calls: usize,
+    pub rect_calls: usize,
+    pub image_calls: usize,
+    // most pixels sent in one draw_image call
+    pub largest_blit: usize,
+}
+
+impl FrameBuffer {
+    pub fn new() -> Self {
+        Self {
+            pixels: vec![0; W * H],
+            pixel_calls: 0,
+            rect_calls: 0,
+            image_calls: 0,
+            largest_blit: 0,
+        }
+    }
+
+    pub fn at(&self, x: usize, y: usize) -> u16 {
+        self.pixels[y * W + x]
+    }
+}
+
+impl DisplayBackend for FrameBuffer {
+    fn draw_pixel(&mut self, x: u16, y: u16, color: u16) {
+        self.pixel_calls += 1;
+        self.pixels[y as usize * W + x as usize] = color;
+    }
+
+    fn fill_rectangle(&mut self, x: u16, w: u16, y: u16, h: u16, color: u16) {
+        self.rect_calls += 1;
+        for row in y..y + h {
+            for col in x..x + w {
+                self.pixels[row as usize * W + col as usize] = color;
+            }
+        }
+    }
+
+    fn draw_image(&mut self, x: u16, w: u16, y: u16, h: u16, image_data: &[u16]) {
+        self.image_calls += 1;
+        self.largest_blit = self.largest_blit.max(image_data.len());
+        assert_eq!(image_data.len(), w as usize * h as usize);
+        for row in 0..h as usize {
+            for col in 0..w as usize {
+                self.pixels[(y as usize + row) * W + x as usize + col] = image_data[row * w as usize + col];
+            }
+        }
+    }
+}
+
+// rle data of `pixels`, rows of `width`
+pub fn encode(pixels: &[u16], width: usize, palette: Option<&[u16]>) -> Vec<u8> {
+    let mut out = Vec::new();
+    for row in pixels.chunks(width) {
+        encode_row(row, palette, &mut |b| out.push(b));
+    }
+    out
+}
+
+// root mean square of the sample to sample movement
+pub fn jitter(values: &[f32]) -> f32 {
+    let sum: f32 = values.windows(2).map(|w| (w[1] - w[0]).powi(2)).sum();
+    (sum / (values.len() - 1) as f32).sqrt()
+}
+
+// trace recorded on the board, testdata/`name` (see testdata/README.md)
+pub fn board_capture(name: &str) -> String {
+    let path = format!("{}/testdata/{}", env!("CARGO_MANIFEST_DIR"), name);
+    std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("{} is not recorded yet, see testdata/README.md", path))
+}
+
+// stand-ins for the C side, so the game runs on the host: the panel draws nothing
+// and HAL_GetTick returns a clock the test sets, one per test thread
+thread_local! {
+    static TICK: Cell<u32> = const { Cell::new(0) };
+}
+
+pub fn set_tick(ms: u32) {
+    TICK.with(|tick| tick.set(ms));
+}
+
+#[no_mangle]
+#[allow(non_snake_case)]
+extern "C" fn HAL_GetTick() -> u32 {
+    TICK.with(Cell::get)
+}
+
+#[no_mangle]
+extern "C" fn display_register_driver(_driver: *const DisplayDriver) {}
+
+#[no_mangle]
+extern "C" fn display_init() {}
+
+#[no_mangle]
+extern "C" fn display_draw_image(_x: u16, _w: u16, _y: u16, _h: u16, _img_data: *const u16) {}
+
+#[no_mangle]
+extern "C" fn display_fill_screen(_color: u16) {}
+
+#[no_mangle]
+extern "C" fn display_fill_rectangle(_x: u16, _w: u16, _y: u16, _h: u16, _color: u16) {}
+
+#[no_mangle]
+extern "C" fn display_draw_pixel(_x: u16, _y: u16, _color: u16) {}
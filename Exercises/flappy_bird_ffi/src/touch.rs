@@ -0,0 +1,145 @@
+// CST816S touch controller of the round display (see c_src/.../tsc.c).
+// a finger coming down on the panel is a tap, where it lands does not matter.
+// the controller goes to standby a few seconds after the last touch and does not
+// answer (NACK) until it is touched again, so its reads fail most of the time
+// and it is used as the secondary device of a Composite, which retries it.
+
+use embedded_hal_1::i2c::I2c;
+
+use crate::game::{self, Input, InputDevice, InputSample};
+
+// gesture id, number of fingers, then the coordinates
+const REG_GESTURE_ID: u8 = 0x01;
+const REG_CHIP_ID: u8 = 0xA7;
+const CHIP_ID: u8 = 0xB4;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Error<E> {
+    Bus(E),
+    // chip id read back
+    WrongDevice(u8),
+}
+
+pub struct TouchInput<T: I2c> {
+    device_addr: u8,
+    i2c: T,
+    // a finger was on the panel at the last read
+    touching: bool,
+}
+
+impl<T: I2c> TouchInput<T> {
+    pub fn new(device_addr: u8, i2c_interface: T) -> Self {
+        Self {
+            device_addr,
+            i2c: i2c_interface,
+            touching: false,
+        }
+    }
+
+    //one sample stamped with `now`, Input::Flap when a finger came down since the last one
+    pub fn sample_at(&mut self, now: u32) -> Result<InputSample, Error<T::Error>> {
+        let mut data = [0u8; 2];
+        self.i2c
+            .write_read(self.device_addr, &[REG_GESTURE_ID], &mut data)
+            .map_err(Error::Bus)?;
+
+        let touching = data[1] > 0;
+        let input = if touching && !self.touching { Input::Flap } else { Input::None };
+        self.touching = touching;
+        Ok(InputSample::new(input, now))
+    }
+}
+
+impl<T: I2c> InputDevice for TouchInput<T> {
+    type Error = Error<T::Error>;
+
+    fn init(&mut self) -> Result<(), Self::Error> {
+        let mut id = [0u8; 1];
+        self.i2c
+            .write_read(self.device_addr, &[REG_CHIP_ID], &mut id)
+            .map_err(Error::Bus)?;
+        if id[0] != CHIP_ID {
+            return Err(Error::WrongDevice(id[0]));
+        }
+        self.touching = false;
+        Ok(())
+    }
+
+    fn sample(&mut self) -> Result<InputSample, Self::Error> {
+        self.sample_at(game::tick())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CST816S_DEV_ADDR;
+    use embedded_hal_1::i2c::{ErrorKind, ErrorType, NoAcknowledgeSource, Operation};
+
+    // controller registers, NACKs while `standby` is set or for another address
+    struct Panel {
+        regs: [u8; 256],
+        standby: bool,
+    }
+
+    impl Panel {
+        fn new() -> Self {
+            let mut regs = [0; 256];
+            regs[REG_CHIP_ID as usize] = CHIP_ID;
+            Self { regs, standby: false }
+        }
+
+        fn fingers(&mut self, count: u8) {
+            self.regs[REG_GESTURE_ID as usize + 1] = count;
+        }
+    }
+
+    impl ErrorType for Panel {
+        type Error = ErrorKind;
+    }
+
+    impl I2c for Panel {
+        fn transaction(&mut self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+            if self.standby || address != CST816S_DEV_ADDR {
+                return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+            }
+            let mut pointer = 0;
+            for operation in operations {
+                match operation {
+                    Operation::Write(bytes) => pointer = bytes[0] as usize,
+                    Operation::Read(buf) => {
+                        buf.copy_from_slice(&self.regs[pointer..pointer + buf.len()]);
+                        pointer += buf.len();
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn finger_down_is_one_tap() {
+        let mut touch = TouchInput::new(CST816S_DEV_ADDR, Panel::new());
+        assert_eq!(touch.init(), Ok(()));
+
+        let mut inputs = Vec::new();
+        for fingers in [0, 1, 1, 0, 1] {
+            touch.i2c.fingers(fingers);
+            inputs.push(touch.sample_at(0).unwrap().input);
+        }
+        assert_eq!(inputs, [Input::None, Input::Flap, Input::None, Input::None, Input::Flap]);
+    }
+
+    #[test]
+    fn standby_and_wrong_chip_fail() {
+        let mut panel = Panel::new();
+        panel.standby = true;
+        let mut touch = TouchInput::new(CST816S_DEV_ADDR, panel);
+        assert!(matches!(touch.init(), Err(Error::Bus(_))));
+        assert!(matches!(touch.sample_at(0), Err(Error::Bus(_))));
+
+        touch.i2c.standby = false;
+        touch.i2c.regs[REG_CHIP_ID as usize] = 0x11;
+        assert_eq!(touch.init(), Err(Error::WrongDevice(0x11)));
+    }
+}
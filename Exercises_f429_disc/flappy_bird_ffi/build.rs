@@ -0,0 +1,136 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::{env, path::PathBuf};
+
+fn main() {
+    generate_assets();
+}
+
+// ---------------------------------------------------------------------------
+// asset pipeline: PNG files in assets/ are converted into RGB565 image
+// constants ($OUT_DIR/assets.rs, pulled in by src/assets.rs).
+// to add an image, drop the PNG into assets/ and add an entry below.
+
+#[derive(Clone, Copy)]
+enum ByteOrder {
+    // u16 values as the CPU sees them, display_draw_image sends the high byte first
+    Native,
+    // high and low byte exchanged, for panels/DMA which stream the raw array bytes
+    Swapped,
+}
+
+struct ImageAsset {
+    // prefix of the generated constants: NAME_WIDTH, NAME_HEIGHT, NAME_DATA
+    name: &'static str,
+    path: &'static str,
+    // RGB565 colour written for transparent pixels (alpha < 128).
+    // PNGs with transparent pixels and no key are rejected.
+    transparency_key: Option<u16>,
+}
+
+const IMAGE_ASSETS: &[ImageAsset] = &[
+    ImageAsset {
+        name: "GAME_NAME_IMG",
+        path: "assets/game_name.png",
+        transparency_key: None,
+    },
+    ImageAsset {
+        name: "PLANT_IMG",
+        path: "assets/plant.png",
+        transparency_key: None,
+    },
+    ImageAsset {
+        name: "BIRD_IMG",
+        path: "assets/bird.png",
+        transparency_key: None,
+    },
+    ImageAsset {
+        name: "GAME_OVER_IMAGE",
+        path: "assets/game_over.png",
+        transparency_key: None,
+    },
+];
+
+fn generate_assets() {
+    // the `swapped-assets` feature selects the byte order for all images
+    let byte_order = if env::var_os("CARGO_FEATURE_SWAPPED_ASSETS").is_some() {
+        ByteOrder::Swapped
+    } else {
+        ByteOrder::Native
+    };
+
+    let mut code = String::from("// generated by build.rs from assets/*.png, do not edit\n");
+
+    for asset in IMAGE_ASSETS {
+        println!("cargo::rerun-if-changed={}", asset.path);
+
+        let (width, height, pixels) = convert_png(asset, byte_order);
+        writeln!(code).unwrap();
+        writeln!(code, "pub const {}_WIDTH: u32 = {};", asset.name, width).unwrap();
+        writeln!(code, "pub const {}_HEIGHT: u32 = {};", asset.name, height).unwrap();
+        writeln!(code, "pub static {}_DATA: [u16; {}] = [", asset.name, pixels.len()).unwrap();
+        for row in pixels.chunks(12) {
+            let row: Vec<String> = row.iter().map(|p| format!("{:#06x}", p)).collect();
+            writeln!(code, "    {},", row.join(", ")).unwrap();
+        }
+        writeln!(code, "];").unwrap();
+        writeln!(
+            code,
+            "pub static {name}: crate::image::Image = crate::image::Image::new({width}, {height}, &{name}_DATA);",
+            name = asset.name
+        )
+        .unwrap();
+    }
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set by Cargo")).join("assets.rs");
+    fs::write(&out_path, code).expect("can not write generated assets.rs");
+}
+
+// returns (width, height, RGB565 pixels row by row)
+fn convert_png(asset: &ImageAsset, byte_order: ByteOrder) -> (u32, u32, Vec<u16>) {
+    let file = fs::File::open(asset.path).unwrap_or_else(|e| panic!("can not open {}: {}", asset.path, e));
+
+    let mut decoder = png::Decoder::new(file);
+    // palette and low bit depth images are expanded, 16 bit channels are stripped to 8 bit
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder
+        .read_info()
+        .unwrap_or_else(|e| panic!("{} is not a valid PNG: {}", asset.path, e));
+
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut buf)
+        .unwrap_or_else(|e| panic!("can not decode {}: {}", asset.path, e));
+
+    let channels = info.color_type.samples();
+    let mut pixels = Vec::with_capacity((info.width * info.height) as usize);
+
+    for px in buf[..info.buffer_size()].chunks(channels) {
+        let (r, g, b, a) = match info.color_type {
+            png::ColorType::Grayscale => (px[0], px[0], px[0], 255),
+            png::ColorType::GrayscaleAlpha => (px[0], px[0], px[0], px[1]),
+            png::ColorType::Rgb => (px[0], px[1], px[2], 255),
+            png::ColorType::Rgba => (px[0], px[1], px[2], px[3]),
+            png::ColorType::Indexed => unreachable!("palette images are expanded by the decoder"),
+        };
+
+        let color = if a < 128 {
+            asset
+                .transparency_key
+                .unwrap_or_else(|| panic!("{} has transparent pixels but no transparency key", asset.path))
+        } else {
+            rgb888_to_rgb565(r, g, b)
+        };
+
+        pixels.push(match byte_order {
+            ByteOrder::Native => color,
+            ByteOrder::Swapped => color.swap_bytes(),
+        });
+    }
+
+    (info.width, info.height, pixels)
+}
+
+fn rgb888_to_rgb565(r: u8, g: u8, b: u8) -> u16 {
+    ((r as u16 >> 3) << 11) | ((g as u16 >> 2) << 5) | (b as u16 >> 3)
+}
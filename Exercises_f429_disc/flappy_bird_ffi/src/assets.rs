@@ -1,2371 +1,4 @@
-pub static GAME_NAME_IMG_DATA: [u16; 12800] = [
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x9f3e, 0x8edd, 0x863b, 0x7599, 0x6d18,
-    0x5c57, 0x53f7, 0x5c17, 0x64b8, 0x7dba, 0x8e7c, 0x973e, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x86bd, 0x6e5d, 0x973e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x96dd, 0x7579, 0x4355,
-    0x21b4, 0x10f5, 0x2117, 0x633a, 0x949b, 0x8c9c, 0x633c, 0x319a, 0x0898, 0x1115, 0x3ab4, 0x64d8,
-    0x8ebd, 0x9f3e, 0x9f3e, 0x9f5e, 0x7ebd, 0x763c, 0x9e9d, 0x663c, 0x973e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x96fd,
-    0x6cf8, 0x5b93, 0x7c17, 0x8c7c, 0x7c1e, 0x5afe, 0x319d, 0x087d, 0x109d, 0x31be, 0x73be, 0xb5bf,
-    0xb59f, 0x5adf, 0x109d, 0x0037, 0x2193, 0x64b7, 0x971e, 0x86bd, 0x867c, 0xe77e, 0xef9e, 0xbefd,
-    0x661c, 0x973e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f3e, 0x9f3e, 0x7dba, 0x5bb4, 0xad78, 0xef7e, 0xffff, 0xffff, 0xffff, 0xffff, 0xef5f, 0xce7f,
-    0x8c5f, 0x39de, 0x085e, 0x109e, 0x529e, 0xbdff, 0xc61f, 0x4a7f, 0x085d, 0x0876, 0x43f7, 0x969d,
-    0xe79e, 0xe75d, 0xe75d, 0xef7e, 0xc73e, 0x661c, 0x973e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x971e, 0x5c56, 0x7c15, 0xe73d, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xef7f, 0xad7f, 0x39df, 0x001e, 0x10be, 0x7bff, 0xe73f,
-    0x9cdf, 0x2a7d, 0x8e1c, 0xb6de, 0xcefc, 0xe75d, 0xe75d, 0xe75d, 0xef7e, 0xcf3e, 0x6e3c, 0x973e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x96fe, 0x5394, 0x94d7, 0xf7bf, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xef5f,
-    0x843f, 0x109f, 0x003f, 0x52df, 0xa67d, 0xa6bd, 0x8e5f, 0x2cff, 0x75de, 0xcefc, 0xe75d, 0xe75d,
-    0xe75d, 0xe77e, 0xcf3e, 0x6e3c, 0x973e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x971e, 0x4b73,
-    0x9d18, 0xffdf, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffdf, 0xbdff, 0x297f, 0x22bd, 0x9e7d, 0xcf1e, 0xaebd, 0x455f,
-    0x24ff, 0x7dfe, 0xd71c, 0xe75d, 0xe75d, 0xe75d, 0xe77e, 0xc71e, 0x6e3c, 0x9f3e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f3e, 0x5c15, 0x8cf7, 0xf7df, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xf7df, 0x8e1d, 0x9e7d,
-    0x65df, 0x455f, 0xaebd, 0xaebd, 0x3d3f, 0x2cff, 0x965d, 0xd71d, 0xe75d, 0xe75d, 0xe75d, 0xef7e,
-    0xb6fd, 0x6e5d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x7559, 0x73f4, 0xdfbf, 0xf7ff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xf7df, 0x967d, 0xb6fd, 0xaebd, 0x455f, 0x24ff, 0x4d7f, 0xb6dd, 0xa69d, 0x351f, 0x351f, 0xb6bc,
-    0xdf3d, 0xe75d, 0xe77d, 0xe77d, 0xef9e, 0x969d, 0x7e9d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa67b, 0xc411, 0xc3af, 0xc3af, 0xc3af, 0xc3af,
-    0xc3af, 0xc3af, 0xc3af, 0xbcb4, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x96fd, 0x42d1, 0xd79e, 0xdfdf,
-    0xf7ff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xf7df, 0x865c, 0xc71e, 0xaebd, 0xa69c, 0x9e7c, 0x455f, 0x24ff, 0x65be,
-    0xbedc, 0x8e3d, 0x2cff, 0x455f, 0xcefc, 0xe75d, 0xef7e, 0xef9e, 0xf7bf, 0xc73e, 0x5ddb, 0x971e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xad97, 0xf041, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xe8a3, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e,
-    0x5c35, 0xa5b9, 0xd7df, 0xd7df, 0xf7ff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xefbf, 0x865c, 0xcf3e, 0xaebc, 0xa69c, 0xa69c,
-    0xa69c, 0x967d, 0x353f, 0x24ff, 0x863d, 0xc6fc, 0x5dbe, 0x24ff, 0xa69d, 0xef9e, 0xe79f, 0xa6bd,
-    0x6e1c, 0x767d, 0x973e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xd9c7, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xae19, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x3a70, 0xd7bf, 0xd7df, 0xd7df, 0xefff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffdf, 0xd77e, 0x865c, 0xcf3e,
-    0xa69c, 0xa69c, 0xa69c, 0xa69c, 0xa69c, 0xa69c, 0x863d, 0x2d1f, 0x351f, 0xb6bd, 0xcf3e, 0xc73f,
-    0xbf1e, 0x867d, 0x765c, 0x8efe, 0x9f5e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xe104, 0xf000, 0xe800, 0x5000, 0x4800, 0x4800, 0x4800,
-    0x4800, 0x8800, 0xf000, 0xf000, 0xc3f0, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x7dba, 0x7415, 0xdfdf, 0xd7df, 0xd7df,
-    0xdfdf, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xbdd7,
-    0x4a49, 0x1987, 0x7431, 0xa67c, 0xa69c, 0xa69c, 0xa69c, 0xa69c, 0xa69c, 0xa69c, 0xa6bc, 0x863e,
-    0xa6bf, 0xbf1e, 0x969d, 0x969d, 0x9dfa, 0x5bf5, 0x971e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xd26a, 0xf000, 0xf000,
-    0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2000, 0xf000, 0xf000, 0xe104, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x53d4,
-    0xb67b, 0xd7bf, 0xd7df, 0xd7df, 0xd7df, 0xe7df, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xce79, 0x18e3, 0x84b3, 0x8cf4, 0x2145, 0x5baf, 0xa6bc, 0xa69c, 0xa69c, 0xa69c,
-    0xaebc, 0xc71d, 0xbf1d, 0x9e9d, 0x967d, 0xbf1e, 0xf7df, 0xffff, 0xffff, 0xd69c, 0x5bd5, 0x971e,
-    0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9efd, 0xa69b, 0xa65a, 0xa67b, 0xa6bc, 0x9efd, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9edc, 0xa67b, 0xa65a, 0xa69b, 0x9edc, 0x9f3e,
-    0x9f5e, 0xb515, 0xf000, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb000, 0xf000,
-    0xf000, 0xb556, 0xa69c, 0xa67b, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f3e, 0x3ab1, 0xd7bf, 0xd7df, 0xd7df, 0xd7df, 0xd7df, 0xd7df, 0xe7df, 0xf7ff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xf7df, 0x5b6e, 0x6baf, 0xaebd, 0xa69c, 0x7cd4, 0x2166,
-    0xa69c, 0xae9c, 0xbefd, 0xc71d, 0xa65c, 0x965c, 0xb6fd, 0xefbf, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffdf, 0xc63b, 0x6456, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9efd, 0xa6bc, 0xa67b, 0xae19,
-    0xad97, 0xc3af, 0xd2ab, 0xd9a7, 0xe8a2, 0xf020, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf020, 0xe145, 0xd2cc, 0xb515, 0xadb8, 0xcb4e, 0xda29, 0xe145, 0xe882, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xe862, 0xd9c7, 0xf000, 0xf000, 0xe800, 0x0800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x5000, 0xf000, 0xf000, 0xd26a, 0xb576, 0xf000, 0xe186, 0xc411, 0xae19, 0xa65a,
-    0xa65a, 0xa65a, 0xa69c, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x4ad1, 0xdfdf, 0xd7df, 0xd7df, 0xd7df,
-    0xdfdf, 0xd7df, 0xd7df, 0xdfdf, 0xe7df, 0xf7ff, 0xf7ff, 0xf7ff, 0xdf9f, 0x865c, 0x5bb0, 0x5b2d,
-    0xaebd, 0xa69c, 0x6432, 0x2987, 0xbedd, 0xae7d, 0x6cfb, 0x2ab9, 0x5211, 0xf73d, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffdf, 0x9496, 0x94b7, 0x8476, 0x8e9c, 0x9f3e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb576, 0xcb6e, 0xcb2d, 0xcb0c, 0xd2cb,
-    0xd2ab, 0xd26a, 0xda29, 0xda08, 0xd9c7, 0xd9a6, 0xe166, 0xe145, 0xe104, 0xe8e3, 0xe8a2, 0xe862,
-    0xf041, 0xf000, 0xf000, 0xe8c3, 0xe104, 0xe861, 0xf000, 0xf041, 0xe882, 0xe8c3, 0xe145, 0xd9a7,
-    0xe186, 0xe166, 0xd2ab, 0xd249, 0xda08, 0xd9c7, 0xe166, 0xe125, 0xe8e3, 0xe882, 0xf041, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0x5000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xe000, 0xf000, 0xf041, 0xb535, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xe104, 0xadb7, 0x9f3e, 0x971e, 0x5312,
-    0xdfdf, 0xd7df, 0xd7df, 0xd7df, 0xd7df, 0xd79f, 0xcf1e, 0xc6fd, 0xcf5e, 0xd7bf, 0xdfdf, 0xc77f,
-    0x7e3c, 0xc71d, 0x9dd9, 0x2125, 0x5b4d, 0x536e, 0x10c4, 0x3b13, 0x3b5b, 0x093a, 0x0039, 0x0016,
-    0x9a6e, 0xffdf, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xdefd, 0x002b, 0x108c, 0xb5b9,
-    0x6cf8, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc3af,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xe000, 0xc000, 0xb000, 0xb000, 0xb000, 0xb000, 0xb800, 0xc000, 0xd000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xd800, 0xc000, 0xb000, 0xb000, 0xb000, 0xb000, 0xb800, 0xc000,
-    0xe000, 0xf000, 0xf000, 0xf000, 0xf000, 0xa800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9800,
-    0xf000, 0xf000, 0xcb6e, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xe8a3, 0x9f1d, 0x971e, 0x5312, 0xdfdf, 0xd7df, 0xd7df, 0xc71e, 0x7458, 0x3ab6, 0x2256, 0x19f5,
-    0x2a35, 0x5356, 0x8d7a, 0x7e1c, 0xc71e, 0xb6bc, 0xbedc, 0x9599, 0x19cd, 0x08ad, 0x0096, 0x007c,
-    0x001d, 0x001e, 0x001e, 0x0837, 0xb968, 0xff1c, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xdedc, 0x002b, 0x086b, 0xc65b, 0x5373, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xc3af, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xe800, 0xe000, 0xd800, 0xd000, 0xd000, 0xc800, 0xc000, 0xc000, 0xe800,
-    0xf000, 0xd000, 0xc000, 0xc800, 0xd000, 0xe000, 0xe800, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xe800, 0xe000, 0xd800, 0xc800, 0xc000, 0xb800, 0xb000, 0xa000, 0x9800,
-    0x9000, 0x8800, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0800, 0x5000, 0xc000, 0xf000, 0xf000, 0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2000, 0x7000, 0xe000, 0xf000, 0xf000, 0x1800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x4800, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xa800, 0x8000, 0x9000,
-    0x9800, 0xa000, 0xc800, 0xf000, 0xf000, 0xbcb3, 0x9f3e, 0x4af1, 0xdfdf, 0xd7df, 0xb67c, 0x2a54,
-    0x02da, 0x03be, 0x03ff, 0x03ff, 0x03de, 0x037c, 0x0257, 0x4295, 0x9d9a, 0x859b, 0x439a, 0x1179,
-    0x009b, 0x001e, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x081a, 0xa865, 0xfaaa, 0xfe79, 0xff9e,
-    0xffdf, 0xffff, 0xffff, 0xffff, 0xffdf, 0x6b73, 0x6b33, 0xf4f4, 0x81c8, 0x9d94, 0x9f1e, 0x9f3e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc3f0, 0xf000, 0xf000, 0x7000, 0x2800,
-    0x2000, 0x2000, 0x1800, 0x1000, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xb800, 0xf000, 0x3800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb800,
-    0xc800, 0x3800, 0x3000, 0x2000, 0x1800, 0x1000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xe800, 0x9800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xa000, 0xf000, 0xf000, 0x4000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2000,
-    0xe000, 0xf000, 0x6000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xe800, 0xf000, 0xf000, 0xf000,
-    0xe800, 0x1000, 0x0000, 0x0000, 0x0000, 0x0000, 0xa800, 0xf000, 0xf000, 0xb536, 0x9f3e, 0x42b1,
-    0xdfdf, 0xcf7e, 0x2a54, 0x037c, 0x03ff, 0x047f, 0x047f, 0x03ff, 0x041f, 0x04df, 0x04bf, 0x0bdd,
-    0x1a56, 0x08d4, 0x0018, 0x001c, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001d,
-    0x686a, 0xe841, 0xf8a2, 0xf9c7, 0xfa8a, 0xfb0c, 0xfb4d, 0xfb6d, 0xfb4d, 0xfaeb, 0xf9e7, 0xd961,
-    0xd480, 0xdd21, 0xad0c, 0x9e59, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc411,
-    0xf000, 0xf000, 0x5800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc000, 0xf000, 0x3800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xb800, 0x8800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xe800, 0x9800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0800, 0xe000, 0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x5800, 0xf000, 0xb800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xa800, 0xf000, 0xf000, 0xf000, 0x9800, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0xf000, 0xf000,
-    0xe8c3, 0x9f3e, 0x9f3e, 0x4b73, 0xbebc, 0x9d9a, 0x0257, 0x03ff, 0x041f, 0x04ff, 0x051f, 0x04bf,
-    0x041f, 0x04df, 0x051f, 0x04ff, 0x0c9f, 0x2bba, 0x1114, 0x001b, 0x001f, 0x001f, 0x001f, 0x001f,
-    0x001f, 0x001f, 0x001f, 0x001f, 0x1854, 0xb084, 0xe841, 0xf820, 0xf820, 0xf820, 0xf820, 0xf820,
-    0xf820, 0xf800, 0xd1a0, 0xe540, 0xfe80, 0xfea0, 0xf640, 0xd563, 0xad0d, 0x9e9a, 0x9f3e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xbc52, 0xf000, 0xf000, 0x6000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc800,
-    0xf000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb800, 0x6000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9800, 0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xe800, 0xf000, 0x2000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x5800, 0xf000, 0xf000, 0xf000, 0x3800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x7000, 0xf000, 0xf000, 0xcb6e, 0x9f5e, 0x9f3e, 0x7db9, 0x7435, 0x6bf6, 0x033b, 0x043f,
-    0x041f, 0x049f, 0x051f, 0x051f, 0x04bf, 0x043f, 0x04df, 0x051f, 0x04ff, 0x04bf, 0x247e, 0x2256,
-    0x0017, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001d, 0x406e, 0xc084, 0xd882,
-    0xe862, 0xf062, 0xf041, 0xe841, 0xe042, 0xc9c1, 0xe560, 0xfea0, 0xfe80, 0xfe80, 0xfe80, 0xfe80,
-    0xf620, 0xc4c4, 0x9db5, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbc93, 0xf000, 0xf000, 0x6800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xd000, 0xf000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb800,
-    0x5000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7800, 0xf000, 0x4000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xd000, 0xf000, 0x7000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0xf000, 0xf000, 0xc000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xd800, 0xf000, 0xf000, 0xa63a, 0x9f5e, 0x9f3e, 0x971e,
-    0x3a70, 0x5314, 0x039c, 0x051f, 0x051f, 0x045f, 0x049f, 0x051f, 0x051f, 0x04df, 0x043f, 0x04df,
-    0x051f, 0x051f, 0x04df, 0x1c9f, 0x33b9, 0x0075, 0x001e, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f,
-    0x001f, 0x001c, 0x384f, 0xb085, 0xd083, 0xd083, 0xd083, 0xd083, 0xc142, 0xdce0, 0xeda0, 0xfe80,
-    0xfe80, 0xfe80, 0xfe80, 0xfe80, 0xfe80, 0xfe80, 0xcce2, 0x9d73, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb4d4,
-    0xf000, 0xf000, 0xb000, 0x7800, 0x7000, 0x7000, 0x6000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x2800, 0x6800, 0x6000, 0x6800, 0x6800, 0x6800, 0x5800, 0xe000, 0xf000, 0x3000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xb800, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6800, 0xe000, 0xf000, 0xf000, 0xf000, 0xa000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0xe800, 0xd800, 0x5000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x6000, 0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7000, 0xe800, 0xb800,
-    0x1000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc000, 0xf000, 0xb800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xb800, 0xf000, 0x5800, 0x0000, 0x0000, 0x0000, 0x0000, 0x5800, 0xf000, 0xf000, 0xd249,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x6cf8, 0x086c, 0x02fa, 0x051f, 0x053f, 0x051f, 0x045f, 0x049f,
-    0x051f, 0x051f, 0x04ff, 0x043f, 0x04bf, 0x051f, 0x051f, 0x04ff, 0x0c9f, 0x445b, 0x0074, 0x001f,
-    0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001e, 0x1834, 0x7088, 0xb084, 0xc8a3, 0xc8a4,
-    0xc320, 0xeda0, 0xed80, 0xf5e0, 0xfe80, 0xfe80, 0xfe80, 0xfe80, 0xfe80, 0xfe80, 0xfe80, 0xc4a3,
-    0x9e38, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xb535, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xd800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x6000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb800, 0xc800, 0xc800, 0xd000, 0xd800,
-    0x1000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x2000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0xf000, 0xf000,
-    0xc000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6000, 0xf000, 0x4800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x7800, 0xf000, 0xf000, 0x6800, 0x0000, 0x0000, 0x0000, 0x0000, 0xc000, 0xf000, 0xf000,
-    0x1000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6000, 0xd800, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xc000, 0xf000, 0xf000, 0xad97, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x971e, 0x3251, 0x0194, 0x047f,
-    0x053f, 0x053f, 0x051f, 0x047f, 0x04bf, 0x051f, 0x051f, 0x04ff, 0x04bf, 0x051f, 0x051f, 0x051f,
-    0x04ff, 0x14df, 0x3337, 0x001a, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f,
-    0x001c, 0x1034, 0x2850, 0x306f, 0xc3e0, 0xeda0, 0xed80, 0xed80, 0xf5c0, 0xfe60, 0xfe80, 0xfe80,
-    0xfe80, 0xfe60, 0xfe80, 0xf640, 0xac8a, 0x9f1d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb577, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xe800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6800, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb800,
-    0xf000, 0xf000, 0xf000, 0xd800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xb800, 0xf000, 0xf000, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x1800, 0xf000, 0xf000, 0xe000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6800, 0xf000, 0x4800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7000, 0xf000, 0xf000, 0x8800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xc800, 0xf000, 0xf000, 0x5000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1000, 0x6800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x4000, 0xf000, 0xf000, 0xe166, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e,
-    0x9f3e, 0x861b, 0x088d, 0x031a, 0x049f, 0x053f, 0x053f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f,
-    0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x04ff, 0x3c9c, 0x0016, 0x001f, 0x001f, 0x001f, 0x001f,
-    0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001d, 0xbb61, 0xeda0, 0xf5a0, 0xed80,
-    0xed80, 0xed80, 0xf5c0, 0xf620, 0xfe40, 0xfe60, 0xfe60, 0xfe80, 0xc462, 0x9e9a, 0x9f3e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9edc,
-    0xb577, 0xb577, 0xb577, 0xb577, 0xe145, 0xf000, 0xf000, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x5800, 0xc000, 0xc000, 0xc000, 0xc000, 0xe800, 0xf000, 0xf000, 0xf000, 0x3000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xb800, 0xf000, 0xf000, 0xf000, 0xb800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x3000, 0xe800, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x6000, 0xf000, 0xf000, 0xf000, 0xa000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1000, 0xf000, 0xf000, 0xd800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x8800, 0xf000, 0x4800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6800, 0xf000, 0xf000,
-    0x7800, 0x0000, 0x0000, 0x0000, 0x0000, 0xe000, 0xf000, 0xf000, 0x9000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb800, 0xf000, 0xf000, 0xb4f4, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f3e, 0x863a, 0x5412, 0x21a9, 0x10e9, 0x00ce, 0x037d, 0x049f, 0x053f, 0x053f,
-    0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x1c5b, 0x0016,
-    0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001e,
-    0x69a9, 0xed60, 0xed60, 0xdce0, 0xdce0, 0xdcc0, 0xdcc0, 0xd4a0, 0xd4a0, 0xd4a0, 0xd480, 0xcc40,
-    0xbb81, 0x9dd6, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xda29, 0xf000, 0xf000, 0x1800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc000, 0xf000, 0xf000,
-    0xf000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb800, 0xf000, 0xf000, 0xf000, 0x8800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x6800, 0xf000, 0x5800, 0x0000, 0x0000, 0x0000, 0x0000, 0x1000,
-    0xe800, 0xf000, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xf000, 0xf000,
-    0x8000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc000, 0xf000, 0x4800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x6000, 0xf000, 0xe800, 0x3000, 0x0000, 0x0000, 0x0000, 0x2800, 0xf000, 0xf000, 0xf000,
-    0xd800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4000, 0xf000,
-    0xf000, 0xe124, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f3e, 0x326b, 0x0002, 0x1947, 0x6494, 0x96fd, 0x5c76,
-    0x00cf, 0x031b, 0x049f, 0x053f, 0x053f, 0x053f, 0x053f, 0x053f, 0x051f, 0x051f, 0x051f, 0x051f,
-    0x051f, 0x049d, 0x0193, 0x001c, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f,
-    0x001f, 0x001e, 0x001b, 0x0018, 0x1056, 0xb342, 0xe540, 0xcc20, 0xcc20, 0xcc20, 0xcc40, 0xd460,
-    0xcc40, 0xab63, 0x9caf, 0x9e7a, 0x9efd, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xd2ab, 0xf000, 0xf000, 0x2800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xc800, 0xf000, 0xf000, 0xf000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb800,
-    0xf000, 0xf000, 0xf000, 0x5800, 0x0000, 0x0000, 0x0000, 0x0000, 0x9800, 0xf000, 0xb000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xa000, 0xf000, 0xf000, 0xa800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x4800, 0x2800, 0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0xf000, 0xf000, 0x4800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0x4000, 0x1000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x9000, 0xf000, 0xf000, 0xf000, 0xf000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0xb000, 0xf000, 0xf000, 0xbc93, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x64d5, 0x5c73,
-    0x971e, 0x9f3e, 0x971d, 0x5c32, 0x10c9, 0x008d, 0x0131, 0x09d4, 0x01f4, 0x09d4, 0x0993, 0x0973,
-    0x0952, 0x0952, 0x0912, 0x0912, 0x08f1, 0x0071, 0x0017, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f,
-    0x001f, 0x001f, 0x001f, 0x001d, 0x001a, 0x0019, 0x0018, 0x0018, 0x0018, 0x2892, 0xb341, 0xdce0,
-    0xdd00, 0xdcc0, 0xcc41, 0xb3e5, 0x9cf0, 0x9edc, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xcb0c, 0xf000, 0xf000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd000, 0xf000, 0xf000, 0xf000, 0x4000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xb800, 0xf000, 0xf000, 0xf000, 0x2800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xc800, 0xf000, 0xf000, 0x1800, 0x0000, 0x0000, 0x0000, 0x0000, 0x4800, 0xf000, 0xf000, 0xa800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800,
-    0xc000, 0xf000, 0xf000, 0x5000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x3000, 0xf000, 0xf000, 0xf020, 0xf000, 0xf000, 0x8800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3800, 0xf000, 0xf000, 0xe104, 0x9f3e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x9f3e, 0x8ebc, 0x3acd, 0x1967, 0x861a, 0x7559, 0x21b2, 0x0034,
-    0x0017, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0019,
-    0x001a, 0x001b, 0x001c, 0x001c, 0x001b, 0x001a, 0x0019, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018,
-    0x0034, 0x1931, 0x7494, 0x9d54, 0x9d74, 0x9db5, 0x9e59, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xcb4e, 0xf000, 0xf000, 0x3800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x1800, 0x2800, 0x3800, 0x4800, 0xe000, 0xf000, 0xf000,
-    0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb000, 0xf000, 0xf000, 0xe800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0800, 0xe800, 0xf000, 0xf000, 0x6800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xe000, 0xf000, 0xb000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x1800, 0xb800, 0xf000, 0xf000, 0xf000, 0x5000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4800, 0xe000, 0xf000, 0xf000, 0xcb6e, 0xf000,
-    0xf000, 0xd800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb000, 0xf000, 0xf000,
-    0xbc72, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x7dd8, 0x1967, 0x21c9, 0x8e9c,
-    0x9f3e, 0x9f3e, 0x96fe, 0x64d8, 0x21d2, 0x0874, 0x0016, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018,
-    0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018,
-    0x0018, 0x0015, 0x08b3, 0x2a53, 0x64d8, 0x96fd, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xc38f, 0xf000, 0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8800, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb000,
-    0xf000, 0xf000, 0xb800, 0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0xf000, 0xf000, 0xf000, 0xb000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8800, 0xf000, 0xb000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0800, 0x2800, 0x3800, 0x6000, 0x9800, 0xe800, 0xf000, 0xf000, 0xf000, 0xf000, 0x5000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0x2800, 0x4800, 0x7000, 0xc000, 0xf000, 0xf000,
-    0xf000, 0xd9a7, 0x9f1e, 0xd9e8, 0xf000, 0xf000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x4800, 0xf000, 0xf000, 0xe104, 0x9f1e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x971d,
-    0x328c, 0x21a8, 0x8e9c, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x971e, 0x75ba, 0x4355, 0x1952,
-    0x0894, 0x0035, 0x0017, 0x0017, 0x0017, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018,
-    0x0017, 0x0016, 0x0054, 0x10f2, 0x2a53, 0x5c57, 0x865c, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc3af, 0xf000, 0xf000, 0x4800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x8800, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0x4000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xb000, 0xf000, 0xf000, 0x8800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2800, 0xf000, 0xb000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0x5800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9800, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xda08, 0x9efd, 0x9f5e, 0xcb2d, 0xf000, 0xf000, 0x3800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x6000, 0xf000, 0xf000, 0xbc31, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x96dd, 0x8e9b, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f3e, 0x9f3e, 0x9f3e, 0x96fd, 0x7dda, 0x5c77, 0x4334, 0x2a33, 0x1992, 0x1132, 0x10f2, 0x10f2,
-    0x10f2, 0x10f2, 0x1932, 0x21d2, 0x3ad3, 0x53f6, 0x7579, 0x8e9c, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc3d0, 0xf000, 0xf000, 0x4800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x8000, 0xf020, 0xe8a2, 0xd28b, 0xc3af, 0xbc52, 0xd249, 0xf000,
-    0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb000, 0xf000, 0xf000, 0x5800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0xa800, 0xb800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xe882, 0xcb2d, 0xc3af, 0xf000, 0xf000, 0x5800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x9000, 0xf000, 0xf000, 0xf000, 0xf000, 0xe145, 0xbc72, 0x9f3e, 0x9f5e, 0x9f5e, 0xcb2d,
-    0xf000, 0xf000, 0x3800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6800, 0xf000, 0xf000, 0xbcb3,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f3e,
-    0x971e, 0x96dd, 0x8e9c, 0x8e7c, 0x8e9c, 0x8e9c, 0x96fd, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f3e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xc411, 0xf000, 0xf000, 0x5000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8000, 0xf000, 0xf000, 0xb556,
-    0x9f5e, 0x9f5e, 0xc3af, 0xf000, 0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb800,
-    0xf000, 0xf000, 0x2800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4000, 0xb800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x2000, 0xf000, 0xf000, 0xe166, 0xad97, 0x9f3e, 0x9f5e, 0xbc72, 0xf000, 0xf000, 0x5800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7800, 0xf000, 0xf000, 0xcb4e, 0xa63a, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xcb4e, 0xf000, 0xf000, 0x3800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x6800, 0xf000, 0xf000, 0xbcd4, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x9f3d, 0xb737, 0xc731, 0xdf2b, 0xef27, 0xef25,
-    0xf723, 0xff21, 0xff20, 0xff20, 0xef26, 0xdf29, 0xbf33, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc411, 0xf000, 0xf000, 0x5000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x7800, 0xf000, 0xf000, 0xb535, 0x9f5e, 0x9f5e, 0xc3af, 0xf000, 0xf000, 0x4000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xc800, 0xf000, 0xe800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5800,
-    0x7800, 0x8000, 0x8800, 0x9000, 0x5800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xf000, 0xf000, 0xd9c7, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xbc93, 0xf000, 0xf000, 0x6000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6000, 0xf000, 0xf000,
-    0xbc93, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xcb6e, 0xf000, 0xf000, 0x3800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x7000, 0xf000, 0xf000, 0xb4f5, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xaf3a, 0xc732, 0xdf2b, 0xe727, 0xff21, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xf724,
-    0xb736, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc411, 0xf000, 0xf000, 0x5000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x7000, 0xf000, 0xf000, 0xb4f4, 0x9f5e, 0x9f5e, 0xc3af, 0xf000,
-    0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc800, 0xf000, 0xb800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xd800, 0xf000, 0xf000, 0xf000, 0xf000, 0xd000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xf000, 0xf000,
-    0xe186, 0x9f5e, 0x9f5e, 0x9f5e, 0xbcd4, 0xf000, 0xf000, 0x6800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x5800, 0xf000, 0xf000, 0xbc31, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xcb6e,
-    0xf000, 0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7800, 0xf000, 0xf000, 0xb535,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbf33, 0xdf2b, 0xf723, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xf6c0, 0xd5e0, 0xbd20, 0xb500, 0xb500, 0xb520,
-    0xd5e0, 0xff00, 0xff20, 0xff20, 0xff20, 0xc731, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xc431, 0xf000, 0xf000, 0x5800, 0x0000, 0x0000, 0x0000, 0x0000, 0x6800, 0xf000, 0xf000, 0xbc93,
-    0x9f5e, 0x9f5e, 0xc3af, 0xf000, 0xf000, 0x4800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xa800,
-    0xd800, 0x7800, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0xe000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0xe000, 0xf000, 0xe104, 0x9f5e, 0x9f5e, 0x9f5e, 0xb4f4, 0xf000, 0xf000, 0x7000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4800, 0xf000, 0xf000, 0xc3d0, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xc38f, 0xf000, 0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x8000, 0xf000, 0xf000, 0xb556, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xaf3a, 0xc733, 0xcf2f, 0xdf2b, 0xef26, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xee80, 0xb520, 0x83a0, 0x5240, 0x20e0, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1080, 0x6b20, 0xe640, 0xff20, 0xff20, 0xbf35, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbc73, 0xf000, 0xf000, 0x6000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x5800, 0xf000, 0xf041, 0xbc93, 0x9f5e, 0x9f5e, 0xc3af, 0xf000, 0xf000, 0x4800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6800,
-    0xf000, 0xf000, 0xe186, 0xf000, 0xf000, 0x8000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0x4800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc800, 0xf000, 0xf020, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xb515, 0xf000, 0xf000, 0x7800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0xf000, 0xf000,
-    0xcaec, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc3af, 0xf000, 0xf000, 0xc800, 0xb000,
-    0xa800, 0xa000, 0x9800, 0x9000, 0xc800, 0xf000, 0xf000, 0xadd8, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xaf3a, 0xc732, 0xbf33, 0xaf39, 0xc731, 0xdf2a, 0xf724, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xace0, 0x62a0, 0x2100, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0860,
-    0xcdc0, 0xff20, 0xf723, 0x9f5d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbc93, 0xf000, 0xf000, 0x6000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x4800, 0xf000, 0xf000, 0xc3af, 0x9f5e, 0x9f5e, 0xc3af, 0xf000,
-    0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x6800, 0xf000, 0xf000, 0xbc52, 0xf020, 0xf000, 0xe000, 0x5800, 0x7800,
-    0xa800, 0xd800, 0xf000, 0xe800, 0x8800, 0x8800, 0x9000, 0xa000, 0xa800, 0xb000, 0xe000, 0xf000,
-    0xf000, 0x9f3e, 0x9f5e, 0x9f5e, 0xb556, 0xf000, 0xf000, 0xc000, 0x8800, 0x8800, 0x9800, 0xa000,
-    0xb000, 0xc000, 0xf000, 0xf000, 0xd2ab, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbcb3,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xe882, 0x9f1e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xaf39, 0xc732, 0xdf2a, 0xf723, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff00, 0xd600, 0xb500, 0xbd40, 0xf6e0, 0x5260, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x4a20, 0xff20, 0xff20, 0xbf34, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xbc72, 0xf000, 0xf000, 0x6000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0xf000, 0xf000, 0xcaec,
-    0x9f5e, 0x9f5e, 0xcb4e, 0xf000, 0xf000, 0x3800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6800, 0xf000, 0xf000, 0xbcd4, 0xd2ab,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0x9f3e, 0x9f5e, 0x9f5e, 0xb556, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xd28b, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbcb3, 0xe8c3, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xe882, 0xb535, 0xaf39, 0xc732, 0xdf2a, 0xf723, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff00, 0xcdc0, 0x9420, 0x5a80, 0x2940, 0x0020, 0x0000, 0x0000,
-    0x0000, 0x10a0, 0x5240, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1080,
-    0x3980, 0x18a0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0xf6e0, 0xff20, 0xe728,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb4f5, 0xf000, 0xf000, 0xc000, 0x8000, 0x8800, 0x8800, 0xa800,
-    0xc800, 0xf000, 0xf000, 0xd26a, 0x9f5e, 0x9f5e, 0xd24a, 0xf000, 0xf000, 0x2800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6800,
-    0xf000, 0xf000, 0xb4d4, 0xa69c, 0xe8c3, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0x9f3e, 0x9f5e, 0x9f5e,
-    0xb556, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xd28b, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa67b, 0xa65a,
-    0xa65a, 0xa65a, 0xa63a, 0xad97, 0xadd8, 0xcecf, 0xf722, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xde40, 0xb500, 0xff00, 0xc580, 0x8bc0, 0x4a00, 0x1060, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x9c60, 0xff20, 0xff20, 0xff00, 0x5260, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xc560, 0xff20, 0xff21, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa6bc, 0xf020, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xd26a, 0x9f5e, 0x9f3e, 0xe862, 0xf000,
-    0xf000, 0x7800, 0x7800, 0x8800, 0x8800, 0x7800, 0x6800, 0x6000, 0x5000, 0x4800, 0x4800, 0x5000,
-    0x5000, 0x5800, 0x5800, 0xa000, 0xf000, 0xf000, 0xb4f4, 0x9f5e, 0xa63a, 0xd249, 0xcb6e, 0xb576,
-    0xa69b, 0xadb7, 0xb577, 0xb577, 0xb577, 0xb577, 0xadb8, 0xa65a, 0x9edd, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa6dc, 0xb577, 0xb577, 0xb577, 0xb577, 0xb577, 0xadf8, 0xa69b,
-    0x9f1e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xaf38, 0xbf33, 0xe729, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xde20, 0x9c60, 0x5aa0, 0x20e0, 0x0000, 0x0000, 0x0860, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x3980, 0x1080, 0x0000, 0x0000, 0x0000, 0x0000, 0x9400, 0xff20, 0xff20, 0xff20, 0xe640, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8c00, 0xff20, 0xff20, 0xaf39, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xb535, 0xe8a2, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf020, 0xe8e3, 0xd9a7, 0xc411,
-    0x9f5e, 0x9efd, 0xe145, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xb4f4, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xaf38, 0xc732, 0xdf2b, 0xef25, 0xff21, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xcdc0, 0x18c0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4a20, 0x2100, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0820, 0xf6c0, 0x5a80, 0x0000, 0x0000, 0x0000, 0x0000, 0x5a60, 0xff20,
-    0xff20, 0xff20, 0xff20, 0x2100, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5240, 0xff20, 0xff20,
-    0xc733, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f1d, 0xad97, 0xb577, 0xb577, 0xad97, 0xa69b,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9edc, 0xd9e8, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xb4f5, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbf34, 0xd72e, 0xe727, 0xff22, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xf6c0, 0x0020, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xe660, 0xf6e0, 0x1080, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbd40, 0x9c40, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x2100, 0xff20, 0xff20, 0xff20, 0xff20, 0x5a80, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x20e0, 0xff20, 0xff20, 0xd72c, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xad97, 0xbcb3, 0xb577, 0xb577, 0xb535, 0xbcd4, 0xbc72, 0xc411, 0xc3af, 0xc3af, 0xc3af, 0xc3af,
-    0xbc52, 0xbc93, 0xbc93, 0xbc93, 0xbc93, 0xbc93, 0xa67b, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa73c, 0xef26, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xe640, 0xace0, 0x7b80, 0x4a20, 0x20e0, 0x0020, 0x3980,
-    0xc580, 0x41c0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0020, 0x41e0, 0x7320, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xbd40, 0xff20, 0x4a20, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x9440, 0xd5e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xee80, 0xff20, 0xff20, 0xff20, 0x9420,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xeea0, 0xff20, 0xef27, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xd72e, 0xff20, 0xff20, 0xff20, 0xf6e0, 0xc580, 0x9420, 0x5260, 0x18c0, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0840, 0x6b00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x18a0, 0xff20, 0xff20, 0x20e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x9420, 0xff20, 0x8be0, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x9420, 0xff20, 0x1080, 0x0000, 0x0000, 0x0000, 0x0000, 0xb520,
-    0xff20, 0xff20, 0xff20, 0xbd40, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcdc0, 0xff20,
-    0xf724, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xef26, 0xff20, 0xf6c0, 0x2940, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5240, 0xb520, 0xbd20,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xde20, 0xff20, 0x5a80, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x62e0, 0xff20, 0xc580, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd5e0, 0xff20, 0x4a20, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x7b80, 0xff20, 0xff20, 0xff20, 0xcdc0, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0xb500, 0xff20, 0xff21, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xdf2a, 0xff20, 0xff20, 0x1080,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0020, 0x3180, 0x0020, 0x0000, 0x0000,
-    0x0000, 0x2100, 0xff20, 0xff20, 0x1080, 0x0000, 0x0000, 0x0000, 0x0000, 0xa4a0, 0xff20, 0x9420,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x3180, 0xff20, 0xb500, 0x0000, 0x0000, 0x0000, 0x3180, 0xbd20,
-    0xff20, 0xff20, 0x83a0, 0x0000, 0x0000, 0x0000, 0x0000, 0x4a00, 0xff20, 0xff20, 0xff20, 0xd5e0,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9c60, 0xff20, 0xff20, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xc731, 0xff20, 0xff20, 0x41e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8c00,
-    0xff20, 0xa480, 0x0000, 0x0000, 0x0000, 0x0000, 0xf6c0, 0xff20, 0x4a00, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x6b00, 0xff20, 0xcda0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0020, 0x18e0, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0840, 0x2920, 0x7b80, 0xfee0, 0xbd40, 0x0000, 0x0000, 0x0000, 0x0000, 0x18a0,
-    0xff20, 0xff20, 0xff20, 0xa4a0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbd40, 0xff20,
-    0xf722, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb736, 0xff20, 0xff20, 0xbd20, 0xb520, 0x9c60, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x62e0, 0xff20, 0xe640, 0x0000, 0x0000, 0x0000, 0x0000, 0xe660, 0xff20,
-    0x83a0, 0x0000, 0x0000, 0x0000, 0x0000, 0x3160, 0xff20, 0xf6e0, 0x0020, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7320, 0xf6e0, 0x0020,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xe640, 0xf6e0, 0xa4a0, 0x1080, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0820, 0xf6e0, 0xff20, 0xe728, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa73b, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xeea0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3160, 0xff20, 0xff00, 0x0000, 0x0000,
-    0x0000, 0x0000, 0xf6c0, 0xff20, 0xbd20, 0x0000, 0x0000, 0x0000, 0x0000, 0x0020, 0xf6e0, 0xff20,
-    0x2940, 0x0000, 0x0000, 0x0000, 0x0000, 0x0860, 0x83a0, 0x9400, 0x1080, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0840, 0xf6e0, 0x3180, 0x0000, 0x0000, 0x0000, 0x0000, 0x1080, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6ae0, 0xff20, 0xff20, 0xcf2f, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xff22, 0xff20, 0xff20, 0xff20, 0xff20, 0x2940, 0x0000, 0x0000, 0x0000, 0x0000, 0x0020,
-    0xf6e0, 0xee80, 0x0020, 0x0000, 0x0000, 0x3160, 0xff20, 0xff20, 0xee80, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0xc560, 0xff20, 0x5aa0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0860, 0xff00, 0xff20,
-    0xcdc0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbd60, 0x6b00, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2920, 0xf6c0, 0xff20, 0xff21,
-    0xa73b, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xd72d, 0xcf30, 0xcf2f, 0xff20, 0xff20, 0x6b00, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x3160, 0x0860, 0x0000, 0x0000, 0x0000, 0x0000, 0x39a0, 0xcdc0,
-    0xff20, 0x20e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x8be0, 0xff20, 0x8c00, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0xcda0, 0xff20, 0xff20, 0x4a00, 0x0000, 0x0000, 0x0000, 0x0000, 0x83a0, 0xacc0,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x41e0,
-    0xee80, 0xff20, 0xff20, 0xd72d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa73c,
-    0xff20, 0xff20, 0xace0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x41e0, 0xff20, 0x5240, 0x0000, 0x0000, 0x0000, 0x0000, 0x5240, 0xff20,
-    0xbd60, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8c00, 0xff20, 0xff20, 0x8be0, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x41c0, 0xe660, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x20e0, 0xa480, 0xff20, 0xff20, 0xff20, 0xe728, 0x9f5d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xef25, 0xff20, 0xde20, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x1080, 0x5a80, 0x1060, 0x0000, 0x0000, 0x0000, 0x0840, 0xf6e0, 0x8be0, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x18c0, 0xff20, 0xeea0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5240, 0xff20,
-    0xff20, 0xc560, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0xf6e0, 0x20e0, 0x0000, 0x0000, 0x0000,
-    0x1080, 0x39a0, 0x6b00, 0xa480, 0xd600, 0xff20, 0xff20, 0xff20, 0xff20, 0xdf2b, 0x9f5d, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xdf2a, 0xff20, 0xff20, 0x1060,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x3180, 0xf6e0, 0xff20, 0x6b00, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xbd40, 0xbd60, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xe640, 0xff20, 0x20e0, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x10a0, 0xff20, 0xff20, 0xf6e0, 0x0820, 0x0000, 0x0000, 0x0000, 0x0000, 0xbd40,
-    0x9c40, 0x8be0, 0xb520, 0xe640, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xef26,
-    0xb737, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xc731, 0xff20, 0xff20, 0x41c0, 0x0000, 0x0000, 0x0000, 0x0000, 0x20e0, 0xff20, 0xff20, 0xace0,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x7b60, 0xeea0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xacc0,
-    0xff20, 0x4a20, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd600, 0xff20, 0xff20, 0x39a0, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x7b60, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xf723, 0xd72c, 0xb737, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb736, 0xff20, 0xff20, 0x7340, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xeec0, 0xff20, 0xeea0, 0x0000, 0x0000, 0x0000, 0x0000, 0x4a20, 0xff20, 0x18e0, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x6b00, 0xff20, 0x8380, 0x0000, 0x0000, 0x0000, 0x0820, 0x5240, 0xde20,
-    0xff20, 0xff20, 0x7b60, 0x0000, 0x0000, 0x0000, 0x0000, 0x3180, 0xff20, 0xff20, 0xff21, 0xef26,
-    0xd72c, 0xc732, 0xb736, 0xa73c, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5d, 0xff20, 0xff20, 0xa480,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc560, 0xff20, 0xe680, 0x0840, 0x0000, 0x0000, 0x0000,
-    0x5a80, 0xff20, 0x4a20, 0x0000, 0x0000, 0x0000, 0x0000, 0x3180, 0xff20, 0xb4e0, 0x18c0, 0x5aa0,
-    0xacc0, 0xf6c0, 0xff20, 0xff20, 0xff20, 0xff20, 0xb520, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xeea0, 0xff20, 0xef26, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xf723, 0xff20, 0xc5a0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3180, 0x41c0, 0x0020,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xb4e0, 0xff20, 0x7b60, 0x0000, 0x0000, 0x0000, 0x0000, 0x0020,
-    0xf6c0, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff21, 0xff20, 0xeec0, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xb4e0, 0xff20, 0xff20, 0xa73c, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xe727, 0xff20, 0xeea0, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4a20, 0xff20, 0xff20, 0xacc0, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xcda0, 0xff20, 0xff20, 0xff20, 0xff20, 0xe727, 0xc732, 0xa73c,
-    0xd72e, 0xff20, 0xff20, 0x3160, 0x0000, 0x0000, 0x0000, 0x0000, 0x6b00, 0xff20, 0xff20, 0xbf35,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xd72c, 0xff20, 0xff20,
-    0x18c0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3160, 0x8be0, 0xfee0,
-    0xff20, 0xff20, 0xde40, 0x0000, 0x0000, 0x0020, 0x41e0, 0x9c60, 0xfee0, 0xff20, 0xf724, 0xc731,
-    0xa73b, 0x9f5e, 0x9f5e, 0x9f5e, 0xbf35, 0xff20, 0xff20, 0x6b00, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x3160, 0xff20, 0xff20, 0xd72d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xc731, 0xff20, 0xff20, 0x41c0, 0x0000, 0x0000, 0x0000, 0x1060, 0x39c0, 0x7340, 0xb500,
-    0xee80, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0x9400, 0xbd40, 0xf6e0, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xc732, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa73c, 0xff20, 0xff20, 0xacc0,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x2920, 0xff00, 0xff20, 0xe728, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb735, 0xff20, 0xff20, 0xa4a0, 0x8c00, 0xb500, 0xde40,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xf723, 0xe729, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xef26, 0xcf30, 0x9f5d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xef25, 0xff20, 0xe680, 0x4a20, 0x7340, 0xaca0, 0xde20, 0xff20, 0xff20, 0xff20, 0xd72d,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xaf3a, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xf724, 0xdf2b, 0xbf34, 0x9f5e,
-    0xaf38, 0xff20, 0xff20, 0xff20, 0xff21, 0xef27, 0xc731, 0xa73a, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xd72c, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xef27, 0xa73c, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0xff21, 0xff20, 0xff20, 0xff20, 0xff21, 0xf724, 0xe729, 0xcf2f, 0xbf35, 0xa73c,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xd72e, 0xbf33, 0xaf38, 0x9f5d, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbf33, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff21, 0xef26, 0xdf2a, 0xbf34, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc732, 0xc732, 0xbf35, 0xaf39, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xaf3a, 0xef26, 0xd72c, 0xc732, 0xaf38, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-];
+// image data is generated by build.rs from the PNG files in assets/
+// (see IMAGE_ASSETS in build.rs for the list and conversion options)
 
-pub static PLANT_IMG_DATA: [u16; 1800] = [
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4362,
-    0x4362, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4bc3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4bc3, 0x3b01, 0x2a40,
-    0x2a40, 0x2a60, 0x3b02, 0x4bc3, 0x4be3, 0x4bc3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4362, 0x2a20,
-    0x4bc3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4bc3, 0x32c1, 0x3260, 0x2a40,
-    0x2a40, 0x2a60, 0x2a40, 0x3281, 0x3281, 0x21c0, 0x32a1, 0x32c1, 0x2a61, 0x2a41, 0x2a40, 0x2200,
-    0x3281, 0x4bc3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x3b22, 0x3281, 0x3b22, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4bc3, 0x3b22, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x3ae1, 0x5ae4, 0x62a7, 0x5285,
-    0x3aa0, 0x2a40, 0x2a20, 0x21e0, 0x21a0, 0x1980, 0x21e0, 0x2a20, 0x2a40, 0x2a40, 0x2a40, 0x2200,
-    0x2200, 0x2a21, 0x3b02, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4bc3, 0x32a1, 0x2a40, 0x2a40, 0x2a40, 0x2a40, 0x4342, 0x3ae2, 0x32c2,
-    0x43a3, 0x3ae2, 0x3b02, 0x2a60, 0x2a21, 0x4bc3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4ba3, 0x3262, 0x9ace, 0xa2eb, 0x9aae,
-    0x21c0, 0x21a0, 0x21a0, 0x1980, 0x1980, 0x21a0, 0x2a00, 0x5265, 0x3a62, 0x2a40, 0x2a40, 0x2200,
-    0x2a20, 0x2200, 0x2200, 0x3ae2, 0x3ae2, 0x4342, 0x2a21, 0x21e0, 0x3281, 0x2a61, 0x3b02, 0x4383,
-    0x4be3, 0x4be3, 0x4be3, 0x3ae1, 0x5ac4, 0x5285, 0x3280, 0x3260, 0x2a20, 0x21e0, 0x19a0, 0x21a0,
-    0x2a40, 0x2a40, 0x2a40, 0x2a20, 0x2200, 0x2220, 0x3b02, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3,
-    0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4be3, 0x4362, 0x3b01, 0x3ae1, 0x3a62, 0x9aee, 0xa2ce, 0x6267,
-    0x1980, 0x1980, 0x1980, 0x1980, 0x21c0, 0x2200, 0x7aca, 0xbb51, 0x8acc, 0x62a6, 0x3261, 0x2200,
-    0x2a20, 0x2220, 0x2200, 0x21e0, 0x21e0, 0x2200, 0x21e0, 0x21e0, 0x21e0, 0x2200, 0x21c0, 0x2200,
-    0x4be3, 0x4be3, 0x4382, 0x4a45, 0xa2ee, 0x9acd, 0x3a03, 0x21a0, 0x21c0, 0x1980, 0x19a0, 0x21e0,
-    0x4244, 0x4263, 0x2a40, 0x2a40, 0x2200, 0x2200, 0x2200, 0x32a1, 0x3b02, 0x3b22, 0x2a21, 0x2a21,
-    0x32c1, 0x32c2, 0x4382, 0x4342, 0x32c1, 0x3260, 0x3280, 0x32a0, 0x3260, 0x2a40, 0x2a21, 0x2a20,
-    0x21c0, 0x21c0, 0x19a0, 0x19a0, 0x21c0, 0x21a0, 0x5265, 0xaaf0, 0xa2ee, 0xbb72, 0x6ac8, 0x21e0,
-    0x2200, 0x2200, 0x2200, 0x19a0, 0x19a0, 0x2200, 0x2a20, 0x2200, 0x2a40, 0x2a01, 0x2200, 0x2200,
-    0x4342, 0x3ae1, 0x32a1, 0x5285, 0x8aac, 0x6a69, 0x21c0, 0x19a0, 0x19a0, 0x19a0, 0x21e0, 0x4244,
-    0xb351, 0x9aed, 0x72c9, 0x3241, 0x2200, 0x2a20, 0x2200, 0x21e0, 0x21e0, 0x2200, 0x21e0, 0x21e0,
-    0x21e0, 0x21e0, 0x2200, 0x2200, 0x2200, 0x2200, 0x3240, 0x3280, 0x3280, 0x2a40, 0x2a40, 0x2200,
-    0x21c0, 0x19a0, 0x1980, 0x1980, 0x21a0, 0x2220, 0x7aaa, 0xa2cf, 0xab48, 0xa2cf, 0x7289, 0x21c0,
-    0x21e0, 0x21e0, 0x21e0, 0x1980, 0x19a0, 0x21a0, 0x2a20, 0x2a20, 0x6aa8, 0xa2ef, 0x2a01, 0x21e0,
-    0x2a40, 0x3a80, 0x3aa0, 0x3280, 0x2a40, 0x2a20, 0x21c0, 0x21c0, 0x1980, 0x19a0, 0x19a0, 0x2a01,
-    0xa2ef, 0xab0c, 0xb331, 0x5a66, 0x21e0, 0x2200, 0x2200, 0x21a0, 0x1980, 0x21e0, 0x2a20, 0x2200,
-    0x62a6, 0x2a01, 0x2200, 0x21e0, 0x21c0, 0x19a0, 0x21a0, 0x21c0, 0x21c0, 0x21e0, 0x2200, 0x21c0,
-    0x1980, 0x2a00, 0x3a80, 0x3aa0, 0x2a60, 0x2a20, 0x4a44, 0x7a6b, 0xa2cf, 0x7269, 0x2a01, 0x21e0,
-    0x21a0, 0x1980, 0x19a0, 0x19a0, 0x21c0, 0x21e0, 0x19a0, 0x2a21, 0xa2ef, 0xa2cd, 0x82ab, 0x19a0,
-    0x21c0, 0x2200, 0x2a20, 0x2200, 0x2200, 0x2200, 0x21a0, 0x21c0, 0x2a00, 0x2a20, 0x2a20, 0x3a43,
-    0xaaf0, 0xab0c, 0x92ad, 0x3a43, 0x21c0, 0x21c0, 0x21a0, 0x19a0, 0x21a0, 0x21c0, 0x2200, 0x4243,
-    0xab10, 0x728a, 0x21c0, 0x21a0, 0x31c2, 0x29c2, 0x39e3, 0x21c0, 0x21c0, 0x2200, 0x21c0, 0x21a0,
-    0x19a0, 0x21c0, 0x2a00, 0x3280, 0x2a40, 0x2a20, 0x2a20, 0x21a0, 0x3202, 0x29a1, 0x21e0, 0x19a0,
-    0x1980, 0x1980, 0x1980, 0x1980, 0x21c0, 0x21c0, 0x1980, 0x21e0, 0x5a87, 0x8a8c, 0x7269, 0x19a0,
-    0x21a1, 0x21a1, 0x21a0, 0x21c0, 0x21e0, 0x21a0, 0x1980, 0x21e0, 0x3260, 0x3aa0, 0x2a40, 0x2a40,
-    0x21c1, 0x5246, 0x39c3, 0x21c0, 0x19a0, 0x19a0, 0x1980, 0x1980, 0x21c0, 0x21c0, 0x19a0, 0x4a45,
-    0x92cc, 0x92cd, 0x21e0, 0x21e0, 0x92ed, 0x92cd, 0xab10, 0x5246, 0x2200, 0x2200, 0x2200, 0x2200,
-    0x21e0, 0x21c0, 0x19a0, 0x21e0, 0x2200, 0x2200, 0x21e0, 0x2a00, 0x3280, 0x2a40, 0x2a40, 0x2200,
-    0x21c0, 0x1980, 0x1980, 0x1980, 0x19a0, 0x1980, 0x1980, 0x19a0, 0x21e0, 0x21e0, 0x21e0, 0x21e0,
-    0x92cd, 0xa30f, 0x3a23, 0x2200, 0x2200, 0x2200, 0x21e0, 0x21e0, 0x21c0, 0x2200, 0x2200, 0x2200,
-    0x2200, 0x3240, 0x2a20, 0x2a20, 0x21e0, 0x21a0, 0x1980, 0x1980, 0x19a0, 0x19a0, 0x1980, 0x21e0,
-    0x21c0, 0x29e1, 0x2200, 0x2a40, 0x7ae9, 0x9aae, 0xab29, 0x8aad, 0x2a20, 0x2200, 0x2a20, 0x2a20,
-    0x2200, 0x21c0, 0x19a0, 0x21a0, 0x19a0, 0x19a0, 0x1980, 0x2a00, 0x2a40, 0x2a20, 0x2200, 0x21c0,
-    0x21e0, 0x2a20, 0x21e0, 0x21e0, 0x21c0, 0x21c0, 0x21c0, 0x21c0, 0x1980, 0x21c0, 0x2200, 0x2200,
-    0xa2cf, 0xab2a, 0x728a, 0x2a20, 0x2a20, 0x2a20, 0x2a20, 0x21e0, 0x21a0, 0x21c0, 0x19a0, 0x1980,
-    0x21c0, 0x2a20, 0x2a40, 0x2200, 0x21c0, 0x2a20, 0x2200, 0x21e0, 0x21e0, 0x21e0, 0x21c0, 0x21c0,
-    0x21a1, 0x5245, 0x2a00, 0x3280, 0x62a6, 0x82ab, 0xa2ed, 0x5226, 0x2200, 0x2200, 0x2a20, 0x3280,
-    0x3260, 0x2a20, 0x2a40, 0x2a40, 0x2a40, 0x2a20, 0x21a0, 0x19a0, 0x2200, 0x2a40, 0x2200, 0x2200,
-    0x2200, 0x2a20, 0x2a40, 0x2a40, 0x2a40, 0x2a40, 0x2a40, 0x21e0, 0x21e0, 0x2200, 0x2200, 0x2200,
-    0x6288, 0x828b, 0x3202, 0x2a00, 0x21e0, 0x3260, 0x3260, 0x2a40, 0x2a40, 0x2a40, 0x2a40, 0x2a00,
-    0x21a0, 0x2a20, 0x2a40, 0x2200, 0x2200, 0x2200, 0x2a40, 0x2a40, 0x2a40, 0x2a40, 0x2a20, 0x4224,
-    0x6a88, 0xa2cf, 0x4224, 0x21c0, 0x21c0, 0x21e0, 0x2a01, 0x3a80, 0x2a00, 0x1980, 0x2a00, 0x2a20,
-    0x2a20, 0x3260, 0x2a40, 0x2a40, 0x2a40, 0x3aa0, 0x2a40, 0x2a20, 0x3260, 0x2a40, 0x21e0, 0x2200,
-    0x21e0, 0x2200, 0x3260, 0x3280, 0x2a40, 0x2a40, 0x2a20, 0x2200, 0x2a20, 0x2a40, 0x2a20, 0x2200,
-    0x21c0, 0x2200, 0x3240, 0x19a0, 0x19a0, 0x21c0, 0x2200, 0x3260, 0x2a40, 0x2a40, 0x3280, 0x2a40,
-    0x2a40, 0x2a60, 0x2a20, 0x21e0, 0x21e0, 0x2200, 0x2a40, 0x3aa0, 0x2a60, 0x2a40, 0x3a42, 0xbb72,
-    0xa2cf, 0xa2cf, 0x5206, 0x2a00, 0x21a0, 0x21c0, 0x21a0, 0x21a0, 0x1980, 0x1980, 0x1980, 0x19a0,
-    0x2200, 0x3260, 0x2a40, 0x2a20, 0x2a20, 0x2a20, 0x2200, 0x3280, 0x2a20, 0x2200, 0x21c0, 0x19a0,
-    0x2200, 0x21e0, 0x3aa0, 0x3280, 0x3280, 0x2a40, 0x2200, 0x2200, 0x2a20, 0x2a20, 0x21e0, 0x21a0,
-    0x1980, 0x1980, 0x19a0, 0x19a0, 0x1980, 0x1980, 0x21e0, 0x2a40, 0x2200, 0x2200, 0x2200, 0x21e0,
-    0x2a60, 0x2200, 0x2200, 0x19a0, 0x21c0, 0x21c0, 0x3240, 0x2a40, 0x3260, 0x2a40, 0x2200, 0x62a7,
-    0x7289, 0x6a69, 0x2a21, 0x2a40, 0x21e0, 0x1980, 0x1980, 0x21c0, 0x21c0, 0x21a0, 0x1980, 0x21a0,
-    0x21e0, 0x2a20, 0x2200, 0x2200, 0x21e0, 0x21e0, 0x2200, 0x2a40, 0x2200, 0x21e0, 0x19a0, 0x19a0,
-    0x21a0, 0x21c0, 0x2a20, 0x2a20, 0x2a40, 0x2a20, 0x2200, 0x2200, 0x2200, 0x2a20, 0x21e0, 0x21c0,
-    0x2a40, 0x2200, 0x2a20, 0x2a40, 0x21a0, 0x1980, 0x21a0, 0x21e0, 0x3202, 0x7aaa, 0x31e2, 0x21c1,
-    0x21e0, 0x21e0, 0x21a0, 0x1980, 0x1980, 0x1980, 0x21c0, 0x2200, 0x2200, 0x2200, 0x21e0, 0x21e0,
-    0x21e0, 0x3280, 0x3280, 0x3280, 0x3280, 0x3280, 0x2a00, 0x2a00, 0x2a40, 0x2200, 0x1980, 0x1980,
-    0x21a0, 0x21e0, 0x3202, 0x930d, 0x6268, 0x31c3, 0x21a0, 0x21e0, 0x21c0, 0x19a0, 0x1980, 0x1980,
-    0x1980, 0x1980, 0x21c0, 0x2200, 0x2200, 0x2200, 0x21e0, 0x21c0, 0x21c0, 0x2200, 0x21c0, 0x1980,
-    0x2a20, 0x21c0, 0x2a20, 0x2a20, 0x21a0, 0x1980, 0x19a0, 0x21a0, 0x5266, 0xb331, 0xa2ef, 0x82ab,
-    0x21a0, 0x1980, 0x1980, 0x1980, 0x1980, 0x1980, 0x1980, 0x21c0, 0x21e0, 0x3a23, 0x39e3, 0x19a0,
-    0x2a20, 0x2a20, 0x2a40, 0x3240, 0x3260, 0x2a20, 0x21c0, 0x2a00, 0x2a40, 0x21e0, 0x1980, 0x1980,
-    0x19a0, 0x21a0, 0x3a23, 0xbb72, 0xa2cf, 0xb351, 0x4224, 0x19a0, 0x1980, 0x1980, 0x1980, 0x1980,
-    0x1980, 0x1980, 0x19a0, 0x21c0, 0x21e0, 0x2a21, 0x4a25, 0x1980, 0x19a0, 0x19a0, 0x21a0, 0x1980,
-    0x2200, 0x2a20, 0x3280, 0x21e0, 0x2a40, 0x2a20, 0x21c0, 0x31c3, 0xab10, 0xa2ee, 0xa2ec, 0x92ed,
-    0x2a20, 0x2a40, 0x2200, 0x21c0, 0x19a0, 0x1980, 0x1980, 0x21a0, 0x6288, 0xa2f0, 0x8a8d, 0x21a1,
-    0x21c0, 0x21a0, 0x1980, 0x21e0, 0x2200, 0x2200, 0x21e0, 0x3aa0, 0x2200, 0x2200, 0x2a20, 0x2200,
-    0x21a0, 0x31c3, 0xa30f, 0xaaf0, 0xab0a, 0xaaf0, 0x6287, 0x2a20, 0x2a20, 0x2200, 0x21c0, 0x19a0,
-    0x1980, 0x1980, 0x1980, 0x21c0, 0x62a7, 0xb331, 0xb331, 0x31c2, 0x1980, 0x1980, 0x1980, 0x1980,
-    0x21a0, 0x3260, 0x3280, 0x3240, 0x3280, 0x3260, 0x3a80, 0x1980, 0x5a67, 0x82ab, 0x6228, 0x3a03,
-    0x2a60, 0x3280, 0x3240, 0x21c0, 0x1980, 0x1980, 0x1980, 0x1980, 0x5226, 0x92ab, 0x9a8e, 0x39e3,
-    0x19a0, 0x1960, 0x19a0, 0x2a20, 0x3280, 0x21a0, 0x3aa0, 0x3280, 0x2a40, 0x3280, 0x2a40, 0x3280,
-    0x2a00, 0x21a0, 0x82ab, 0xaaf0, 0x92ac, 0x7a8b, 0x3a43, 0x3280, 0x3280, 0x3260, 0x21e0, 0x1980,
-    0x1980, 0x1980, 0x1980, 0x1980, 0x6a69, 0x9aad, 0xa2cf, 0x7269, 0x21c0, 0x21c0, 0x19a0, 0x1980,
-    0x1980, 0x2200, 0x21e0, 0x21c0, 0x3280, 0x2200, 0x3aa0, 0x21c0, 0x21a0, 0x21a0, 0x21e0, 0x3260,
-    0x3260, 0x3260, 0x21e0, 0x21c0, 0x19a0, 0x1980, 0x21e0, 0x21e0, 0x21c1, 0x5a27, 0x6208, 0x21c0,
-    0x21c0, 0x1980, 0x1980, 0x21a0, 0x21e0, 0x1980, 0x2200, 0x3a80, 0x21c0, 0x3280, 0x3260, 0x3260,
-    0x3ac0, 0x1980, 0x21e0, 0x29e1, 0x21c1, 0x2a20, 0x2200, 0x3280, 0x3260, 0x2200, 0x21c0, 0x21a0,
-    0x19a0, 0x1980, 0x19a0, 0x19a0, 0x3222, 0x92cc, 0xa2cf, 0x5a66, 0x2a20, 0x2a20, 0x2200, 0x21e0,
-    0x1980, 0x19a0, 0x1980, 0x19a0, 0x2200, 0x21e0, 0x21e0, 0x21c0, 0x1980, 0x19a0, 0x21c0, 0x21e0,
-    0x21e0, 0x21e0, 0x21a0, 0x1980, 0x1980, 0x19a0, 0x2200, 0x2200, 0x21e0, 0x1980, 0x1980, 0x1960,
-    0x1960, 0x1940, 0x1960, 0x1980, 0x19a0, 0x1980, 0x21c0, 0x19a0, 0x21a0, 0x2a20, 0x2a20, 0x21e0,
-    0x3a80, 0x21c0, 0x1980, 0x1980, 0x21c0, 0x2a00, 0x2a20, 0x3260, 0x2a20, 0x21e0, 0x21a0, 0x1980,
-    0x1980, 0x21e0, 0x2a40, 0x2a20, 0x21c0, 0x29e2, 0x4204, 0x1980, 0x2200, 0x2a00, 0x2200, 0x2a20,
-    0x21e1, 0x19a0, 0x19a0, 0x1980, 0x21c0, 0x21c0, 0x1980, 0x1980, 0x1980, 0x1980, 0x1980, 0x19a0,
-    0x21c0, 0x1980, 0x19a0, 0x1980, 0x1980, 0x1980, 0x21a0, 0x19a0, 0x21a0, 0x1980, 0x1980, 0x1940,
-    0x1120, 0x1120, 0x1120, 0x1940, 0x1980, 0x1980, 0x1980, 0x1980, 0x1980, 0x21c0, 0x2200, 0x21c0,
-    0x21a0, 0x19a0, 0x1980, 0x1980, 0x19a0, 0x21c0, 0x21c0, 0x21c0, 0x21a0, 0x19a0, 0x1980, 0x1980,
-    0x19a0, 0x21c0, 0x21e0, 0x2200, 0x21e0, 0x1980, 0x1980, 0x1980, 0x21a0, 0x21a0, 0x21e0, 0x21a0,
-    0x4383, 0x4342, 0x3ae2, 0x32a2, 0x2201, 0x1980, 0x1980, 0x19a0, 0x19a0, 0x1980, 0x1980, 0x1980,
-    0x1980, 0x2a21, 0x3282, 0x2a41, 0x2a41, 0x2a41, 0x21a0, 0x1980, 0x1980, 0x1980, 0x1980, 0x21a0,
-    0x21c1, 0x19a0, 0x2a41, 0x32a2, 0x2a01, 0x21c1, 0x19a0, 0x1980, 0x1980, 0x19a0, 0x21e0, 0x1980,
-    0x1980, 0x1980, 0x1980, 0x1980, 0x1980, 0x1980, 0x19a0, 0x21a0, 0x1980, 0x19a0, 0x19a0, 0x1980,
-    0x1980, 0x19a0, 0x19a0, 0x19a0, 0x21a0, 0x1980, 0x1980, 0x1980, 0x1980, 0x1980, 0x1980, 0x1980,
-];
-
-pub static BIRD_IMG_DATA: [u16; 900] = [
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x9f3e,
-    0x9f3e, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f3e, 0x865c, 0x64b8, 0x6417, 0x6c17, 0x4af7, 0x42f7, 0x5c58, 0x865c, 0x9f3e, 0x9f5e,
-    0x973e, 0x765c, 0x86bd, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x96fd, 0x8e3b, 0x7cb7, 0x7c1a, 0x633d, 0x421d, 0x423e,
-    0x6b5e, 0x843f, 0x4a7e, 0x2999, 0x4358, 0x863c, 0x96dd, 0xd75e, 0xaedd, 0x8ebd, 0x973e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x861b, 0x8518,
-    0xd6bc, 0xf7bf, 0xffff, 0xffff, 0xef7f, 0xd6bf, 0xa51f, 0x421f, 0x421f, 0x8c7f, 0x5b3c, 0x755b,
-    0xaebd, 0xe75d, 0xe77d, 0xc71d, 0x8edd, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x8e7c, 0x8d18, 0xef7e, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffdf, 0xdeff, 0x5aff, 0x215f, 0x95dd, 0x967e, 0x455f, 0xbedd, 0xe75d, 0xe75d, 0xb6fd, 0x86bd,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x96dd, 0x7cf7, 0xe75e, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xe79f, 0x7d5d, 0x6dde, 0x9e7d,
-    0x75fe, 0x5d9e, 0xc6fd, 0xe75d, 0xef7e, 0xaedd, 0x8efe, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x971e, 0x6476, 0xcf1d, 0xf7ff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xd75e, 0xb6fd, 0xaebd, 0x6dde, 0x353f, 0x8e3d, 0x5dbe, 0x65be, 0xd73d, 0xef7e, 0xdf7e,
-    0x8e9d, 0x971e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x7db9, 0xa61a, 0xdfdf, 0xf7ff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xdf7e, 0xb6dd, 0xaebc, 0xa69c, 0xa69c, 0x5dbe,
-    0x4d7f, 0x9e7d, 0x65df, 0xcf3e, 0x9ebd, 0x86bd, 0x971e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f3e, 0x7518, 0xcf7e, 0xd7df, 0xe7df, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xdedb, 0x8c92,
-    0x6411, 0x9e1a, 0xa69c, 0xa69c, 0xaebc, 0xa6bd, 0x7e1e, 0xb6fe, 0xc71e, 0x95db, 0x8e9d, 0x9f3e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x96fd, 0x8d79, 0xd79f, 0xd7df, 0xdfdf, 0xe7df,
-    0xffff, 0xffff, 0xffff, 0xf7bf, 0x73d0, 0x9577, 0x7cd4, 0x6c52, 0xae9c, 0xaebd, 0xa63b, 0xc71e,
-    0xdf7e, 0xefbf, 0xffff, 0xf79e, 0x9d9a, 0x971e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x8e9c, 0x9dda, 0xd7bf, 0xd7df, 0xdfdf, 0xdfdf, 0xdfdf, 0xe7df, 0xe7df, 0xaedd, 0x5b6e, 0x9577,
-    0x7494, 0x6412, 0x6cdb, 0x21f8, 0xa4b8, 0xf7df, 0xffff, 0xffff, 0xffff, 0xa538, 0x73b4, 0x7d99,
-    0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x8e7c, 0x9dfa, 0xd7bf, 0xbefe, 0x74ba, 0x3b58,
-    0x3b37, 0x74da, 0x7dbb, 0xae9c, 0x8d59, 0x21ee, 0x08b1, 0x009b, 0x001d, 0x101a, 0xbbb3, 0xff5d,
-    0xffff, 0xffff, 0xffff, 0x8435, 0x7353, 0x6352, 0x9f1d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x8ebd, 0x95b9, 0xcf3e, 0x3379, 0x03de, 0x045f, 0x041f, 0x041d, 0x1bfc, 0x4338, 0x193a, 0x005e,
-    0x001f, 0x001f, 0x001f, 0x081d, 0x98aa, 0xfa49, 0xfb6d, 0xfbcf, 0xfc10, 0xe32d, 0xca48, 0xc3e3,
-    0xc5cb, 0x9e99, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x7d18, 0x8d19, 0x035c, 0x047f, 0x04ff,
-    0x049f, 0x04bf, 0x04ff, 0x0c5e, 0x11f8, 0x001d, 0x001f, 0x001f, 0x001f, 0x001f, 0x4051, 0xd063,
-    0xf041, 0xf861, 0xf841, 0xe881, 0xe400, 0xfe60, 0xfe60, 0xddc3, 0xad70, 0x9f3e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x7559, 0x4af3, 0x043d, 0x04bf, 0x04bf, 0x04ff, 0x049f, 0x04bf, 0x04ff, 0x0cdf, 0x1af9,
-    0x08bb, 0x001f, 0x001f, 0x001f, 0x001e, 0x4050, 0xa886, 0xc883, 0xd083, 0xd2c1, 0xf5e0, 0xfe80,
-    0xfe80, 0xfe80, 0xf660, 0xb54b, 0x9f1c, 0x9f5e, 0x9f5e, 0x96fd, 0x3af2, 0x033a, 0x051f, 0x04ff,
-    0x04bf, 0x051f, 0x04df, 0x04df, 0x051f, 0x04ff, 0x239b, 0x005c, 0x001f, 0x001f, 0x001f, 0x001e,
-    0x1839, 0x4050, 0x586d, 0xd480, 0xeda0, 0xf5e0, 0xfe60, 0xfe80, 0xfe80, 0xee21, 0xae34, 0x9f3e,
-    0x9f3e, 0x9f3e, 0x6d16, 0x0150, 0x045e, 0x053f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f,
-    0x0c7d, 0x08db, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001e, 0x001d, 0xc402, 0xf5a0, 0xf5a0,
-    0xf5c0, 0xf5e0, 0xf600, 0xf600, 0xb50a, 0x9f1d, 0x7df9, 0x3aac, 0x4b90, 0x5c76, 0x0a34, 0x03dc,
-    0x047d, 0x045c, 0x045c, 0x043c, 0x043c, 0x043c, 0x02f9, 0x005c, 0x001f, 0x001f, 0x001f, 0x001f,
-    0x001e, 0x001d, 0x001a, 0x59cd, 0xcc41, 0xcc20, 0xcc00, 0xc422, 0xac27, 0x9d11, 0x9e17, 0x9f3e,
-    0x96fd, 0x8e9b, 0x865b, 0x4b6f, 0x5c55, 0x32d4, 0x00f5, 0x00b6, 0x00b6, 0x0096, 0x0096, 0x0076,
-    0x0037, 0x001a, 0x001c, 0x001c, 0x001c, 0x001b, 0x0019, 0x0018, 0x0017, 0x2154, 0x94d0, 0xb5af,
-    0xadd1, 0xa656, 0x9efc, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x7dd9, 0x1947, 0x7557, 0x973e, 0x96fd,
-    0x6d5a, 0x21b4, 0x0035, 0x0017, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018,
-    0x0036, 0x2194, 0x5c57, 0x8ebd, 0x9f1e, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x96dd, 0x7dd8, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f3e, 0x971e, 0x7e1b, 0x5c78, 0x3af6, 0x2a15,
-    0x21b5, 0x2195, 0x21b5, 0x2a15, 0x4316, 0x5c78, 0x7dfb, 0x971e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x96fe, 0x96fd, 0x96fd, 0x9f3e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-];
-
-//160(w)x80(h)
-pub static GAME_OVER_IMAGE_DATA: [u16; 12800] = [
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f3e, 0x8ebd, 0x865b, 0x861b, 0x863b,
-    0x8e7c, 0x96dd, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x96dd, 0x7dba, 0x53d5, 0x3252, 0x2173,
-    0x4234, 0x73f6, 0x6bb7, 0x4257, 0x2196, 0x21b4, 0x42f5, 0x64d8, 0x8ebd, 0x9f3e, 0x9f3e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x7ebd, 0x6e5c, 0x973e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x865c, 0x6456, 0x5b33,
-    0x5af6, 0x52bb, 0x31dd, 0x109d, 0x085d, 0x18fd, 0x5ade, 0x94df, 0xb59f, 0x841f, 0x297d, 0x0859,
-    0x10f3, 0x4354, 0x8e7c, 0x9f3e, 0x9f5e, 0x9f5e, 0x769d, 0x7e3c, 0x9e9d, 0x663c, 0x973e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x8ebd,
-    0x5c56, 0x73f5, 0xc61a, 0xf7df, 0xffff, 0xffff, 0xffff, 0xef5f, 0xad7f, 0x631e, 0x18de, 0x003e,
-    0x295e, 0x841f, 0xce5f, 0x949f, 0x213f, 0x003b, 0x0891, 0x5416, 0x9f3e, 0x767d, 0x969d, 0xe79e,
-    0xef9e, 0xaedd, 0x6e3c, 0x973e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f3e, 0x9f3e, 0x7599, 0x5332, 0xb5d9, 0xf7df, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffdf, 0xdeff, 0x8c5f, 0x211e, 0x003e, 0x213e, 0xa53f, 0xdeff, 0x631f, 0x005e, 0x0057,
-    0x4457, 0xb6dd, 0xef9e, 0xe75d, 0xe75d, 0xef9e, 0xaedd, 0x6e5c, 0x973e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa6bc, 0xa63a, 0xadd8, 0xad97, 0xb577, 0xb577, 0xb577, 0xb577,
-    0xb577, 0xadd8, 0x9efd, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9edd, 0xa65a,
-    0xae19, 0xadd8, 0xadb8, 0xb577, 0xb535, 0xbcd4, 0xbc93, 0xbc93, 0xbcb3, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x6497, 0x6b93, 0xe71d, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xd6bf, 0x5adf, 0x085f, 0x003e,
-    0x739f, 0xf7bf, 0x9cff, 0x339c, 0xb6fe, 0xa69e, 0xd71c, 0xe75d, 0xe75d, 0xe75d, 0xef7e, 0xaedd,
-    0x767d, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xadf8, 0xc431, 0xd26a, 0xe125, 0xf041, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xe8c3, 0xda29, 0xbcb4, 0x9f1d, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f1e, 0xa6dc, 0xa67b, 0xae39, 0xadd8, 0xad97,
-    0xb536, 0xb4f4, 0xbc93, 0xbc52, 0xc3f0, 0xc3af, 0xcb4e, 0xcb0c, 0xd2ab, 0xd26a, 0xd9c7, 0xe0e4,
-    0xe8c3, 0xf020, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xadf9, 0x9f5e, 0x9f5e, 0x9f5e, 0xae19, 0xbc93, 0xc431, 0xc3d0, 0xc3af, 0xc3f0, 0xc3f0,
-    0xc411, 0xbc52, 0xb4d4, 0xb556, 0xb577, 0xb577, 0xb577, 0xb577, 0xb577, 0xb577, 0xb577, 0xb577,
-    0xb577, 0xb577, 0xb577, 0xad97, 0xadd8, 0xae19, 0xa67b, 0x9edc, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x5415, 0x73f4, 0xef9e, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xf7bf, 0x94bf, 0x109f, 0x001f, 0x5b5f, 0x865c, 0xd75e, 0x559f, 0x2cff, 0x8e3d, 0xd71c,
-    0xe75d, 0xe75d, 0xe75d, 0xef7e, 0x9ebd, 0x767d, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9edc, 0xc3f0, 0xe124, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xe861, 0xc3af, 0xd9a7, 0xe145, 0xe104, 0xe8a3, 0xe861, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xcb6e, 0x9f5e, 0xa63a, 0xd9a7, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xe166, 0xcb2d, 0xadb7, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x6496,
-    0x7435, 0xefbf, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xbddf, 0x21be, 0x5d1c, 0xbf1e, 0xc6fd,
-    0xa69d, 0x351f, 0x2d1f, 0x9e5d, 0xd71d, 0xe75d, 0xe75d, 0xe75d, 0xef9e, 0x8e7d, 0x7ebd, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0xbc31, 0xe882, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xe104, 0xa69b, 0xe8c3,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xda29, 0xa69b, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f3e, 0x7599, 0x6393, 0xd7bf, 0xf7ff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xc71e,
-    0x863c, 0xa6bf, 0x2d1f, 0x5dbe, 0xbedc, 0x965d, 0x2cff, 0x3d3f, 0xb6bd, 0xdf3d, 0xe75d, 0xe75d,
-    0xe75d, 0xe79e, 0x763c, 0x8efe, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f1e, 0xda29, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xe000, 0xa800, 0x8800, 0x6000, 0x5000, 0x4000, 0x3800,
-    0x3800, 0x3800, 0x3800, 0x4000, 0x4800, 0x5800, 0x7800, 0xb000, 0xe800, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xe800, 0xe000, 0xd000, 0xc800, 0xc000, 0xc800,
-    0xf000, 0xf000, 0x9000, 0x6800, 0x5800, 0x4800, 0x4000, 0x3800, 0x3000, 0x2800, 0x8800, 0xf000,
-    0xf000, 0xf000, 0xda29, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xb536, 0x9f5e, 0x9f5e, 0x9f3e, 0x96dd, 0x42b1, 0xd77e, 0xd7df, 0xf7ff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xf7df, 0xaedd, 0x9e9d, 0xc71d, 0x863d, 0x351f, 0x24ff, 0x75fe, 0xbedc, 0x75fe, 0x24ff,
-    0x5d9e, 0xcefc, 0xe75d, 0xe75d, 0xe77d, 0xe77d, 0xdf7e, 0x661c, 0x973e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xcb4d, 0xf000, 0xf000, 0xf000, 0xf000, 0xd800, 0x8000, 0x2800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0800, 0x7800, 0xe800, 0xf000, 0xf000, 0xf000, 0xa800, 0x9800, 0x9000, 0x8000, 0x7000, 0x6800,
-    0x6000, 0x5800, 0x4800, 0x4000, 0x3800, 0x2800, 0x2000, 0x1800, 0x0800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x3000, 0xf000, 0xf000, 0x2000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x3000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xd000, 0x4800, 0x2000,
-    0x1000, 0x0800, 0x0800, 0x1800, 0x7000, 0xf000, 0xb800, 0x4000, 0x4000, 0x4000, 0x4000, 0x4800,
-    0x4800, 0x4800, 0x4800, 0x4800, 0x4000, 0x4000, 0x3800, 0x3800, 0x4000, 0x4000, 0x4800, 0x5800,
-    0x7000, 0xb000, 0xf000, 0xf000, 0xf000, 0xb556, 0x9f5e, 0x9f5e, 0x9f3e, 0x53f4, 0xa5b9, 0xd7df,
-    0xd7df, 0xf7ff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xefbf, 0x9e9d, 0xb6dd, 0xbefd, 0xa69c, 0xa69c, 0x863d, 0x2d1f,
-    0x2cff, 0x965d, 0xbedc, 0x557e, 0x2cff, 0x8e3d, 0xd71d, 0xe75d, 0xef7e, 0xef7e, 0xf7be, 0xb6fd,
-    0x6e3c, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xadb8, 0xf000, 0xf000, 0xf000, 0xf000, 0x8000, 0x0800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0xe800, 0xf000, 0xe000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2800, 0xf000, 0xf000, 0x1000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd800, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0x6000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4800, 0xf000, 0xa800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4000, 0xf000, 0xf000, 0xf000, 0xad97, 0x9f5e, 0x9f3e,
-    0x96fd, 0x3a70, 0xdfbf, 0xdfdf, 0xd7df, 0xe7df, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xdf7e, 0x967d, 0xbf1e, 0xb6dd, 0xa69c,
-    0xa69c, 0xa69c, 0xa69c, 0x6ddd, 0x2cff, 0x3d3f, 0xb6bd, 0xa69d, 0x351f, 0x455f, 0xcefd, 0xef9e,
-    0xef9e, 0xd75e, 0x9ebd, 0x6e1c, 0x7e9d, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xd28a, 0xf000, 0xf000, 0xf000,
-    0x6000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8800,
-    0xf000, 0xb800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3000,
-    0xf000, 0xf000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xe800, 0x1000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x4000, 0xf000, 0xa800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4000, 0xf000, 0xf000,
-    0xf000, 0xadb7, 0x9f5e, 0x9f3e, 0x6d18, 0x84b6, 0xdfdf, 0xd7df, 0xd7df, 0xdfdf, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xe71c, 0xadd8, 0x861b,
-    0xc71d, 0xaebc, 0xa69c, 0xa69c, 0xa69c, 0xa69c, 0xa69c, 0xa69c, 0x4d7e, 0x24ff, 0x6dde, 0xcf1d,
-    0x9e9f, 0xb6df, 0xcf3e, 0xa6dd, 0x7e5c, 0x767d, 0x8efe, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa6bc,
-    0xf000, 0xf000, 0xf000, 0xa800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x3000, 0xf000, 0x9800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x3800, 0xf000, 0xe800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x5000, 0xf000, 0xf000, 0xf000, 0xf000, 0x9000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x4000, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x4000, 0xf000, 0xf000, 0xf000, 0xb577, 0x9f5e, 0x973e, 0x4b73, 0xc6dc, 0xd7bf, 0xd7df,
-    0xd7df, 0xd7df, 0xe7df, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffdf,
-    0x8410, 0x10a3, 0x2125, 0x2125, 0x8515, 0xa69c, 0xa69c, 0xa69c, 0xa69c, 0xa69c, 0xa69c, 0xa69c,
-    0x967d, 0x7e1f, 0xb6ff, 0xbf1e, 0x9e9d, 0x9e9d, 0x5c77, 0x7e3c, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xbc52, 0xf000, 0xf000, 0xf000, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0x8800, 0xa800, 0xa800, 0x9800, 0x2000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xe800, 0x8000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3800, 0xf000, 0xd800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xe800, 0xf000, 0xf000, 0xf000,
-    0x2800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3800, 0xf000, 0x9800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3800, 0xf000, 0xf000, 0xf000, 0xb556, 0x9f3e, 0x8ebd,
-    0x5332, 0xd7bf, 0xd7df, 0xd7df, 0xd7df, 0xd7df, 0xd7df, 0xe7df, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xbdf7, 0x2986, 0xb639, 0xbedc, 0x6bd0, 0x2986, 0x9e5b, 0xa69c, 0xa69c,
-    0xa69c, 0xaebc, 0xbefd, 0xcf3e, 0xb6dd, 0x8e5c, 0x9e9d, 0xc73e, 0xefbf, 0xffff, 0xdefd, 0x63f5,
-    0x8ebd, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xda29, 0xf000, 0xf000, 0xe000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0xd800, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xb000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xc000, 0x7000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5800, 0x8800, 0x9000, 0xa000, 0xc800,
-    0xf000, 0xc800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xb000, 0xf000, 0xf000, 0xb800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x3000, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3800, 0xf000, 0xf000,
-    0xf000, 0xadb7, 0x9f5e, 0x863b, 0x6bf4, 0xdfdf, 0xd7df, 0xd7df, 0xdfdf, 0xdfdf, 0xd7df, 0xd7df,
-    0xdfdf, 0xf7ff, 0xffff, 0xffff, 0xffff, 0xffff, 0xcf5e, 0x4bb1, 0x5b0c, 0xaebc, 0xa69c, 0x95d9,
-    0x18e4, 0x95d9, 0xaebc, 0xbefd, 0xc71d, 0xae9d, 0x961b, 0x9e9d, 0xcf5e, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xe71d, 0x5b94, 0x971e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xe8c3,
-    0xf000, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x8800, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xc800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xb000, 0x7000, 0x2000, 0x2800, 0x3000, 0x3800, 0x1000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xc000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x7000, 0xf000, 0xf000, 0x5800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x2800, 0xf000, 0xc000, 0x3800, 0x1800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x5000, 0xf000, 0xf000, 0xf000, 0xa65b, 0x9f5e, 0x7599, 0x84b6, 0xdfdf, 0xd7df, 0xd7df,
-    0xd7df, 0xd7df, 0xdfdf, 0xdfdf, 0xdfdf, 0xdfdf, 0xdfdf, 0xdfdf, 0xdfdf, 0xa6dd, 0x8e7c, 0x9d56,
-    0x31a7, 0xae9b, 0xaebd, 0x6411, 0x2125, 0xb69c, 0x9e5c, 0x64db, 0x2ada, 0x2131, 0xde3a, 0xffff,
-    0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xf79e, 0xbdfa, 0xad79, 0x7539, 0x9f3e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xf020, 0xf000, 0xf000, 0x8000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xd800, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xd800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xa800, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0x2800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x1800, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xb800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0xf000, 0xe000, 0x0800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0x1800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xa800, 0xc000, 0xc000,
-    0xc000, 0xc800, 0xc800, 0xd000, 0xd000, 0xe800, 0xf000, 0xf000, 0xf041, 0x9f1e, 0x9f5e, 0x7558,
-    0x8d17, 0xdfdf, 0xd7df, 0xd7df, 0xcf5e, 0x8d19, 0x5335, 0x4295, 0x4274, 0x6396, 0xa5bb, 0xcf7e,
-    0x96bd, 0x9e9d, 0xc71d, 0xa65b, 0x4aab, 0x18e4, 0x2166, 0x0884, 0x2ab4, 0x229a, 0x08fa, 0x003a,
-    0x0019, 0x60aa, 0xff9e, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffdf, 0x6b73, 0x088b,
-    0x9cf7, 0x5bd5, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9edd, 0xf000, 0xf000, 0xf000, 0x7000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2800, 0xf000, 0xf000, 0xf000, 0xf041,
-    0xf000, 0xf000, 0xe000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x9800, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x8000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xb000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xd800, 0x9000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x1800, 0xf000, 0xf000, 0xf000, 0xf000, 0x1000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xd800, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xd249, 0x9f5e, 0x9f3e, 0x7579, 0x8cf7, 0xdfdf, 0xd7df, 0xa5fb, 0x2214, 0x0299, 0x03be, 0x03df,
-    0x039e, 0x031b, 0x0a37, 0x2a55, 0x7cf9, 0xcf1d, 0xb6bc, 0xae9c, 0x7d9b, 0x2275, 0x08d5, 0x005a,
-    0x003d, 0x001e, 0x001e, 0x001e, 0x001b, 0x88a7, 0xfeba, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
-    0xffff, 0xf79e, 0x424f, 0x006a, 0x73d4, 0x73f5, 0x96dd, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa67b, 0xf000,
-    0xf000, 0xf000, 0x6000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5000,
-    0xf000, 0xf000, 0xf000, 0xe8c3, 0xf000, 0xf000, 0xf000, 0x8800, 0x5800, 0x4000, 0x2800, 0x1000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xc000, 0xf000, 0xf000, 0xf000, 0xf000, 0xd000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xb800, 0x8800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x5800, 0xf000, 0xf000, 0xf000, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9000, 0x3800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x1000, 0xf000, 0xf000, 0xf000, 0xf000, 0x0800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xe800, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xd26a, 0x9f5e, 0x9f3e, 0x7dda, 0x7456, 0xd7df, 0xb67c, 0x19f4,
-    0x03bd, 0x03ff, 0x043f, 0x03ff, 0x03ff, 0x041f, 0x049f, 0x03dd, 0x1a36, 0x5b55, 0x3b39, 0x0959,
-    0x0039, 0x001d, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001c, 0x5333, 0xbc32, 0xfedb,
-    0xffdf, 0xffff, 0xffff, 0xffff, 0xffff, 0xffdf, 0x5b32, 0x088b, 0x9cd7, 0x93b2, 0x7d98, 0x9f3e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0xadf9, 0xf000, 0xf000, 0xf000, 0x5000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x7000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xe000, 0xd800, 0xe800, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xa800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xe800, 0xe000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xe800, 0xf000, 0xf000, 0xf000, 0x9800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x3800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xd26a, 0x9f5e, 0x9f3e, 0x8edd,
-    0x5332, 0xdfdf, 0x5314, 0x037c, 0x03ff, 0x047f, 0x051f, 0x04ff, 0x041f, 0x045f, 0x051f, 0x04ff,
-    0x0c9f, 0x1b5a, 0x08f3, 0x0017, 0x001e, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f,
-    0x095e, 0x45dd, 0x74b6, 0xf104, 0xfacb, 0xfc30, 0xfd14, 0xfdb6, 0xfdf7, 0xfe38, 0xed97, 0xcc53,
-    0xf34d, 0xc1a3, 0xa3a6, 0x9df7, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xadb8, 0xf000, 0xf000, 0xf000, 0x4800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7800, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0x7800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3000,
-    0xf000, 0xf000, 0x4800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9800, 0xf000, 0xf000,
-    0xf000, 0x8800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0800, 0xf000, 0xf000, 0xf000, 0xe800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0800, 0x8800, 0x9800, 0xa000, 0xb000, 0xb800, 0xc800, 0xd000, 0xd800, 0xf000, 0xf000, 0xf000,
-    0xd26a, 0x9f5e, 0x9f5e, 0x9f3e, 0x42f1, 0xc71d, 0x19b2, 0x041f, 0x041f, 0x041f, 0x051f, 0x051f,
-    0x04ff, 0x043f, 0x04bf, 0x051f, 0x051f, 0x04bf, 0x247d, 0x19f5, 0x0038, 0x001f, 0x001f, 0x001f,
-    0x001f, 0x001f, 0x001f, 0x003f, 0x34bf, 0x465f, 0x463e, 0xb24b, 0xf820, 0xf800, 0xf800, 0xf820,
-    0xf861, 0xf882, 0xf861, 0xf061, 0xd1e0, 0xe520, 0xfe60, 0xdd21, 0xad0e, 0x9ebb, 0x9f3e, 0x9f3e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xadb8, 0xf000,
-    0xf000, 0xf000, 0x4800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8000,
-    0xf000, 0xf000, 0xe800, 0xd000, 0xd000, 0xd000, 0xd000, 0xd000, 0xc800, 0xc800, 0xc000, 0xc000,
-    0xb800, 0xb800, 0xb000, 0xb000, 0xe000, 0xf000, 0xf000, 0xf000, 0xf000, 0x4000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x6000, 0xf000, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x4000, 0xf000, 0xf000, 0xf000, 0x8000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x9000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xf000, 0xf000, 0xf000, 0xe800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xb000, 0xf000, 0xf000, 0xe166, 0x9f5e, 0x9f5e, 0x9f3e, 0x64d7, 0x7435, 0x0972, 0x049f,
-    0x051f, 0x049f, 0x045f, 0x051f, 0x051f, 0x04ff, 0x045f, 0x04bf, 0x051f, 0x051f, 0x04df, 0x1c9f,
-    0x2af7, 0x0056, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x11ff, 0x463f, 0x465f, 0x465f, 0x5d9b,
-    0xd125, 0xf061, 0xf861, 0xf841, 0xf800, 0xf820, 0xe861, 0xcaa0, 0xede0, 0xfea0, 0xfe80, 0xfea0,
-    0xf640, 0xccc3, 0x9d32, 0x9f1e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0xadd8, 0xf000, 0xf000, 0xf000, 0x4800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x8800, 0xf000, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0x1800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9000, 0xf000, 0xf000, 0xe800, 0x1000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd800, 0xf000, 0xf000, 0x8800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xc000, 0x1000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x1800, 0xc800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xf000, 0xf000, 0xf000,
-    0xe800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9800, 0xf000, 0xf000, 0xe8c3, 0x9f5e, 0x9f5e, 0x9f3e,
-    0x96dd, 0x21af, 0x0111, 0x04bf, 0x053f, 0x053f, 0x049f, 0x047f, 0x051f, 0x051f, 0x04ff, 0x045f,
-    0x04bf, 0x051f, 0x051f, 0x04ff, 0x149f, 0x33da, 0x0895, 0x001e, 0x001f, 0x001f, 0x001f, 0x2bff,
-    0x465f, 0x465f, 0x465f, 0x465f, 0x7c14, 0xd0a3, 0xd083, 0xd083, 0xd083, 0xd0a3, 0xc2c1, 0xed80,
-    0xfe80, 0xfe80, 0xfea0, 0xfe80, 0xfe80, 0xfea0, 0xedc0, 0xa44a, 0x9efc, 0x9f3e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xae19, 0xf000, 0xf000, 0xf000, 0x5800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9000, 0xf000, 0xf000, 0x9000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xb800, 0xf000, 0xf000, 0xf000, 0xd800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc000,
-    0xf000, 0xf000, 0xf000, 0x5800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8000, 0xf000,
-    0xf000, 0x9000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc000, 0x5800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7000, 0xd000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0800, 0xf000, 0xf000, 0xf000, 0xe800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xa000, 0xf000, 0xf000,
-    0xe8e4, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x6497, 0x008e, 0x03dd, 0x051f, 0x053f, 0x053f, 0x049f,
-    0x047f, 0x051f, 0x051f, 0x04ff, 0x045f, 0x04bf, 0x051f, 0x051f, 0x04ff, 0x0cbf, 0x3bfa, 0x0055,
-    0x001f, 0x001f, 0x001f, 0x34ff, 0x465f, 0x465f, 0x465f, 0x465f, 0x55fd, 0xa8a6, 0xd0a3, 0xd083,
-    0xd083, 0xc1e1, 0xe540, 0xed80, 0xf640, 0xfe80, 0xfe80, 0xfe80, 0xfe80, 0xfe80, 0xfe80, 0xf620,
-    0xa42a, 0x9f1d, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa6bc, 0xf000,
-    0xf000, 0xf000, 0x7000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8800,
-    0xf000, 0xf000, 0x9000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xb800, 0xf000, 0xf000, 0xf000, 0xa800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xe800, 0xf000, 0xf000, 0xf000, 0xa800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x2800, 0xf000, 0xf000, 0x9000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xc000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc800, 0xd000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x1000, 0xf000, 0xf000, 0xf000, 0xe000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xb800, 0xf000, 0xf000, 0xd9a7, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x96fd, 0x21f0, 0x0216,
-    0x047f, 0x053f, 0x053f, 0x053f, 0x04bf, 0x04ff, 0x051f, 0x051f, 0x051f, 0x04ff, 0x051f, 0x051f,
-    0x051f, 0x04ff, 0x251f, 0x2234, 0x001c, 0x001f, 0x001f, 0x34ff, 0x465f, 0x465f, 0x465f, 0x465f,
-    0x769f, 0x1056, 0x406b, 0x7887, 0x7887, 0xbb61, 0xeda0, 0xed80, 0xeda0, 0xfe40, 0xfe80, 0xfe80,
-    0xfe80, 0xfe80, 0xfe80, 0xfea0, 0xe5a0, 0x9d11, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xf041, 0xf000, 0xf000, 0x9800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x7000, 0xf000, 0xf000, 0x9000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb000, 0xf000, 0xf000, 0xf000,
-    0x7800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0xa800, 0xb000, 0xb000, 0xb000, 0xa000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc000, 0xf000, 0x8800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xc000, 0xe000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x3800, 0xf000, 0xd000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xf000, 0xf000, 0xf000,
-    0xe000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6800, 0xb000, 0xc000, 0xd000,
-    0xc800, 0xc000, 0xc000, 0xc000, 0xb800, 0xe800, 0xf000, 0xf000, 0xd28a, 0x9f5e, 0x9f3e, 0x96fd,
-    0x75b8, 0x5412, 0x196a, 0x00ad, 0x031a, 0x049f, 0x053f, 0x053f, 0x053f, 0x051f, 0x051f, 0x051f,
-    0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x0d1f, 0x2b37, 0x001a, 0x001f, 0x001f, 0x233f,
-    0x463f, 0x465f, 0x465f, 0x4e5f, 0x4c7f, 0x001f, 0x001e, 0x001c, 0x1039, 0xbba1, 0xeda0, 0xeda0,
-    0xed80, 0xed80, 0xf620, 0xfe60, 0xfe80, 0xfe80, 0xfe60, 0xfe80, 0xfe80, 0xbc44, 0x9edc, 0x9f3e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xe186, 0xf000, 0xf000, 0xc800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4800, 0xf000, 0xf000, 0xe000, 0xa800,
-    0xb000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xa800, 0xf000, 0xf000, 0xf000, 0x4800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5800,
-    0xf000, 0x8000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc800, 0xf000, 0x3800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xa000, 0xf000, 0xd800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xf000, 0xf000, 0xf000, 0xe000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xb800, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xd2cc, 0x9f3e, 0x5c73, 0x1926, 0x08c5, 0x2a2a, 0x64b5, 0x5c76, 0x092f, 0x033b, 0x049f, 0x053f,
-    0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x051f, 0x053f, 0x1ab5,
-    0x001b, 0x001f, 0x001f, 0x005f, 0x1a9f, 0x2bdf, 0x2bdf, 0x1adf, 0x009f, 0x001f, 0x001f, 0x001f,
-    0x001e, 0x9ac4, 0xeda0, 0xf5a0, 0xf5a0, 0xeda0, 0xeda0, 0xeda0, 0xf5e0, 0xf620, 0xfe40, 0xfe60,
-    0xfe80, 0xdd60, 0x9d74, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xcb6e,
-    0xf000, 0xf000, 0xf000, 0x2800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xb800, 0xf000, 0xf000, 0xe000, 0x9800, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xa000, 0xf000, 0xf000, 0xf000, 0x1800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xd800, 0x8000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xd000, 0xf000, 0x8000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0xf000, 0xf000, 0xd800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xf000, 0xf000, 0xf000, 0xd800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xc000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xcb2d, 0x9f5e, 0x7dd8, 0x3aad, 0x7598, 0x96fd, 0x9f3e, 0x8e9b,
-    0x21ec, 0x008e, 0x0257, 0x03db, 0x049d, 0x049d, 0x049c, 0x047c, 0x047c, 0x047c, 0x047c, 0x049d,
-    0x049d, 0x049d, 0x0399, 0x0093, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f, 0x001f,
-    0x001f, 0x001f, 0x001f, 0x001e, 0x001b, 0x490e, 0xe4e0, 0xe500, 0xc3e0, 0xc400, 0xcc20, 0xcc20,
-    0xcc40, 0xd460, 0xd480, 0xd480, 0xcc60, 0xc401, 0x9cd0, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xadd8, 0xf000, 0xf000, 0xf000, 0xb800, 0x0800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0x1000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9800, 0xf000, 0xf000, 0xd800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7800, 0x8000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xd000, 0xf000, 0xc800, 0x0000, 0x0000, 0x0000, 0x0000, 0x7800,
-    0xf000, 0xf000, 0xd800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xf000, 0xf000, 0xf000,
-    0xd000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd000, 0xf000, 0xf000, 0xe104,
-    0xda29, 0xd9c7, 0xd9c7, 0xd9c7, 0xd9c7, 0xd9c7, 0xd9c7, 0xd9c7, 0xbc72, 0x9f5e, 0x973e, 0x971e,
-    0x9f3e, 0x9f3e, 0x7598, 0x21c9, 0x3aac, 0x4bd5, 0x08ae, 0x0852, 0x0033, 0x0853, 0x0873, 0x0093,
-    0x0093, 0x00b3, 0x00b3, 0x08b2, 0x08d2, 0x0092, 0x0032, 0x001b, 0x001f, 0x001f, 0x001f, 0x001f,
-    0x001f, 0x001f, 0x001f, 0x001f, 0x001e, 0x001d, 0x001a, 0x0018, 0x0018, 0x0837, 0x8a46, 0xe520,
-    0xe520, 0xdd00, 0xdce0, 0xdcc0, 0xd4a0, 0xcc40, 0xb383, 0x9c6e, 0x9e38, 0x9e9a, 0x9efc, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xd9a7, 0xf000, 0xf000, 0xf000,
-    0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x9000, 0xf000, 0xf000, 0xa800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x1800, 0x7800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xd800, 0xf000, 0xf000, 0x1800,
-    0x0000, 0x0000, 0x0000, 0xd800, 0xf000, 0xf000, 0xe000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xf000, 0xf000, 0xf000, 0xc800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xe000, 0xf000, 0xf000, 0xe841, 0xe8e3, 0xe8e3, 0xe8e3, 0xe8e3, 0xe8e3, 0xe8e3, 0xe8e3, 0xe8e3,
-    0xe8e3, 0xe862, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xb515, 0x9f3e, 0x7dfb, 0x2a52,
-    0x0033, 0x0017, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018,
-    0x0019, 0x001b, 0x001d, 0x001d, 0x001d, 0x001c, 0x001c, 0x001a, 0x0019, 0x0018, 0x0018, 0x0018,
-    0x0018, 0x0018, 0x1852, 0x92a4, 0xc402, 0xcc42, 0xc462, 0xbc44, 0xac28, 0x9d11, 0x9edc, 0x9f3e,
-    0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xadf9, 0xf041, 0xf000, 0xf000, 0xf000, 0xa000, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x8800, 0xf000, 0xf000, 0x7800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0xa000, 0xd000, 0xd800, 0xe000, 0xe000, 0xe800, 0xe000, 0x0800, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6800, 0x6800, 0x6800, 0x7800, 0x9000, 0xb800, 0xe800,
-    0xf000, 0xf000, 0xf000, 0xa800, 0x9800, 0xa800, 0xc000, 0xf000, 0xf000, 0xf000, 0xe000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xf000, 0xf000, 0xf000, 0xb800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xadd8, 0x9f5e, 0x9f3e, 0x9f3e, 0x7599, 0x3273, 0x0052, 0x0016, 0x0018, 0x0018, 0x0018, 0x0018,
-    0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018,
-    0x0018, 0x0018, 0x0018, 0x0017, 0x0033, 0x1931, 0x53f5, 0x8e5b, 0x9e7a, 0x9e79, 0x9e59, 0x9e9b,
-    0x9f3e, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb515, 0xf000, 0xf000, 0xf000, 0xf000, 0xc000, 0x2000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0xa000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8800, 0xf000, 0xf000, 0x4800,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xe800, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0x4800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xe800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xf000, 0xf000, 0xf000,
-    0xb000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xa65a, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x865c, 0x4bb5,
-    0x21b3, 0x0873, 0x0015, 0x0016, 0x0017, 0x0017, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018,
-    0x0018, 0x0018, 0x0018, 0x0017, 0x0035, 0x0893, 0x1992, 0x4b95, 0x7dba, 0x96fe, 0x9f3e, 0x9f3e,
-    0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbc93, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xe800, 0x9800, 0x3800, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x4000, 0xd800, 0xa800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x8000, 0xf000, 0xf000, 0x1800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0xc000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xe000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xe800, 0xf000, 0xf000, 0xa000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800,
-    0x7800, 0x9000, 0x9800, 0x9800, 0x9800, 0x9800, 0x9000, 0x9000, 0x9000, 0x9000, 0x9000, 0x9000,
-    0x9000, 0x8800, 0x8800, 0x8800, 0xb800, 0xf000, 0xf000, 0xf000, 0x9edd, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x96dd, 0x7e1b, 0x5c77, 0x4334, 0x2a13, 0x1932, 0x08b2, 0x0872,
-    0x0052, 0x0012, 0x0012, 0x0051, 0x08d2, 0x1972, 0x2a13, 0x4355, 0x64b8, 0x7e1b, 0x971e, 0x9f3e,
-    0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xb515, 0xf041, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xe800, 0xd000,
-    0xc000, 0xb800, 0xb000, 0xb000, 0xc000, 0xf000, 0xf000, 0xb800, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x7800, 0xf000, 0xd800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x7000, 0xf000, 0xf000, 0xf000, 0xd26a, 0xe104, 0xf000, 0xf000, 0xe800, 0x0800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8800, 0xf000, 0xf000, 0xf000, 0xe125, 0xcb0d,
-    0xd2cb, 0xf000, 0xf000, 0xf000, 0xe8a3, 0xe186, 0xd9c7, 0xe8c3, 0xf000, 0xf000, 0xe800, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xe000, 0xf000, 0xf000, 0x9800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8000, 0xf000, 0xf000, 0xf020,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f3e,
-    0x9f3e, 0x96fd, 0x8e9c, 0x865c, 0x863b, 0x863b, 0x861b, 0x867c, 0x8e9c, 0x8ebd, 0x971e, 0x9f3e,
-    0x9f3e, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa63a, 0xd9a6, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xb800,
-    0x0000, 0x0000, 0x0000, 0x1000, 0x2000, 0x2800, 0x3000, 0x3800, 0x9000, 0xf000, 0xc800, 0x1000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb800, 0xf000, 0xf000, 0xe8c3, 0x9f5e, 0xbc72, 0xf000,
-    0xf000, 0xf000, 0x5800, 0x0000, 0x0000, 0x0800, 0x3000, 0x5800, 0x9000, 0xd000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xbc52, 0x9f5e, 0xa6bc, 0xb515, 0xae19, 0x9efd, 0x9f5e, 0x9f5e, 0x9f5e, 0xcb4e,
-    0xf000, 0xf000, 0xe800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd800, 0xf000, 0xf000,
-    0x9000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x9800, 0xf000, 0xf000, 0xe8c3, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f3e, 0x9f3e,
-    0x9f5e, 0x9f3e, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0xb4f4, 0xe166, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xe800, 0xe000, 0xe800, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xc800, 0xa000, 0x7800, 0x5000, 0x3000, 0xe800, 0xf000, 0xf000,
-    0xd2cb, 0x9f5e, 0xa6dc, 0xf020, 0xf000, 0xf000, 0xd800, 0xa000, 0xc800, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xd26a, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xcb4d, 0xf000, 0xf000, 0xe800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xd800, 0xf000, 0xf000, 0x9000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xa800, 0xf000, 0xf000, 0xe145, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xae19, 0xbc52, 0xcb2d, 0xd26a,
-    0xda08, 0xd9c7, 0xd9c7, 0xd9c7, 0xe882, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xb4d4, 0x9f5e, 0x9f5e, 0xcb0d, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf020, 0xcb4d, 0x9f1e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc38f, 0xf000, 0xf000, 0xf000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd800, 0xf000, 0xf000, 0x8800, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc000, 0xf000, 0xf000, 0xda08,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xda08, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf020, 0x9edd, 0x9f5e, 0x9f5e, 0xa6bc,
-    0xe104, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf041, 0xda08, 0xbc52, 0xa6bc,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc3af,
-    0xf000, 0xf000, 0xf000, 0x5800, 0x6800, 0x7800, 0x9800, 0xb000, 0xd800, 0xf000, 0xf000, 0xf000,
-    0x8800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x1000, 0x1800,
-    0xd800, 0xf000, 0xf000, 0xd2ab, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xcb6e, 0xda08, 0xd249, 0xd28b, 0xd2ab, 0xd2cb, 0xcb4d, 0xc3af, 0xc411, 0xbc72, 0xbcb4, 0xb515,
-    0xb576, 0xb556, 0xbc72, 0xcb4d, 0xd9e8, 0xe0e4, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xda08,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa65a, 0xe8c3, 0xf000, 0xe8a3, 0xe8a2, 0xd2cb, 0xbcb3, 0xadf9,
-    0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xcb8e, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xe000, 0xd800, 0xe000, 0xe800, 0xf000, 0xf000, 0xe000, 0xd000,
-    0xc000, 0xc000, 0xb800, 0xb800, 0xb000, 0xb000, 0xc000, 0xc800, 0xd000, 0xe000, 0xe800, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xcb6e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa73a, 0xb736, 0xaf38,
-    0x9f5d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9efd, 0xb577,
-    0xcb0c, 0xe882, 0xf000, 0xbc52, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa69b, 0xa69c, 0xa69b,
-    0xe1a3, 0xbc91, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xcb2d, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xc431,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa73c, 0xbf35, 0xcf2f, 0xe728, 0xf723,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff21, 0xdf2b, 0xa73b, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0xb535, 0xa69b, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f1d, 0xe1c4, 0xc470, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xcaec,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xe862, 0xe104, 0xd249, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xb4f4, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb737, 0xcf2e, 0xef26, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xf723, 0xaf39,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xd2e9, 0xb596, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f1d, 0x9f1d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xb515, 0xc431, 0xbc93, 0xb515, 0xad97, 0xa67b, 0x9f3e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xd2ab, 0xd2ab, 0xd2ab, 0xd2ab, 0xd2ab, 0xd2ab, 0xd2ab, 0xd2ab, 0xd2ab, 0xd2ab, 0xd249,
-    0xe1c6, 0xf1c2, 0xf1c0, 0xf1c0, 0xf1c0, 0xf1c0, 0xf1c0, 0xea23, 0xd2ab, 0xcb0d, 0xcb8e, 0xc3af,
-    0xc3af, 0xc410, 0xbc73, 0xbc93, 0xbc93, 0xbc93, 0xbc93, 0xa69b, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xaf38, 0xc731, 0xdf2a, 0xf722,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xf6e0, 0xde20, 0xbd40, 0xc560,
-    0xff00, 0xff20, 0xff20, 0xf722, 0xa73c, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xadb7, 0xb596, 0x9f5e, 0x9f5e,
-    0xc3ce, 0x9f1e, 0x9f5e, 0x9f5e, 0x9edc, 0xa6bc, 0x9f5e, 0x9f5e, 0x9f5e, 0xa69b, 0xe205, 0xc3ee,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xa73b, 0xd72e, 0xef25, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xf723,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5d, 0xb737, 0xbf34, 0xd72d, 0xf722,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xf6e0, 0xc560, 0x83a0, 0x4a00, 0x18e0,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x3160, 0xe680, 0xff20, 0xff20, 0xcf30, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa659, 0xa6bb, 0x9f5e, 0xb534,
-    0xe183, 0xe183, 0xc450, 0x9f5e, 0xc450, 0x9f5e, 0x9f5e, 0xa69b, 0xe1c4, 0xdaa8, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xcbcd, 0xda46, 0xb596, 0xda26, 0xd30a, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbf33, 0xff21, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xe640, 0xee80, 0xff20, 0xff20, 0xbf35, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa73a, 0xbf33, 0xd72d, 0xef25, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xd5e0, 0x83c0, 0x39c0, 0x0820,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5a80, 0xff20, 0xff20,
-    0xf723, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xa639, 0xb534, 0x9f5e, 0xb534, 0xe163, 0xe163, 0xd2e9, 0x9f5e, 0xd2c9, 0x9f5e, 0x9f5e, 0xbcb2,
-    0xe163, 0xda67, 0x9f5e, 0x9f5e, 0xa69b, 0xe1c4, 0xc3ce, 0xbcb2, 0xb555, 0xa6bc, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xd72d, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xeea0, 0xb500, 0x7340, 0x3160, 0x0000, 0x8bc0, 0xff20, 0xff20, 0xe728, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb736, 0xcf30, 0xbf35, 0xcf30, 0xe728, 0xff22, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xde40, 0x62e0, 0x18e0,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0020, 0xe680, 0xff20, 0xff20, 0xb737, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0x9f3e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0xbcd2, 0xa6bb, 0x9f5e, 0x9efd, 0xb555, 0x9efd, 0xe1c4, 0xe162, 0xd2c9, 0x9edc,
-    0xe183, 0x9f5e, 0x9f5e, 0xa67a, 0xcb4b, 0xb596, 0x9f5e, 0xa6bb, 0xda67, 0xe162, 0xcbad, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xcf30,
-    0xff20, 0xff20, 0xff20, 0x7340, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2100, 0xff00, 0xff20,
-    0xff20, 0xaf39, 0x9f5e, 0x9f5e, 0x9f5e, 0xaf3a, 0xc732, 0xdf2a, 0xff21, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xd600, 0xa4a0, 0x7b60, 0x5a60,
-    0x7360, 0xd5e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9420, 0xff20, 0xff20, 0xd72e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xa6dc, 0xb534, 0x9f5e, 0x9f5e, 0xa69b, 0xe942, 0xda06, 0x9f1e, 0x9f5e, 0xae19, 0x9f5e,
-    0xbc91, 0xe942, 0xcb4c, 0xa639, 0xbcf3, 0x9f3e, 0xd9e5, 0x9f1d, 0x9f5e, 0x9f5e, 0x9f5e, 0xd2ea,
-    0xcb6c, 0xae18, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xe727, 0xff20, 0xff20, 0xcdc0, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0xace0, 0xff20, 0xff20, 0xef26, 0xe728, 0xf724, 0xff21, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xf6c0, 0xbd40, 0x83a0, 0x4200, 0x0860,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x1080, 0xff00, 0x0840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x18c0, 0x3180, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4a00, 0xff20,
-    0xff20, 0xef26, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc450, 0xadb7, 0x9f5e, 0x9f1d, 0xe183, 0xe942,
-    0xb576, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f1d, 0xe1e5, 0xc450, 0x9f5e, 0x9f5e, 0xa67a, 0xe1c4, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xa67a, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa6bc, 0xb534, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa73b, 0xff21, 0xff20, 0xff20,
-    0x62a0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x39a0, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff00, 0xcdc0, 0x9c40, 0x62c0, 0x2940, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd5e0, 0x39a0, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2940, 0xff20, 0xff00, 0x18a0, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x1080, 0xff20, 0xff20, 0xf722, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xadd7, 0xd2ea, 0xb534, 0x9f5e, 0x9f5e, 0xa65a, 0xda06,
-    0x9f3e, 0x9f5e, 0xc40f, 0xe942, 0xcb8d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc430, 0xb576, 0x9f5e,
-    0x9f5e, 0xadf8, 0xda47, 0xbcb2, 0xd30b, 0xcb4c, 0xbc71, 0xa6dc, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xc3ee, 0xe942, 0xa69b, 0xbcd3, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xcf2f, 0xff20, 0xff20, 0xe680, 0x1060, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc5a0,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xe660, 0xace0, 0x7320, 0xacc0, 0xc560, 0x0840, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x9420, 0x83a0, 0x5260, 0x2920, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xe681, 0xff20,
-    0x62c0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xf6c0, 0xff20, 0xff20, 0x9f5d, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa6bc, 0xd2c9, 0xe922, 0xe163,
-    0xa6dc, 0xadb7, 0x9f5e, 0xadd7, 0x9f5e, 0x9f5e, 0x9efd, 0xe1c5, 0xe1a4, 0x9f1e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xb576, 0xa6bb, 0xae39, 0xbcf3, 0xbcd3, 0xe922, 0xe922, 0xe922, 0xe922, 0xe922, 0xe163,
-    0xb514, 0x9f5e, 0x9f5e, 0xa6dc, 0x9f1e, 0xad97, 0x9f5e, 0xa67b, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xa73b, 0xbf34, 0xc731, 0xd72d, 0xdf2b, 0xdf2a, 0xff20, 0xff20, 0xff20, 0x8be0, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x5aa0, 0xff20, 0xff20, 0xff20, 0x7320, 0x1080, 0x0000, 0x0000, 0x0000,
-    0x9420, 0xee80, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x62c0, 0xff20, 0xff20, 0x7340, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x8be1, 0xa4a0, 0x2920, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0860, 0xff00,
-    0xff20, 0xff21, 0x9f5d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xadf8, 0xe922, 0xe922, 0xe922, 0xe184, 0xd288, 0x9f1d, 0x9f1d, 0x9f5e, 0x9f3e, 0x9f1d, 0xbc92,
-    0xe922, 0xc3ce, 0x9f3e, 0x9efd, 0x9f5e, 0x9f5e, 0x9f5e, 0xd30b, 0xe922, 0xe143, 0xe922, 0xe922,
-    0xe922, 0xe922, 0xe922, 0xe922, 0xe943, 0xa65a, 0xa69b, 0xe922, 0xe184, 0x9edc, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xb737, 0xd72e, 0xef25, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xf6e0, 0x18e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0840, 0xee80, 0xff20, 0xff20, 0x62c0,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xa4a0, 0xff20, 0x18e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x20e0, 0x5a80, 0xbd40, 0xff20, 0xff20,
-    0xa4a0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x83c0, 0xff20, 0xff20, 0xef27, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0xb514, 0xd2c9, 0xda06, 0xe163, 0xe1e5, 0xe922, 0xe902, 0xd9e6, 0xd30b, 0xa69b,
-    0x9f5e, 0xb555, 0xe1a4, 0xcb8d, 0xe902, 0xe902, 0xe143, 0xe902, 0xda47, 0xadd8, 0xa65a, 0xe942,
-    0xe902, 0xe902, 0xe902, 0xe902, 0xe902, 0xe902, 0xe902, 0xe902, 0xe902, 0xbc51, 0x9f5e, 0xbc51,
-    0xcb8d, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0xa73a, 0xcf2f, 0xf724, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0x9c40, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x83a0, 0xff20, 0xff20, 0x6ae0, 0x0000, 0x0000, 0x0000, 0x0000, 0xb500, 0xff20, 0x4a00, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3160, 0x6b00, 0xa4a0, 0xe640, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xd5e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4a20, 0xff20, 0xff20, 0xff20, 0xbf34, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb596, 0xcbce, 0xb535, 0x9efd, 0x9f5e, 0xa69b,
-    0xbcb2, 0x9edd, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb514, 0xe902, 0xe902, 0xe902, 0xe902, 0xe902,
-    0xe902, 0xe902, 0xe902, 0xe902, 0xe902, 0xe902, 0xe902, 0xe902, 0xe902, 0xe902, 0xe902, 0xe902,
-    0xe902, 0xbcf4, 0x9f5e, 0x9f5e, 0x9f5e, 0x9efd, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa73b, 0xdf2b, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xf6e0, 0xc560, 0x8be0, 0x62c0, 0x5a80, 0x6ae0, 0xb500, 0xff20, 0xff20, 0xff20, 0xff00, 0x18c0,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x18a0, 0xff00, 0xff20, 0x6b00, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xc560, 0xff20, 0x8be0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd600,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff00, 0x0840, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1080, 0xf6e0, 0xff20,
-    0xff20, 0xaf39, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xb514, 0xd9e6, 0xd30b, 0xb556, 0x9f5e, 0x9f5e, 0x9f5e, 0xbc92, 0xe8e2,
-    0xe8e2, 0xe8e2, 0xe8e2, 0xe8e2, 0xe8e2, 0xe8e2, 0xe8e2, 0xe8e2, 0xe8e2, 0xe8e2, 0xe8e2, 0xe8e2,
-    0xe8e2, 0xe8e2, 0xe8e2, 0xe8e2, 0xe1c5, 0x9efd, 0x9f5e, 0x9f5e, 0xb514, 0xe923, 0xe1c6, 0xa67b,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbf34, 0xff22, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xee80, 0x9440, 0x4a00, 0x0840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5a80,
-    0xff00, 0xff20, 0xff20, 0x8be0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xacc0, 0xff20, 0x7340,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xd5e0, 0xff20, 0xee80, 0xd5e0, 0xace0, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xacc0, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0x41e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3160, 0x62e0, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x9c60, 0xff20, 0xff20, 0xd72d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xadb7, 0xe902, 0xe8e1, 0xe8e1, 0xe8e1, 0xd30b,
-    0x9f5e, 0x9f5e, 0xc3ce, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1,
-    0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe164, 0xae19, 0x9f5e, 0x9f5e, 0xae19,
-    0xe922, 0xe8e1, 0xe8e1, 0xc3cf, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb737, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xace0, 0x4a20, 0x0020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x7b60, 0xff20, 0xff20, 0xf6c0, 0x1060, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x39a0, 0xff20, 0x7b60, 0x0000, 0x0000, 0x0000, 0x0000, 0xe640, 0xff20, 0xff20, 0xff20,
-    0xf6c0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x83a0, 0xff20, 0xff20, 0xde40, 0xb520,
-    0x8be0, 0x62a0, 0xc580, 0xff20, 0xff20, 0x7b80, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2940,
-    0xff20, 0x7320, 0x0000, 0x0000, 0x0000, 0x0000, 0x3180, 0xff20, 0xff20, 0xff21, 0xa73c, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa73b, 0xbf35, 0xa75c, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xd2aa, 0xe8e1,
-    0xe8e1, 0xe8e1, 0xcb4c, 0xbc92, 0x9f5e, 0x9f5e, 0xadd8, 0xe902, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1,
-    0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e1, 0xe8e2, 0xadb7,
-    0x9f5e, 0x9f5e, 0xadf8, 0xe164, 0xe8e1, 0xe8e1, 0xe8e1, 0xc410, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0xef25, 0xff20, 0xff20, 0xde20, 0x3980, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0020, 0xde40, 0xff20, 0xff20,
-    0x7340, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd5e0, 0x7b80, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xf6c0, 0xff20, 0xff20, 0xff20, 0xff20, 0x20e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x20e0,
-    0x3180, 0x0840, 0x0000, 0x0000, 0x0000, 0x0000, 0x62a0, 0xff20, 0xff20, 0xb500, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xf6c0, 0xeea0, 0x0860, 0x0000, 0x0000, 0x0000, 0x0000, 0xcdc0,
-    0xff20, 0xff20, 0xd72c, 0x9f5e, 0xaf39, 0xbf33, 0xd72e, 0xe728, 0xf723, 0xff20, 0xff20, 0xbf35,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0xe1a5, 0xe8c1, 0xe8c1, 0xd289, 0x9f5e, 0x9f5e, 0x9f3e, 0xda48, 0xe8c1, 0xe8c1,
-    0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1,
-    0xe8c1, 0xe8c1, 0xd2ca, 0x9f5e, 0x9f5e, 0xbcf4, 0xe902, 0xe8c1, 0xe8c1, 0xda07, 0xc3cf, 0x9efd,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb738, 0xff20, 0xff20, 0xeea0, 0x18a0, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x8be0, 0xff20, 0xff20, 0xde40, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7b80, 0x83c0,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0x4a20, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3180, 0xff20,
-    0xff20, 0xe660, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc580, 0xff20, 0x6b00, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x62a0, 0xff20, 0xff20, 0xff20, 0xff22, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xd72e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xcb2c, 0xe8c1, 0xe8c1, 0xad97, 0x9f5e, 0x9f5e,
-    0x9f1e, 0xb4f4, 0xe902, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1,
-    0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe8c1, 0xe184, 0xa65a, 0xb514, 0xd9c6, 0xc3cf, 0xcb4c,
-    0xd2eb, 0x9efd, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc731, 0xff20, 0xff20,
-    0xa480, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x18c0, 0x5260, 0x7b60, 0x62c0,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x41c0, 0xff20, 0xff20, 0xff20, 0x5260, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x2100, 0x8be0, 0x0000, 0x0000, 0x0000, 0x1080, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0x83a0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x1080, 0x5aa0, 0xff20, 0xff20, 0xff20, 0x18e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x9420, 0xff20, 0xd5e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbd60, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xef26, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9edc, 0xc431,
-    0xadf8, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f3e, 0xbcf4, 0xe8e2, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1,
-    0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8c2, 0x9f1d,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f1e, 0xbcb3, 0xc410, 0x9efd, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xcf30, 0xff20, 0xff20, 0x83c0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x18c0,
-    0xf6e0, 0xff20, 0xff20, 0xff20, 0x2940, 0x0000, 0x0000, 0x0000, 0x0000, 0x0020, 0xf6c0, 0xff20,
-    0xff20, 0xc560, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5aa0, 0x0000, 0x0000, 0x0000, 0x2100,
-    0xff20, 0xff20, 0xff21, 0xff20, 0xff20, 0xbd40, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x3180, 0x7b80, 0xacc0, 0xde20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0x5a80, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x62a0, 0xff20, 0xff20, 0x2100, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0860, 0xacc0, 0xff20, 0xf6c0, 0xd600, 0xacc0, 0x7b60, 0x4a20, 0x2100, 0xff00, 0xff20, 0xff20,
-    0x9f5d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f1d, 0xc431, 0xe1a5, 0xe8a1, 0xe8a1, 0xe8a1,
-    0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1, 0xe8a1,
-    0xe8a1, 0xe8a1, 0xe8a1, 0xcb0c, 0x9f5e, 0x9f5e, 0xbcd3, 0xbcf4, 0xadb7, 0xa67a, 0x9efd, 0xc3cf,
-    0xcb6d, 0x9edc, 0x9f5e, 0x9f5e, 0x9f5e, 0xc732, 0xff20, 0xff20, 0x9420, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x4a20, 0xff20, 0xff20, 0xff20, 0xff20, 0x7320, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0xace0, 0xff20, 0xff20, 0xff20, 0x2920, 0x0000, 0x0000, 0x0000, 0x0000, 0x1080,
-    0x0000, 0x0000, 0x0000, 0x3160, 0xff20, 0xff20, 0xef25, 0xff20, 0xff20, 0xeea0, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xde20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xa480, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2940, 0xff20, 0xff20, 0x83a0,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xc580, 0xff20, 0xff20, 0xbf35, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb514, 0xe123, 0xe881,
-    0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881,
-    0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xadb7, 0x9f5e, 0x9edd, 0xc431,
-    0xd269, 0xe903, 0xe8e2, 0xd269, 0xbcb3, 0x9f3e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbf34, 0xff20, 0xff20,
-    0xacc0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x41c0, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xb4e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x62c0, 0xff20, 0xff20, 0xff20, 0x9c40, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x41c0, 0xff20, 0xff20, 0xe728, 0xf723,
-    0xff20, 0xff20, 0x20e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xde20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff21, 0xff20, 0xff20, 0xff20, 0xff20, 0xee80, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0xf6c0, 0xff20, 0xeea0, 0x10a0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8bc0, 0xff20, 0xff20, 0xcf2e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xadb7, 0xe8a1, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881,
-    0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881,
-    0xe8a1, 0xd9e7, 0xcb2c, 0x9f5e, 0x9f5e, 0x9f5e, 0xadd8, 0xcb8e, 0xe903, 0xe1a6, 0xadf8, 0xd2cb,
-    0xcb4d, 0xb716, 0xff00, 0xff20, 0xbd40, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x18c0,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xeea0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2920, 0xff20,
-    0xff20, 0xff20, 0xf6e0, 0x1060, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5240,
-    0xff20, 0xff20, 0xdf2a, 0xe728, 0xff20, 0xff20, 0x5a80, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0xb500, 0xff20, 0xff20, 0xff21, 0xff22, 0xff21, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0x41e0,
-    0x2920, 0x39a0, 0x4a00, 0x62a0, 0x8be0, 0xeea0, 0xff20, 0xff20, 0xc580, 0x0860, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4a00, 0xff20, 0xff20,
-    0xef26, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xadf8, 0xe8a1, 0xe881, 0xe881, 0xe881, 0xe8e3, 0xe881, 0xe881,
-    0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881,
-    0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xe881, 0xc3af, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f1d, 0xbc51, 0xda28, 0x9f1e, 0xb515, 0xc470, 0xf3a0, 0xff20, 0xd600, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0xe640, 0xff20, 0xff20, 0xff20, 0xff20, 0x3160, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xeea0, 0xff20, 0xff20, 0xff20, 0x6b00, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x62a0, 0xff20, 0xff20, 0xd72c, 0xcf2f, 0xff20, 0xff20, 0x8be0, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x8c00, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xeea0, 0xb520, 0x7b60, 0x41c0, 0xff00, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xd5e0, 0x3160, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1060, 0x41e0,
-    0x7b80, 0xc560, 0xff20, 0xff20, 0xff21, 0x9f5d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa65a, 0xbcb3, 0xbcd3,
-    0xae19, 0x9f5e, 0xbc72, 0xcb6d, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060,
-    0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xe165, 0xa65a,
-    0x9f5e, 0x9f5e, 0xc410, 0xe165, 0xe165, 0xa69b, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xff20, 0xff20,
-    0xf6e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xa480, 0xff20, 0xff20, 0xff20,
-    0xff20, 0x83a0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc560, 0xff20, 0xff20, 0xff20, 0xd5e0,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7320, 0xff20, 0xff20, 0xcf2e, 0xbf34,
-    0xff20, 0xff20, 0xb520, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6ae0, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff00, 0xcda0, 0x9420, 0x5a80, 0x2120, 0x0000, 0x0000, 0x0000, 0x0000, 0xde00, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xcdc0, 0x9c60, 0x8380, 0x83a0, 0x9420,
-    0xb500, 0xde20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xb736, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa6bc, 0x9f5e, 0xa6bc, 0xf060, 0xf060, 0xf060, 0xf060,
-    0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060, 0xf060,
-    0xf060, 0xf060, 0xf060, 0xe185, 0x9f1d, 0x9f5e, 0xc3f0, 0xe881, 0xf060, 0xe8e2, 0xd2cb, 0xcb6d,
-    0x9efd, 0x9f5e, 0xf723, 0xff20, 0xff20, 0x2100, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x62c0, 0xff20, 0xff20, 0xff20, 0xff20, 0xcdc0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9420,
-    0xff20, 0xff20, 0xff20, 0xff20, 0x5240, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x83a0,
-    0xff20, 0xff20, 0xcf30, 0xaf3a, 0xff20, 0xff20, 0xde40, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x39a0, 0xd600, 0xb500, 0x7b60, 0x41e0, 0x0860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0xbd20, 0xff20, 0xff20, 0xcf2f, 0xa73a, 0xc733, 0xff21, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff22,
-    0xe728, 0xaf38, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f1e, 0xcb8e, 0xe144, 0xd9e7, 0xbc93, 0xbc51, 0xe881, 0xae19, 0xa69b,
-    0xd2cb, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040,
-    0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xbcb3, 0x9f5e, 0x9edc, 0xe8a2,
-    0xf040, 0xd269, 0xc431, 0xcb2d, 0x9f1e, 0x9f5e, 0xe729, 0xff20, 0xff20, 0x5260, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x2100, 0xff20, 0xff20, 0xff20, 0xff20, 0xd5e0, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x7340, 0xff20, 0xff20, 0xff20, 0xff20, 0xc580, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0xc580, 0xff20, 0xff20, 0xc732, 0x9f5e, 0xff21, 0xff20, 0xff20, 0x0840,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9c60, 0xff20, 0xff20, 0xc732, 0x9f5e, 0x9f5e,
-    0xaf39, 0xe729, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff22, 0xe727,
-    0xd72e, 0xbf34, 0xaf39, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa67a, 0xf040, 0xf040, 0xf040, 0xf061,
-    0xa6bc, 0x9edc, 0x9f5e, 0x9f5e, 0xc411, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040,
-    0xf040, 0xadf8, 0xbc52, 0xe144, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040, 0xf040,
-    0xcb8e, 0x9f5e, 0xb4f4, 0xe881, 0xbc52, 0x9edd, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xcf30, 0xff20,
-    0xff20, 0x9420, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbd60, 0xff20, 0xff20,
-    0xf6c0, 0x5260, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x83a0, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0x5a80, 0x2920, 0x4a20, 0x7b80, 0xbd20, 0xeec0, 0xff20, 0xff20, 0xff21, 0xa73c, 0x9f5e,
-    0xef25, 0xff20, 0xff20, 0x3160, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1080, 0x5240, 0xc580, 0xff20,
-    0xff20, 0xcf30, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5d, 0xb736, 0xcf30, 0xcf2f, 0xcf30, 0xc731,
-    0xbf34, 0xaf39, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xcb8e, 0xf020, 0xf020, 0xe861, 0x9efd, 0x9f5e, 0x9f5e, 0xb515, 0xf040, 0xe8a2, 0xd2ab, 0xf020,
-    0xf020, 0xf020, 0xf020, 0xf040, 0xd9c7, 0x9f1d, 0x9f5e, 0x9f3e, 0xc3d0, 0xe903, 0xb556, 0xe8a2,
-    0xf020, 0xf020, 0xf020, 0xe8e3, 0xae19, 0x9f5e, 0x9f3e, 0xa65a, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0xb737, 0xff20, 0xff20, 0xde40, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x1080, 0x6ae0, 0x4a20, 0x0860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbd40,
-    0xff20, 0xff20, 0xef25, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xd72c, 0x9f5e, 0x9f5e, 0xdf2a, 0xff20, 0xff20, 0x62a0, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1080, 0x5240, 0x9c40, 0xd5e0,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xdf2b, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xadd8, 0xc411, 0xad97, 0x9f5e, 0x9f5e, 0xa6bc, 0xe8a2,
-    0xf020, 0xc3af, 0x9f5e, 0xe8a2, 0xf020, 0xf020, 0xf020, 0xc3d0, 0x9f5e, 0x9f1d, 0xbc31, 0xa69b,
-    0x9f5e, 0xe165, 0xa6bc, 0xc3f0, 0xf020, 0xf020, 0xd2aa, 0x9efd, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xf723, 0xff20, 0xff20, 0x39c0, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x39a0, 0xff20, 0xff20, 0xff21, 0xaf39, 0xff21, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xf722, 0xe729, 0xaf3a, 0x9f5e, 0x9f5e, 0xc730, 0xff20, 0xff20, 0x9420,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x18a0, 0x4a20, 0x83c0, 0xcda0,
-    0xff00, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xdf2c, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xcb2d, 0xa63a, 0xbc52, 0x9f5e, 0x9f5e, 0xadb8,
-    0x9f5e, 0x9f5e, 0xad97, 0xf020, 0xf020, 0xb4f4, 0xadf9, 0xf020, 0xf020, 0xf020, 0xf020, 0xadd8,
-    0x9f5e, 0xadf9, 0xf020, 0xe882, 0xc3f0, 0xadb7, 0x9f3e, 0x9efd, 0xe8e3, 0xf020, 0xb515, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xd72d,
-    0xff20, 0xff20, 0xc5a0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3180, 0xee80, 0xff20, 0xff20, 0xd72d, 0x9f5e, 0xa73a,
-    0xdf2c, 0xef27, 0xef27, 0xe729, 0xd72e, 0xbf35, 0xa73b, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xb736, 0xff20, 0xff20, 0xc5a0, 0x0000, 0x0000, 0x0840, 0x39a0, 0x62c0, 0x8be0, 0xb500, 0xe660,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xe727, 0xd72e, 0xbf35,
-    0xa73c, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa67b, 0xe861, 0xcb4e,
-    0xa67b, 0x9f5e, 0xbc93, 0xa69b, 0xd9c7, 0xad97, 0x9f5e, 0xbc93, 0xbcd4, 0x9f5e, 0xcb2d, 0xf000,
-    0xf000, 0xf000, 0xb535, 0x9f5e, 0x9f5e, 0xadd8, 0xf000, 0xf000, 0xf000, 0xf000, 0xda29, 0x9f3e,
-    0xadf8, 0xcb2d, 0x9efd, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0xa73b, 0xff21, 0xff20, 0xff20, 0xa480, 0x0000, 0x0000, 0x0000, 0x0000,
-    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x20e0, 0x9400, 0xff00, 0xff20, 0xff20,
-    0xf724, 0x9f5d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5d, 0xff20, 0xff20, 0xf6e0, 0x7b80, 0xbd40, 0xf6e0, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff21, 0xe728, 0xcf30, 0xb735,
-    0x9f5d, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xa69b, 0xbc72, 0xa6bc, 0xae19, 0x9f5e, 0xadb8, 0xb556, 0x9f1e, 0xf020, 0xda29, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0xe186, 0xf000, 0xf000, 0xd249, 0x9f5e, 0x9f5e, 0x9f5e, 0xa67b, 0xf000, 0xf000,
-    0xf000, 0xf000, 0xf000, 0xadb7, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc731, 0xff20, 0xff20, 0xff20,
-    0xbd40, 0x2920, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0020, 0x3180, 0x7340, 0xc560, 0xff00,
-    0xff20, 0xff20, 0xff20, 0xef25, 0xa73a, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xf724, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff22, 0xe728, 0xd72d, 0xb736,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xd9a7, 0xbc93, 0x9f5e, 0x9f5e, 0x9f5e, 0xa69c, 0xa6bc, 0x9f5e,
-    0xbc32, 0xbcb3, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbcb3, 0xd9c7, 0xd28b, 0xa65b, 0xcb4e, 0xa67b,
-    0x9f5e, 0x9f5e, 0xe8c3, 0xf000, 0xf000, 0xf000, 0xf000, 0xadf8, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xcf2f, 0xff20, 0xff20, 0xff20, 0xff20, 0xd5e0, 0xace0, 0xa4a0, 0xb500, 0xd5e0, 0xff00,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xdf2a, 0xa73c, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0xdf2a, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xef26, 0xdf29, 0xcf2f, 0xbf33, 0xaf39,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa67b, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0xc3f0, 0xe186, 0xc3af, 0xc411, 0x9f5e, 0x9f5e, 0xb515, 0xf041, 0xf000, 0xe166, 0xbcb3, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xbf33, 0xf722, 0xff20, 0xff20, 0xff20, 0xff20,
-    0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff20, 0xff21, 0xdf2b, 0xaf38, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xc731, 0xff21, 0xe727, 0xd72e, 0xbf35, 0xa75c, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xb4f5, 0xbc52, 0xc38f, 0xa6bc, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5d,
-    0xcf30, 0xef27, 0xff21, 0xff20, 0xff20, 0xff20, 0xff20, 0xff21, 0xef26, 0xd72e, 0xbf35, 0x9f5d,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5d, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0xa73a, 0xb737, 0xb737, 0xaf39, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-    0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e, 0x9f5e,
-];
\ No newline at end of file
+include!(concat!(env!("OUT_DIR"), "/assets.rs"));
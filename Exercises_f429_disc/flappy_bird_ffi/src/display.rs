@@ -1,8 +1,9 @@
 use crate::config::*;
 use core::convert::TryInto;
-use core::ffi;
 // Color constants not used in this module; remove to silence warnings.
 use crate::display_ffi;
+use crate::font::{self, Align, TextStyle, TextWriter};
+use crate::image::{self, Image, Rect};
 
 extern "C" {
     fn display_register_driver(driver: *const DisplayDriver);
@@ -10,44 +11,40 @@ extern "C" {
     fn display_draw_image(x: u16, w: u16, y: u16, h: u16, img_data: *const u16);
     fn display_fill_screen(color: u16);
     fn display_fill_rect(x: u16, w: u16, y: u16, h: u16, color: u16);
-    fn display_write_string(
-        x: u16,
-        y: u16,
-        str_ptr: *const ffi::c_char,
-        font: FontDef,
-        color: u16,
-        bgcolor: u16,
-    );
-
-    static Font_16x26: FontDef;
+    fn display_draw_pixel(x: u16, y: u16, color: u16);
 
     fn display_game_title();
-    fn ili9341_write_string(
-        x: i32,
-        y: i32,
-        text: *const u8,
-        font: FontDef,
-        fg_color: u16,
-        bg_color: u16,
-    );
 }
 
-#[repr(C)]
-pub struct DisplayDriver {
-    __private: [u8; 0],
+// low level drawing operations of the panel.
+// display_ffi implements them for the board, tests can plug in a framebuffer
+pub trait DisplayBackend {
+    fn draw_pixel(&mut self, x: u16, y: u16, color: u16);
+    fn fill_rectangle(&mut self, x: u16, w: u16, y: u16, h: u16, color: u16);
+    fn draw_image(&mut self, x: u16, w: u16, y: u16, h: u16, image_data: &[u16]);
 }
 
-#[derive(Copy, Clone)]
-#[repr(C)]
-pub struct FontDef {
-    width: ffi::c_uchar,
-    height: ffi::c_uchar,
-    data: *const u16,
+// backend which forwards to the display_ffi layer
+pub struct FfiBackend;
+
+impl DisplayBackend for FfiBackend {
+    fn draw_pixel(&mut self, x: u16, y: u16, color: u16) {
+        display_ffi::draw_pixel(x, y, color);
+    }
+
+    fn fill_rectangle(&mut self, x: u16, w: u16, y: u16, h: u16, color: u16) {
+        display_ffi::fill_rect(x, w, y, h, color);
+    }
+
+    fn draw_image(&mut self, x: u16, w: u16, y: u16, h: u16, image_data: &[u16]) {
+        assert!(image_data.len() >= w as usize * h as usize, "image data too short");
+        display_ffi::draw_image_u16(x, w, y, h, image_data);
+    }
 }
 
-// Provide a const constructor so other modules can define the `Font_16x26` symbol.
-pub const fn font_16x26() -> FontDef {
-    FontDef { width: 16, height: 26, data: core::ptr::null() }
+#[repr(C)]
+pub struct DisplayDriver {
+    __private: [u8; 0],
 }
 
 pub fn register_driver(driver: &DisplayDriver) {
@@ -58,12 +55,13 @@ pub fn init() {
     display_ffi::init();
 }
 
-pub fn draw_image(x: Coord, w: u32, y: Coord, h: u32, image_data: &[u16]) {
-    let x: u16 = x.try_into().expect("X co-ordinate is out of range");
-    let y: u16 = y.try_into().expect("y co-ordinate is out of range");
-    let w: u16 = w.try_into().expect("width out of range");
-    let h: u16 = h.try_into().expect("height out of range");
-    display_ffi::draw_image_u16(x, w, y, h, image_data);
+pub fn draw_image(x: Coord, y: Coord, image: &Image) {
+    image::blit(&mut FfiBackend, x, y, image, image.bounds());
+}
+
+//draws only the `src` part of the image, its top left corner goes to (x, y)
+pub fn draw_image_region(x: Coord, y: Coord, image: &Image, src: Rect) {
+    image::blit(&mut FfiBackend, x, y, image, src);
 }
 
 pub fn set_background_color(bg_color: u16) {
@@ -78,8 +76,16 @@ pub fn draw_rect_angle(x: Coord, w: u32, y: Coord, h: u32, color: u16) {
     display_ffi::fill_rect(x, w, y, h, color);
 }
 
-pub fn write_string(x: Coord, y: Coord, c_str: &ffi::CStr, color: u16, bgcolor: u16) {
-    let x: u16 = x.try_into().expect("X co-ordinate is out of range");
-    let y: u16 = y.try_into().expect("y co-ordinate is out of range");
-    display_ffi::write_string_u16(x, y, c_str, color, bgcolor);
-}
\ No newline at end of file
+pub fn write_string(x: Coord, y: Coord, text: &str, style: &TextStyle) {
+    font::draw_str(&mut FfiBackend, x, y, Align::Left, text, style);
+}
+
+//`x` is the left edge, the centre or the right edge of the text depending on `align`
+pub fn write_string_aligned(x: Coord, y: Coord, align: Align, text: &str, style: &TextStyle) {
+    font::draw_str(&mut FfiBackend, x, y, align, text, style);
+}
+
+//text cursor at (x, y) for write!()
+pub fn text_writer<'a>(x: Coord, y: Coord, style: TextStyle<'a>) -> TextWriter<'a, FfiBackend> {
+    TextWriter::new(FfiBackend, x, y, style)
+}
@@ -1,10 +1,9 @@
-use core::ffi;
-use crate::display::{DisplayDriver, FontDef};
+use crate::display::DisplayDriver;
 use rtt_target::rprintln;
-// Allow using FontDef in a static; raw pointers are assumed valid for FFI.
-unsafe impl Sync for FontDef {}
 
 // Provide Rust-defined extern "C" stubs so linking succeeds without C.
+// The drawing stubs trace their calls with the log feature only, a print per pixel
+// stalls the game loop.
 #[no_mangle]
 pub extern "C" fn display_register_driver(driver: *const DisplayDriver) {
     rprintln!("display_register_driver: {:p}", driver);
@@ -15,29 +14,30 @@ pub extern "C" fn display_init() {}
 
 #[no_mangle]
 pub extern "C" fn display_draw_image(x: u16, w: u16, y: u16, h: u16, img_data: *const u16) {
-    rprintln!("display_draw_image: x={}, y={}, w={}, h={}, data={:p}", x, y, w, h, img_data);
+    if cfg!(feature = "log") {
+        rprintln!("display_draw_image: x={}, y={}, w={}, h={}, data={:p}", x, y, w, h, img_data);
+    }
 }
 
 #[no_mangle]
 pub extern "C" fn display_fill_screen(color: u16) {
-    rprintln!("display_fill_screen: color=0x{:04X}", color);
+    if cfg!(feature = "log") {
+        rprintln!("display_fill_screen: color=0x{:04X}", color);
+    }
 }
 
 #[no_mangle]
 pub extern "C" fn display_fill_rect(x: u16, w: u16, y: u16, h: u16, color: u16) {
-    rprintln!("display_fill_rect: x={}, y={}, w={}, h={}, color=0x{:04X}", x, y, w, h, color);
+    if cfg!(feature = "log") {
+        rprintln!("display_fill_rect: x={}, y={}, w={}, h={}, color=0x{:04X}", x, y, w, h, color);
+    }
 }
 
 #[no_mangle]
-pub extern "C" fn display_write_string(
-    x: u16,
-    y: u16,
-    str_ptr: *const ffi::c_char,
-    font: FontDef,
-    color: u16,
-    bgcolor: u16,
-) {
-    rprintln!("display_write_string: x={}, y={}, color=0x{:04X}, bg=0x{:04X}", x, y, color, bgcolor);
+pub extern "C" fn display_draw_pixel(x: u16, y: u16, color: u16) {
+    if cfg!(feature = "log") {
+        rprintln!("display_draw_pixel: x={}, y={}, color=0x{:04X}", x, y, color);
+    }
 }
 
 
@@ -62,14 +62,6 @@ pub fn fill_rect(x: u16, w: u16, y: u16, h: u16, color: u16) {
     display_fill_rect(x, w, y, h, color);
 }
 
-pub fn write_string_u16(x: u16, y: u16, c_str: &ffi::CStr, color: u16, bgcolor: u16) {
-    extern "C" {
-        static Font_16x26: crate::display::FontDef;
-    }
-    // Use our Rust-defined symbol to satisfy the extern reference.
-    display_write_string(x, y, c_str.as_ptr(), unsafe { Font_16x26 }, color, bgcolor);
+pub fn draw_pixel(x: u16, y: u16, color: u16) {
+    display_draw_pixel(x, y, color);
 }
-
-// Define the `Font_16x26` symbol using the constructor in display.rs.
-#[no_mangle]
-pub static Font_16x26: crate::display::FontDef = crate::display::font_16x26();
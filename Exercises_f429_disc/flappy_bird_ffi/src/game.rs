@@ -1,4 +1,4 @@
-use core::ffi;
+use core::fmt::Write;
 
 use crate::assets;
 use crate::color;
@@ -9,6 +9,7 @@ use crate::config::{
     Coord
 };
 use crate::display;
+use crate::font::{self, TextStyle};
 use crate::obstacle;
 use crate::player;
 use core::sync::atomic::{AtomicU32, Ordering};
@@ -20,12 +21,98 @@ fn HAL_GetTick() -> u32 {
     TICK_MS.fetch_add(16, Ordering::Relaxed)
 }
 
+//current tick without advancing it, for input timestamps
+pub fn now_ms() -> u32 {
+    TICK_MS.load(Ordering::Relaxed)
+}
+
+// failed input reads in a row before the game gives up and shows the fault screen,
+// single glitches are skipped
+const FAULT_ERRORS: u32 = 5;
+// time between attempts to bring the input device back
+const FAULT_RETRY_MS: u32 = 1000;
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum GameState {
     Start,
     Running,
     End,
     Halt,
+    // the input device does not answer, retried every FAULT_RETRY_MS
+    Fault,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Gesture {
+    // short jolt of the board
+    Flick,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Input {
+    // position in the device's range, 0.0 = top .. 1.0 = bottom
+    Absolute(f32),
+    // movement since the last sample, in parts of the whole range, positive is down
+    Relative(f32),
+    // the player wants the bird to flap (button, tap)
+    Flap,
+    Gesture(Gesture),
+    // nothing new
+    None,
+}
+
+// one reading of an input device
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct InputSample {
+    // tick when the reading was taken
+    pub time_ms: u32,
+    pub input: Input,
+}
+
+impl InputSample {
+    pub fn new(input: Input, time_ms: u32) -> Self {
+        Self { time_ms, input }
+    }
+
+    //flaps and gestures, everything that flaps rather than moves
+    pub fn is_tap(&self) -> bool {
+        matches!(self.input, Input::Flap | Input::Gesture(_))
+    }
+}
+
+// last position of the input, built up from the samples and mapped to the screen by the game
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Pointer {
+    // 0.0 = top .. 1.0 = bottom
+    position: f32,
+}
+
+impl Pointer {
+    pub fn new() -> Self {
+        Self { position: 0.0 }
+    }
+
+    //takes the position out of `sample`, true if the sample moved the pointer
+    pub fn feed(&mut self, sample: &InputSample) -> bool {
+        match sample.input {
+            Input::Absolute(position) => self.position = position.clamp(0.0, 1.0),
+            Input::Relative(delta) => self.position = (self.position + delta).clamp(0.0, 1.0),
+            _ => return false,
+        }
+        true
+    }
+
+    //position on the screen, between y_min and y_max
+    pub fn y(&self, y_min: Coord, y_max: Coord) -> Coord {
+        let mapped = y_min as f32 + self.position * (y_max - y_min) as f32;
+        (mapped + 0.5) as Coord
+    }
+}
+
+impl Default for Pointer {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 pub trait InputDevice {
@@ -34,7 +121,8 @@ pub trait InputDevice {
     fn log_data(&mut self) {
 
     }
-    fn is_tap(&mut self, y_min: Coord, y_max: Coord) -> Result<(Coord, bool), Self::Error>;
+    //the next reading, Input::None if there is nothing new
+    fn sample(&mut self) -> Result<InputSample, Self::Error>;
 }
 
 pub struct Game<T: InputDevice> {
@@ -43,6 +131,10 @@ pub struct Game<T: InputDevice> {
     countdown_start_time: u32,
     obstacle: obstacle::Obstacle,
     player: player::Player,
+    pointer: Pointer,
+    // failed input reads in a row
+    input_errors: u32,
+    fault_time: u32,
     pub input_device: T,
 }
 
@@ -58,6 +150,9 @@ impl<T: InputDevice> Game<T> {
             countdown_start_time: 0,
             obstacle: obstacle::Obstacle::init(),
             player: player::Player::init(),
+            pointer: Pointer::new(),
+            input_errors: 0,
+            fault_time: 0,
             input_device, 
         };
 
@@ -77,21 +172,22 @@ impl<T: InputDevice> Game<T> {
                 
                 let (_, player_curr_y) = self.player.get_xy();
 
-                if let Ok(data) = self.input_device.is_tap(0, 239) {
-                   
-                   let new_y = data.0;
-                   let is_tap = data.1;
-
-                   if is_tap {
-                        self.player.move_player(new_y.clamp(PLAYER_Y_MIN, PLAYER_Y_MAX));
-                   } else {
-                        self.player.move_player(player_curr_y);
-                   }
-                    
+                // a failed read counts like one with nothing new
+                let moved = match self.read_input() {
+                    Some(sample) => self.pointer.feed(&sample),
+                    None => false,
+                };
+                if self.state == GameState::Fault {
+                    return;
+                }
+
+                if moved {
+                    let new_y = self.pointer.y(0, config::LCD_HEIGHT as Coord - 1);
+                    self.player.move_player(new_y.clamp(PLAYER_Y_MIN, PLAYER_Y_MAX));
                 } else {
-                    panic!("Input device error");
+                    self.player.move_player(player_curr_y);
                 }
-                
+
                 self.obstacle.move_obstacle();
 
                 if self.is_collison() {
@@ -110,19 +206,65 @@ impl<T: InputDevice> Game<T> {
             GameState::Halt => {
 
             }
+
+            GameState::Fault => {
+                let now = unsafe { HAL_GetTick() };
+                if now.wrapping_sub(self.fault_time) >= FAULT_RETRY_MS {
+                    self.fault_time = now;
+                    if self.input_device.init().is_ok() && self.input_device.sample().is_ok() {
+                        self.input_errors = 0;
+                        self.restart();
+                    }
+                }
+            }
         }
     }
 
+    //None if the read failed, after FAULT_ERRORS failures in a row the game is in the fault state
+    fn read_input(&mut self) -> Option<InputSample> {
+        match self.input_device.sample() {
+            Ok(sample) => {
+                self.input_errors = 0;
+                Some(sample)
+            }
+            Err(_) => {
+                self.input_errors += 1;
+                if self.input_errors >= FAULT_ERRORS {
+                    self.state = GameState::Fault;
+                    self.fault_time = now_ms();
+                    Game::<T>::draw_fault_screen();
+                }
+                None
+            }
+        }
+    }
+
+    // the round the fault broke off is lost, a new one starts with the countdown
+    fn restart(&mut self) {
+        self.score = 0;
+        self.countdown_start_time = 0;
+        self.obstacle = obstacle::Obstacle::init();
+        self.player = player::Player::init();
+        self.pointer = Pointer::new();
+        Game::<T>::draw_start_screen();
+        self.state = GameState::Start;
+    }
+
+    pub fn draw_fault_screen() {
+        Game::<T>::set_background();
+        display::write_string(0, 120, "Input device", &text_style(color::RED, color::BACKGROUND));
+        display::write_string(0, 156, "not answering", &text_style(color::RED, color::BACKGROUND));
+    }
+
     pub fn draw_game_over_screen() {
         Game::<T>::set_background();
-        display::draw_image(80, 160, 40, 80, &assets::GAME_OVER_IMAGE_DATA);
+        display::draw_image(80, 40, &assets::GAME_OVER_IMAGE);
     }
 
     pub fn draw_start_screen() {
         Game::<T>::set_background();
-        display::draw_image(40, 160, 40, 80, &assets::GAME_NAME_IMG_DATA);
-        let text = c"Game Starts In";
-        display::write_string(0, 120, text, color::RED, color::BACKGROUND);
+        display::draw_image(40, 40, &assets::GAME_NAME_IMG);
+        display::write_string(0, 120, "Game Starts In", &text_style(color::RED, color::BACKGROUND));
     }
 
     pub fn set_background() {
@@ -133,13 +275,13 @@ impl<T: InputDevice> Game<T> {
         print_score_card_background();
 
         //3. print the plant
-        display::draw_image(0, 60, 210, 30, &assets::PLANT_IMG_DATA);
-        display::draw_image(60, 60, 210, 30, &assets::PLANT_IMG_DATA);
-        display::draw_image(120, 60, 210, 30, &assets::PLANT_IMG_DATA);
-        display::draw_image(180, 60, 210, 30, &assets::PLANT_IMG_DATA);
-        display::draw_image(240, 60, 210, 30, &assets::PLANT_IMG_DATA);
-        display::draw_image(260, 60, 210, 30, &assets::PLANT_IMG_DATA);
-
+        let plant = &assets::PLANT_IMG;
+        let plant_y = (config::LCD_HEIGHT - plant.height()) as Coord;
+        for x in (0..config::LCD_WIDTH).step_by(plant.width() as usize) {
+            // the last plant ends flush with the right edge
+            let x = x.min(config::LCD_WIDTH - plant.width());
+            display::draw_image(x as Coord, plant_y, plant);
+        }
     }
 
     //returns 'true' if countdown is over , otherwise 'false'
@@ -150,17 +292,17 @@ impl<T: InputDevice> Game<T> {
 
         let elapsed = unsafe { HAL_GetTick() } - self.countdown_start_time;
         let number = if elapsed < 1000 {
-            c"3"
+            "3"
         } else if elapsed < 2000 {
-            c"2"
+            "2"
         } else if elapsed < 3000 {
-            c"1"
+            "1"
         } else {
             self.countdown_start_time = 0;
             return true;
         };
 
-        display::write_string(140, 156, number, color::BLACK, color::BACKGROUND);
+        display::write_string(140, 156, number, &text_style(color::BLACK, color::BACKGROUND));
 
         false
     }
@@ -219,31 +361,14 @@ impl<T: InputDevice> Game<T> {
     }
 
     fn show_score(&self, x: config::Coord, y: config::Coord) {
-        let mut buf = [0u8; 4];
+        let mut writer = display::text_writer(x, y, text_style(color::BLACK, color::SCORE));
 
-        let score = self.score;
-
-        if score >= 1000 {
-            buf[0] = b'W';
-            buf[1] = b'I';
-            buf[2] = b'N';
+        // drawing text never fails, the fmt::Result is always Ok
+        let _ = if self.score >= 1000 {
+            writer.write_str("WIN")
         } else {
-            buf[0] = b'0' + ((score / 100) % 10) as u8;
-            buf[1] = b'0' + ((score / 10) % 10) as u8;
-            buf[2] = b'0' + (score % 10) as u8;
-        }
-
-        buf[3] = b'\0';
-
-        let score_str = ffi::CStr::from_bytes_with_nul(&buf);
-            display::write_string(
-                x ,
-                y,
-                score_str.unwrap(),
-                color::BLACK,
-                color::SCORE,
-            );
-        
+            write!(writer, "{:03}", self.score)
+        };
     }
 
 
@@ -257,7 +382,72 @@ impl<T: InputDevice> Game<T> {
     }
 }
 
+fn text_style(color: u16, bgcolor: u16) -> TextStyle<'static> {
+    TextStyle {
+        font: &font::FONT_16X26,
+        color,
+        bgcolor,
+    }
+}
+
 pub fn print_score_card_background() {
     display::draw_rect_angle(0, 320, 0, 27, color::WHITE);
     display::draw_rect_angle(0, 320, 28, 2, color::BLACK);
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // answers with the middle of the range, fails every read and init while `down`
+    struct FlakyInput {
+        down: bool,
+    }
+
+    impl InputDevice for FlakyInput {
+        type Error = ();
+
+        fn init(&mut self) -> Result<(), Self::Error> {
+            if self.down { Err(()) } else { Ok(()) }
+        }
+
+        fn sample(&mut self) -> Result<InputSample, Self::Error> {
+            if self.down {
+                return Err(());
+            }
+            Ok(InputSample::new(Input::Absolute(0.5), now_ms()))
+        }
+    }
+
+    #[test]
+    fn failed_reads_fault_and_recover() {
+        let mut game = Game::init(FlakyInput { down: false }).unwrap();
+        game.state = GameState::Running;
+
+        // a glitch is skipped
+        game.input_device.down = true;
+        game.update();
+        game.input_device.down = false;
+        game.update();
+        assert_eq!((game.state, game.input_errors), (GameState::Running, 0));
+
+        game.input_device.down = true;
+        for _ in 1..FAULT_ERRORS {
+            game.update();
+            assert_eq!(game.state, GameState::Running);
+        }
+        game.update();
+        assert_eq!(game.state, GameState::Fault);
+
+        // retried, but still down
+        for _ in 0..200 {
+            game.update();
+        }
+        assert_eq!(game.state, GameState::Fault);
+
+        game.input_device.down = false;
+        for _ in 0..100 {
+            game.update();
+        }
+        assert_eq!((game.state, game.score), (GameState::Start, 0));
+    }
+}
@@ -0,0 +1,134 @@
+// InputDevice on the I3G4250D gyroscope.
+// the turn rate around one axis is integrated into Input::Relative steps, one
+// step of 1/odr seconds per new reading, so the turn does not depend on a clock:
+// turning the board by `range_deg` moves the bird from the top to the bottom.
+// the rate at rest (bias) is measured in init(), so keep the board still while
+// the game starts. integration drifts slowly, the screen edges catch it.
+
+use embedded_hal::blocking::spi::Transfer;
+use embedded_hal::digital::v2::OutputPin;
+#[cfg(feature = "log")]
+use rtt_target::rprintln;
+
+use crate::game::{self, Input, InputDevice, InputSample};
+use crate::i3g4250d::{Config, Error, I3g4250d};
+
+pub const DEFAULT_RANGE_DEG: f32 = 60.0;
+
+// readings averaged for the bias
+const BIAS_SAMPLES: u32 = 50;
+// data ready polls before init gives up on a bias reading
+const MAX_POLLS: u32 = 100_000;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+pub struct GyroInput<SPI, CS> {
+    pub gyro: I3g4250d<SPI, CS>,
+    config: Config,
+    axis: Axis,
+    // turn from the top to the bottom of the screen, negative for the other direction
+    range_deg: f32,
+    bias: f32,
+    clock: fn() -> u32,
+}
+
+impl<SPI, CS, SpiE, PinE> GyroInput<SPI, CS>
+where
+    SPI: Transfer<u8, Error = SpiE>,
+    CS: OutputPin<Error = PinE>,
+{
+    //tilting the board towards you (around x) moves the bird down
+    pub fn new(gyro: I3g4250d<SPI, CS>) -> Self {
+        Self {
+            gyro,
+            config: Config::default(),
+            axis: Axis::X,
+            range_deg: DEFAULT_RANGE_DEG,
+            bias: 0.0,
+            clock: game::now_ms,
+        }
+    }
+
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn axis(mut self, axis: Axis, range_deg: f32) -> Self {
+        self.axis = axis;
+        self.range_deg = range_deg;
+        self
+    }
+
+    //`clock` gives the milliseconds the samples are stamped with
+    pub fn clock(mut self, clock: fn() -> u32) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    //rate at rest in degrees/s, as measured by init()
+    pub fn bias(&self) -> f32 {
+        self.bias
+    }
+
+    fn rate(&mut self) -> Result<f32, Error<SpiE, PinE>> {
+        let dps = self.gyro.read_gyro_dps()?;
+        Ok(match self.axis {
+            Axis::X => dps[0],
+            Axis::Y => dps[1],
+            Axis::Z => dps[2],
+        })
+    }
+
+    fn measure_bias(&mut self) -> Result<f32, Error<SpiE, PinE>> {
+        let mut sum = 0.0;
+        let mut count = 0;
+        let mut polls = 0;
+        while count < BIAS_SAMPLES && polls < MAX_POLLS {
+            polls += 1;
+            if self.gyro.data_ready()? {
+                sum += self.rate()?;
+                count += 1;
+            }
+        }
+        Ok(if count > 0 { sum / count as f32 } else { 0.0 })
+    }
+}
+
+impl<SPI, CS, SpiE, PinE> InputDevice for GyroInput<SPI, CS>
+where
+    SPI: Transfer<u8, Error = SpiE>,
+    CS: OutputPin<Error = PinE>,
+{
+    type Error = Error<SpiE, PinE>;
+
+    fn init(&mut self) -> Result<(), Self::Error> {
+        self.gyro.init(self.config)?;
+        self.bias = self.measure_bias()?;
+        Ok(())
+    }
+
+    #[cfg(feature = "log")]
+    fn log_data(&mut self) {
+        if let Ok(gyro) = self.gyro.read_gyro_raw() {
+            rprintln!("gyro: X={}, Y={}, Z={}", gyro[0], gyro[1], gyro[2]);
+        }
+    }
+
+    //the turn since the last sample, Input::None without a new reading
+    fn sample(&mut self) -> Result<InputSample, Self::Error> {
+        let now = (self.clock)();
+        if !self.gyro.data_ready()? {
+            return Ok(InputSample::new(Input::None, now));
+        }
+
+        let rate = self.rate()? - self.bias;
+        let turn = rate / self.config.odr.hz() as f32;
+        Ok(InputSample::new(Input::Relative(turn / self.range_deg), now))
+    }
+}
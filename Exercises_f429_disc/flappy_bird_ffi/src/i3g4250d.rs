@@ -1,95 +1,394 @@
-// // Reads gyroscope sensor data from I3G4250D over SPI and maps movement to screen Y position.
-// //
-// // Steps:
-// // 1. Reads raw gyro data
-// // 2. Computes angular roll using atan2 from Y and Z axis
-// // 3. Maps roll to y-coordinate between y_min and y_max
-
-// use crate::{config::Coord, game::InputDevice};
-// use rtt_target::{rtt_init_print, rprintln};
-
-// use embedded_hal::digital::v2::OutputPin;
-// use embedded_hal::blocking::spi::Transfer;
-
-// //  Import the driver from the external crate
-// //use crate::i3g4250d::I3g4250d;
-
-// pub struct SensorInput<SPI, CS>
-// where
-//     SPI: Transfer<u8>,
-//     CS: OutputPin,
-// {
-//     pub gyro: I3g4250d<SPI, CS>,
-// }
-
-// impl<SPI, CS> SensorInput<SPI, CS>
-// where
-//     SPI: Transfer<u8>,
-//     CS: OutputPin,
-// {
-//     pub fn new(spi: SPI, cs: CS) -> Self {
-//         Self {
-//             gyro: I3g4250d::new(spi, cs),
-//         }
-//     }
-// }
-
-// impl<SPI, CS> InputDevice for SensorInput<SPI, CS>
-// where
-//     SPI: Transfer<u8>,
-//     CS: OutputPin,
-// {
-//     type Error = SPI::Error;
-
-//     fn init(&mut self) -> Result<(), Self::Error> {
-//         #[cfg(feature = "log")]
-//         rtt_init_print!();
-
-//         self.gyro.init().map_err(|_| {
-//             // You can replace this with a more graceful error conversion if needed
-//             panic!("Gyroscope initialization failed")
-//         })?;
-
-//         Ok(())
-//     }
-
-//     #[cfg(feature = "log")]
-//     fn log_data(&mut self) {
-//         if let Ok(gyro) = self.gyro.read_gyro_raw() {
-//             rprintln!("gyro: X={}, Y={}, Z={}", gyro[0], gyro[1], gyro[2]);
-//         }
-//     }
-
-//     fn is_tap(&mut self, y_min: Coord, y_max: Coord) -> Result<(Coord, bool), Self::Error> {
-//         let gyro_data = self.gyro.read_gyro_raw().map_err(|_| {
-//             panic!("Gyro read failed")
-//         })?;
-
-//         let roll_angle = get_roll_angle(gyro_data[1] as f32, gyro_data[2] as f32);
-
-//         let roll_min = -200.0;
-//         let roll_max = 200.0;
-
-//         let new_y = map_roll_angle_to_y(roll_angle, roll_min, roll_max, y_min, y_max);
-
-//         Ok((new_y as Coord, true))
-//     }
-// }
-
-// pub fn get_roll_angle(y: f32, z: f32) -> f32 {
-//     libm::atan2f(y, z) * (180.0_f32 / core::f32::consts::PI)
-// }
-
-// fn map_roll_angle_to_y(
-//     roll: f32,
-//     roll_min: f32,
-//     roll_max: f32,
-//     y_min: Coord,
-//     y_max: Coord,
-// ) -> Coord {
-//     let clamped_roll = roll.clamp(roll_min, roll_max);
-//     let normalized = (clamped_roll - roll_min) / (roll_max - roll_min);
-//     let y_range = (y_max - y_min) as f32;
-//     let mapped = y_min as f32 + normalized * y_range;
-//     (mapped + 0.5) as Coord
-// }
+// Driver for the I3G4250D gyroscope on the F429 Discovery (older boards carry
+// the register compatible L3GD20) over SPI with a chip-select pin.
+//
+// SPI frames: the first byte is the register address, bit 7 set for a read,
+// bit 6 set to auto-increment the address on multi-byte transfers.
+
+use embedded_hal::blocking::spi::Transfer;
+use embedded_hal::digital::v2::OutputPin;
+
+// registers
+pub const WHO_AM_I: u8 = 0x0F;
+pub const CTRL_REG1: u8 = 0x20;
+pub const CTRL_REG4: u8 = 0x23;
+pub const STATUS_REG: u8 = 0x27;
+pub const OUT_X_L: u8 = 0x28;
+
+// WHO_AM_I values
+pub const I3G4250D_ID: u8 = 0xD3;
+pub const L3GD20_ID: u8 = 0xD4;
+
+const READ: u8 = 0x80;
+const AUTO_INCREMENT: u8 = 0x40;
+// registers in one read_registers() transfer
+pub const MAX_READ: usize = 8;
+
+// CTRL_REG1: DR[7:6] BW[5:4] PD[3] Zen Yen Xen
+const POWER_ON: u8 = 0x08;
+const ALL_AXES: u8 = 0x07;
+// CTRL_REG4: block data update, the output registers hold until both bytes are read
+const BDU: u8 = 0x80;
+// STATUS_REG: new data on all axes
+const ZYXDA: u8 = 0x08;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Device {
+    I3g4250d,
+    L3gd20,
+}
+
+// output data rate
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Odr {
+    Hz100 = 0,
+    Hz200 = 1,
+    Hz400 = 2,
+    Hz800 = 3,
+}
+
+impl Odr {
+    pub fn hz(self) -> u32 {
+        100 << self as u32
+    }
+}
+
+// low pass cut-off, the frequency depends on the data rate (datasheet table 21),
+// e.g. 12.5 / 25 / 25 / 25 Hz at 100 Hz
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Bandwidth {
+    Narrowest = 0,
+    Narrow = 1,
+    Wide = 2,
+    Widest = 3,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FullScale {
+    // 250 dps on the L3GD20
+    Dps245 = 0,
+    Dps500 = 1,
+    Dps2000 = 2,
+}
+
+impl FullScale {
+    //sensitivity in degrees/s per count
+    pub fn dps_per_lsb(self) -> f32 {
+        match self {
+            FullScale::Dps245 => 0.00875,
+            FullScale::Dps500 => 0.0175,
+            FullScale::Dps2000 => 0.07,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Config {
+    pub odr: Odr,
+    pub bandwidth: Bandwidth,
+    pub full_scale: FullScale,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            odr: Odr::Hz100,
+            bandwidth: Bandwidth::Narrowest,
+            full_scale: FullScale::Dps245,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Error<SpiE, PinE> {
+    Spi(SpiE),
+    Pin(PinE),
+    // WHO_AM_I answered with something else
+    UnknownDevice(u8),
+    // more registers than one read_registers() transfer takes
+    InvalidLength,
+}
+
+pub struct I3g4250d<SPI, CS> {
+    spi: SPI,
+    cs: CS,
+    full_scale: FullScale,
+}
+
+impl<SPI, CS, SpiE, PinE> I3g4250d<SPI, CS>
+where
+    SPI: Transfer<u8, Error = SpiE>,
+    CS: OutputPin<Error = PinE>,
+{
+    //`cs` should already be high
+    pub fn new(spi: SPI, cs: CS) -> Self {
+        Self {
+            spi,
+            cs,
+            full_scale: FullScale::Dps245,
+        }
+    }
+
+    //checks WHO_AM_I, powers the sensor up with all axes on and applies `config`
+    pub fn init(&mut self, config: Config) -> Result<Device, Error<SpiE, PinE>> {
+        let device = self.probe()?;
+        self.write_register(CTRL_REG4, BDU)?;
+        self.configure(config)?;
+        Ok(device)
+    }
+
+    pub fn probe(&mut self) -> Result<Device, Error<SpiE, PinE>> {
+        match self.read_register(WHO_AM_I)? {
+            I3G4250D_ID => Ok(Device::I3g4250d),
+            L3GD20_ID => Ok(Device::L3gd20),
+            id => Err(Error::UnknownDevice(id)),
+        }
+    }
+
+    pub fn configure(&mut self, config: Config) -> Result<(), Error<SpiE, PinE>> {
+        let ctrl1 = (config.odr as u8) << 6 | (config.bandwidth as u8) << 4 | POWER_ON | ALL_AXES;
+        self.write_register(CTRL_REG1, ctrl1)?;
+        self.set_full_scale(config.full_scale)
+    }
+
+    pub fn set_full_scale(&mut self, full_scale: FullScale) -> Result<(), Error<SpiE, PinE>> {
+        let ctrl4 = self.read_register(CTRL_REG4)?;
+        self.write_register(CTRL_REG4, (ctrl4 & !0x30) | (full_scale as u8) << 4)?;
+        self.full_scale = full_scale;
+        Ok(())
+    }
+
+    pub fn full_scale(&self) -> FullScale {
+        self.full_scale
+    }
+
+    //turns the sensor off, configure() starts it again
+    pub fn power_down(&mut self) -> Result<(), Error<SpiE, PinE>> {
+        let ctrl1 = self.read_register(CTRL_REG1)?;
+        self.write_register(CTRL_REG1, ctrl1 & !POWER_ON)
+    }
+
+    //true when a new reading of all three axes is waiting
+    pub fn data_ready(&mut self) -> Result<bool, Error<SpiE, PinE>> {
+        Ok(self.read_register(STATUS_REG)? & ZYXDA != 0)
+    }
+
+    //x, y, z in counts
+    pub fn read_gyro_raw(&mut self) -> Result<[i16; 3], Error<SpiE, PinE>> {
+        let mut buf = [0u8; 6];
+        self.read_registers(OUT_X_L, &mut buf)?;
+        Ok([
+            i16::from_le_bytes([buf[0], buf[1]]),
+            i16::from_le_bytes([buf[2], buf[3]]),
+            i16::from_le_bytes([buf[4], buf[5]]),
+        ])
+    }
+
+    //x, y, z in degrees/s at the configured full scale
+    pub fn read_gyro_dps(&mut self) -> Result<[f32; 3], Error<SpiE, PinE>> {
+        let raw = self.read_gyro_raw()?;
+        let scale = self.full_scale.dps_per_lsb();
+        Ok([raw[0] as f32 * scale, raw[1] as f32 * scale, raw[2] as f32 * scale])
+    }
+
+    pub fn read_register(&mut self, reg: u8) -> Result<u8, Error<SpiE, PinE>> {
+        let mut buf = [0u8; 1];
+        self.read_registers(reg, &mut buf)?;
+        Ok(buf[0])
+    }
+
+    pub fn write_register(&mut self, reg: u8, value: u8) -> Result<(), Error<SpiE, PinE>> {
+        let mut frame = [reg & !(READ | AUTO_INCREMENT), value];
+        self.transfer(&mut frame)
+    }
+
+    //reads `buf.len()` registers starting at `reg`, Error::InvalidLength for more than MAX_READ
+    pub fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Error<SpiE, PinE>> {
+        if buf.len() > MAX_READ {
+            return Err(Error::InvalidLength);
+        }
+        let mut frame = [0u8; MAX_READ + 1];
+        let frame = &mut frame[..buf.len() + 1];
+        frame[0] = reg | READ | if buf.len() > 1 { AUTO_INCREMENT } else { 0 };
+        self.transfer(frame)?;
+        buf.copy_from_slice(&frame[1..]);
+        Ok(())
+    }
+
+    pub fn release(self) -> (SPI, CS) {
+        (self.spi, self.cs)
+    }
+
+    // one chip-select framed transfer, cs goes high again also after an error
+    fn transfer(&mut self, frame: &mut [u8]) -> Result<(), Error<SpiE, PinE>> {
+        self.cs.set_low().map_err(Error::Pin)?;
+        let result = self.spi.transfer(frame).map(|_| ()).map_err(Error::Spi);
+        self.cs.set_high().map_err(Error::Pin)?;
+        result
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::Infallible;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // register file behind the fake bus, with every frame and chip-select level seen
+    struct Chip {
+        regs: [u8; 0x40],
+        frames: Vec<Vec<u8>>,
+        cs: Vec<bool>,
+        // transfers fail while set
+        broken: bool,
+    }
+
+    struct FakeSpi(Rc<RefCell<Chip>>);
+    struct FakeCs(Rc<RefCell<Chip>>);
+
+    impl Transfer<u8> for FakeSpi {
+        type Error = ();
+
+        // answers like the sensor: a read fills the frame from the registers, a write
+        // stores it, both step through the registers with AUTO_INCREMENT
+        fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], ()> {
+            let mut chip = self.0.borrow_mut();
+            assert_eq!(chip.cs.last(), Some(&false), "transfer without chip select");
+            chip.frames.push(words.to_vec());
+            if chip.broken {
+                return Err(());
+            }
+
+            let (command, data) = words.split_first_mut().unwrap();
+            let reg = (*command & 0x3F) as usize;
+            let step = usize::from(*command & AUTO_INCREMENT != 0);
+            for (i, word) in data.iter_mut().enumerate() {
+                if *command & READ != 0 {
+                    *word = chip.regs[reg + i * step];
+                } else {
+                    chip.regs[reg + i * step] = *word;
+                }
+            }
+            Ok(words)
+        }
+    }
+
+    impl OutputPin for FakeCs {
+        type Error = Infallible;
+
+        fn set_low(&mut self) -> Result<(), Infallible> {
+            self.0.borrow_mut().cs.push(false);
+            Ok(())
+        }
+
+        fn set_high(&mut self) -> Result<(), Infallible> {
+            self.0.borrow_mut().cs.push(true);
+            Ok(())
+        }
+    }
+
+    fn gyro(who_am_i: u8) -> (I3g4250d<FakeSpi, FakeCs>, Rc<RefCell<Chip>>) {
+        let mut regs = [0; 0x40];
+        regs[WHO_AM_I as usize] = who_am_i;
+        let chip = Rc::new(RefCell::new(Chip {
+            regs,
+            frames: Vec::new(),
+            cs: vec![true],
+            broken: false,
+        }));
+        let gyro = I3g4250d::new(FakeSpi(chip.clone()), FakeCs(chip.clone()));
+        (gyro, chip)
+    }
+
+    #[test]
+    fn who_am_i() {
+        assert_eq!(gyro(I3G4250D_ID).0.probe(), Ok(Device::I3g4250d));
+        assert_eq!(gyro(L3GD20_ID).0.probe(), Ok(Device::L3gd20));
+        assert_eq!(gyro(0xFF).0.probe(), Err(Error::UnknownDevice(0xFF)));
+
+        // one register, read bit without auto-increment
+        let (mut gyro, chip) = gyro(I3G4250D_ID);
+        gyro.probe().unwrap();
+        assert_eq!(chip.borrow().frames, [vec![0x8F, 0]]);
+    }
+
+    #[test]
+    fn unknown_device_is_not_configured() {
+        let (mut gyro, chip) = gyro(0x00);
+        assert_eq!(gyro.init(Config::default()), Err(Error::UnknownDevice(0x00)));
+        assert_eq!(chip.borrow().frames.len(), 1);
+    }
+
+    #[test]
+    fn control_registers() {
+        let (mut gyro, chip) = gyro(I3G4250D_ID);
+        let config = Config {
+            odr: Odr::Hz400,
+            bandwidth: Bandwidth::Wide,
+            full_scale: FullScale::Dps2000,
+        };
+        assert_eq!(gyro.init(config), Ok(Device::I3g4250d));
+
+        let chip = chip.borrow();
+        // DR = 2, BW = 2, power on, x y z on
+        assert_eq!(chip.regs[CTRL_REG1 as usize], 0xAF);
+        // block data update kept, FS = 2
+        assert_eq!(chip.regs[CTRL_REG4 as usize], 0xA0);
+        assert_eq!(gyro.full_scale(), FullScale::Dps2000);
+        // writes go out without the read and auto-increment bits
+        assert!(chip.frames.contains(&vec![CTRL_REG1, 0xAF]));
+    }
+
+    #[test]
+    fn power_down_keeps_the_rest_of_ctrl_reg1() {
+        let (mut gyro, chip) = gyro(I3G4250D_ID);
+        gyro.init(Config::default()).unwrap();
+        gyro.power_down().unwrap();
+        assert_eq!(chip.borrow().regs[CTRL_REG1 as usize], 0x07);
+    }
+
+    #[test]
+    fn readings_are_little_endian() {
+        let (mut gyro, chip) = gyro(I3G4250D_ID);
+        chip.borrow_mut().regs[OUT_X_L as usize..OUT_X_L as usize + 6]
+            .copy_from_slice(&[0x34, 0x12, 0xFF, 0xFF, 0x00, 0x80]);
+
+        assert_eq!(gyro.read_gyro_raw(), Ok([0x1234, -1, i16::MIN]));
+        // read bit and auto-increment, then one byte per register
+        let frame = chip.borrow().frames.last().unwrap().clone();
+        assert_eq!(frame[0], OUT_X_L | 0xC0);
+        assert_eq!(frame.len(), 7);
+
+        assert_eq!(gyro.read_gyro_dps(), Ok([0x1234 as f32 * 0.00875, -0.00875, i16::MIN as f32 * 0.00875]));
+    }
+
+    #[test]
+    fn data_ready_needs_all_axes() {
+        let (mut gyro, chip) = gyro(I3G4250D_ID);
+        // x and y only
+        chip.borrow_mut().regs[STATUS_REG as usize] = 0x03;
+        assert_eq!(gyro.data_ready(), Ok(false));
+        chip.borrow_mut().regs[STATUS_REG as usize] = 0x0F;
+        assert_eq!(gyro.data_ready(), Ok(true));
+    }
+
+    #[test]
+    fn chip_select_goes_high_after_a_failed_transfer() {
+        let (mut gyro, chip) = gyro(I3G4250D_ID);
+        chip.borrow_mut().broken = true;
+        assert_eq!(gyro.data_ready(), Err(Error::Spi(())));
+        assert_eq!(chip.borrow().cs, [true, false, true]);
+    }
+
+    #[test]
+    fn long_reads_are_refused() {
+        let (mut gyro, chip) = gyro(I3G4250D_ID);
+        let mut buf = [0; MAX_READ];
+        assert_eq!(gyro.read_registers(OUT_X_L, &mut buf), Ok(()));
+
+        let mut buf = [0; MAX_READ + 1];
+        assert_eq!(gyro.read_registers(OUT_X_L, &mut buf), Err(Error::InvalidLength));
+        assert_eq!(chip.borrow().frames.len(), 1);
+    }
+}
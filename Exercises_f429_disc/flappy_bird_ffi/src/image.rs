@@ -0,0 +1,98 @@
+// uncompressed RGB565 image with its dimensions.
+// Image::new is a const fn: for a static or const image a pixel count which
+// does not match width * height stops the build instead of reading past the
+// end of the array in the C driver.
+
+use crate::config::*;
+use crate::display::DisplayBackend;
+
+#[derive(Copy, Clone)]
+pub struct Image<'a> {
+    width: u32,
+    height: u32,
+    pixels: &'a [u16],
+}
+
+// area of an image in image pixels
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub w: u32,
+    pub h: u32,
+}
+
+impl<'a> Image<'a> {
+    pub const fn new(width: u32, height: u32, pixels: &'a [u16]) -> Self {
+        assert!(
+            pixels.len() == width as usize * height as usize,
+            "pixel count does not match width * height"
+        );
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &'a [u16] {
+        self.pixels
+    }
+
+    pub fn bounds(&self) -> Rect {
+        Rect {
+            x: 0,
+            y: 0,
+            w: self.width,
+            h: self.height,
+        }
+    }
+
+    // `w` pixels of row `y`, starting at column `x`
+    pub fn row(&self, x: u32, y: u32, w: u32) -> &'a [u16] {
+        let start = (y * self.width + x) as usize;
+        &self.pixels[start..start + w as usize]
+    }
+}
+
+impl Rect {
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        other.x >= self.x
+            && other.y >= self.y
+            && other.x + other.w <= self.x + self.w
+            && other.y + other.h <= self.y + self.h
+    }
+}
+
+// draws the `src` part of `image` with its top left corner at (x, y).
+// full width parts go out in one draw_image call, narrower ones row by row
+// because their rows are not contiguous in memory.
+pub fn blit<B: DisplayBackend>(backend: &mut B, x: Coord, y: Coord, image: &Image, src: Rect) {
+    assert!(image.bounds().contains_rect(&src), "source rectangle outside the image");
+    if src.w == 0 || src.h == 0 {
+        return;
+    }
+
+    let x: u16 = x.try_into().expect("X co-ordinate is out of range");
+    let y: u16 = y.try_into().expect("y co-ordinate is out of range");
+    let w: u16 = src.w.try_into().expect("width out of range");
+    let h: u16 = src.h.try_into().expect("height out of range");
+
+    if src.w == image.width {
+        let start = (src.y * image.width) as usize;
+        let end = start + (src.w * src.h) as usize;
+        backend.draw_image(x, w, y, h, &image.pixels[start..end]);
+    } else {
+        for row in 0..h {
+            backend.draw_image(x, w, y + row, 1, image.row(src.x, src.y + row as u32, src.w));
+        }
+    }
+}
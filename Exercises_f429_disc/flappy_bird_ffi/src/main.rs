@@ -16,7 +16,7 @@
 // use core::panic::PanicInfo;
 // use game::Game;
 // use crate::config::Coord;
-// use crate::game::InputDevice;
+// use crate::game::{Input, InputDevice, InputSample};
 
 // unsafe extern "C" {
 //     fn c_main();
@@ -78,26 +78,34 @@
 
 
 
-#![no_std]
-#![no_main]
+#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(test), no_main)]
 #![allow(dead_code)]
 
 mod game;
 mod display;
 mod display_ffi;
+mod font;
+mod image;
 mod assets;
 mod config;
 mod color;
 mod obstacle;
 mod player;
 mod spi_adapter;
-// mod i3g4250d;
+mod i3g4250d;
+mod gyro_input;
+#[cfg(test)]
+mod test_support;
 
+#[cfg(not(test))]
 use core::panic::PanicInfo;
-use crate::config::Coord;
-use crate::game::InputDevice;
+use crate::game::{Input, InputDevice, InputSample};
+use crate::gyro_input::GyroInput;
+use crate::i3g4250d::I3g4250d;
 use game::Game;
 use rtt_target::rtt_init_print;
+use stm32f4xx_hal::{pac, prelude::*, spi::Spi};
 
 // Dummy input device for testing
 pub struct DummyInputDevice;
@@ -115,30 +123,52 @@ impl InputDevice for DummyInputDevice {
         Ok(())
     }
 
-    fn is_tap(&mut self, _y_min: Coord, _y_max: Coord) -> Result<(Coord, bool), Self::Error> {
-        // Always return "no tap" and dummy X-coordinate
-        Ok((100, false))
+    fn sample(&mut self) -> Result<InputSample, Self::Error> {
+        // Never any input, the bird keeps its place
+        Ok(InputSample::new(Input::None, game::now_ms()))
     }
 }
 
 // Entry point for Rust (called after `c_main`)
-#[no_mangle]
+#[cfg_attr(not(test), no_mangle)]
 fn main() -> ! {
     // Init RTT before any logging
     rtt_init_print!();
-    // With no C layer present, skip hardware init and driver registration.
+    // With no C layer present, skip display driver registration.
     display::init(); // No-op display init for Rust-only build
 
-    let input = DummyInputDevice::new();
-    let mut game_instance = Game::init(input).expect("Failed to initialize game");
+    // On-board gyro: SPI5 on PF7 (SCK), PF8 (MISO), PF9 (MOSI), chip-select on PC1
+    let dp = pac::Peripherals::take().expect("peripherals already taken");
+    let rcc = dp.RCC.constrain();
+    let clocks = rcc.cfgr.freeze();
+    let gpiof = dp.GPIOF.split();
+    let gpioc = dp.GPIOC.split();
+    let mut cs = gpioc.pc1.into_push_pull_output();
+    cs.set_high();
+    let spi = Spi::new(
+        dp.SPI5,
+        (gpiof.pf7, gpiof.pf8, gpiof.pf9),
+        embedded_hal::spi::MODE_3,
+        1.MHz(),
+        &clocks,
+    );
+
+    match Game::init(GyroInput::new(I3g4250d::new(spi, cs))) {
+        Ok(game_instance) => run(game_instance),
+        // No gyro answering, the game still runs without input
+        Err(_) => run(Game::init(DummyInputDevice::new()).expect("Failed to initialize game")),
+    }
+}
 
-    // Game loop
+// Game loop
+fn run<T: InputDevice>(mut game_instance: Game<T>) -> ! {
     loop {
         game_instance.update();
     }
 }
 
 // Panic handler required for `#![no_std]`
+#[cfg(not(test))]
 #[panic_handler]
 fn panic(_info: &PanicInfo) -> ! {
     loop {}
@@ -33,7 +33,7 @@ impl Player {
     }
 
     fn draw(&self) {
-        display::draw_image(self.x, self.w, self.y, self.h, &assets::BIRD_IMG_DATA);
+        display::draw_image(self.x, self.y, &assets::BIRD_IMG);
     }
 
     fn clear(&self, old_y: Coord) {
@@ -0,0 +1,60 @@
+// helpers shared by the host tests
+
+use crate::config::{LCD_HEIGHT, LCD_WIDTH};
+use crate::display::DisplayBackend;
+
+const W: usize = LCD_WIDTH as usize;
+const H: usize = LCD_HEIGHT as usize;
+
+// framebuffer backend of the panel size, counts the calls per drawing operation
+pub struct FrameBuffer {
+    pub pixels: Vec<u16>,
+    pub pixel_calls: usize,
+    pub rect_calls: usize,
+    pub image_calls: usize,
+    // most pixels sent in one draw_image call
+    pub largest_blit: usize,
+}
+
+impl FrameBuffer {
+    pub fn new() -> Self {
+        Self {
+            pixels: vec![0; W * H],
+            pixel_calls: 0,
+            rect_calls: 0,
+            image_calls: 0,
+            largest_blit: 0,
+        }
+    }
+
+    pub fn at(&self, x: usize, y: usize) -> u16 {
+        self.pixels[y * W + x]
+    }
+}
+
+impl DisplayBackend for FrameBuffer {
+    fn draw_pixel(&mut self, x: u16, y: u16, color: u16) {
+        self.pixel_calls += 1;
+        self.pixels[y as usize * W + x as usize] = color;
+    }
+
+    fn fill_rectangle(&mut self, x: u16, w: u16, y: u16, h: u16, color: u16) {
+        self.rect_calls += 1;
+        for row in y..y + h {
+            for col in x..x + w {
+                self.pixels[row as usize * W + col as usize] = color;
+            }
+        }
+    }
+
+    fn draw_image(&mut self, x: u16, w: u16, y: u16, h: u16, image_data: &[u16]) {
+        self.image_calls += 1;
+        self.largest_blit = self.largest_blit.max(image_data.len());
+        assert_eq!(image_data.len(), w as usize * h as usize);
+        for row in 0..h as usize {
+            for col in 0..w as usize {
+                self.pixels[(y as usize + row) * W + x as usize + col] = image_data[row * w as usize + col];
+            }
+        }
+    }
+}